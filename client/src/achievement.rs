@@ -0,0 +1,162 @@
+use serde::{Deserialize, Serialize};
+
+use crate::state::GameState;
+
+/// What unlocks an achievement. Checked against the game state every tick,
+/// like mission steps.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Condition {
+    CommitCrimes(u32),
+    ReachLevel(u32),
+    OwnProperties(u32),
+    WinAtCasino(u32),
+    HaveBanked(u64),
+}
+
+impl Condition {
+    /// Current progress and the target, for display.
+    fn progress(self, state: &GameState) -> (u64, u64) {
+        match self {
+            Condition::CommitCrimes(n) => (state.crimes_committed.into(), n.into()),
+            Condition::ReachLevel(n) => (state.player.level.into(), n.into()),
+            Condition::OwnProperties(n) => (state.real_estate.owned().count() as u64, n.into()),
+            Condition::WinAtCasino(n) => (state.casino_wins.into(), n.into()),
+            Condition::HaveBanked(y) => (state.bank.balance, y),
+        }
+    }
+
+    fn is_met(self, state: &GameState) -> bool {
+        let (current, target) = self.progress(state);
+        current >= target
+    }
+}
+
+pub struct Achievement {
+    /// Saved in place of the name, so renaming one doesn't re-lock it.
+    pub id: &'static str,
+    pub name: &'static str,
+    pub description: &'static str,
+    pub condition: Condition,
+}
+
+pub const ACHIEVEMENTS: [Achievement; 8] = [
+    Achievement {
+        id: "first-crime",
+        name: "First Offence",
+        description: "Commit your first crime",
+        condition: Condition::CommitCrimes(1),
+    },
+    Achievement {
+        id: "repeat-offender",
+        name: "Repeat Offender",
+        description: "Commit 25 crimes",
+        condition: Condition::CommitCrimes(25),
+    },
+    Achievement {
+        id: "level-5",
+        name: "Finding Your Feet",
+        description: "Reach level 5",
+        condition: Condition::ReachLevel(5),
+    },
+    Achievement {
+        id: "level-10",
+        name: "Local Legend",
+        description: "Reach level 10",
+        condition: Condition::ReachLevel(10),
+    },
+    Achievement {
+        id: "landlord",
+        name: "Landlord",
+        description: "Own 2 properties",
+        condition: Condition::OwnProperties(2),
+    },
+    Achievement {
+        id: "tycoon",
+        name: "Property Tycoon",
+        description: "Own every property in town",
+        condition: Condition::OwnProperties(4),
+    },
+    Achievement {
+        id: "beginners-luck",
+        name: "Beginner's Luck",
+        description: "Win at the Casino",
+        condition: Condition::WinAtCasino(1),
+    },
+    Achievement {
+        id: "saver",
+        name: "Rainy Day Fund",
+        description: "Have $10000 in the bank",
+        condition: Condition::HaveBanked(10_000),
+    },
+];
+
+/// Ids of the achievements unlocked so far, in the order they were.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Achievements {
+    pub unlocked: Vec<String>,
+}
+
+impl Achievements {
+    pub fn is_unlocked(&self, achievement: &Achievement) -> bool {
+        self.unlocked.iter().any(|id| id == achievement.id)
+    }
+}
+
+/// Unlocks every achievement whose condition is now met and reports it in
+/// the Newspaper. Returns the ones just unlocked, for toasts.
+pub fn update(state: &mut GameState) -> Vec<&'static Achievement> {
+    let mut unlocked = Vec::new();
+    for achievement in &ACHIEVEMENTS {
+        if state.achievements.is_unlocked(achievement) || !achievement.condition.is_met(state) {
+            continue;
+        }
+        state.achievements.unlocked.push(achievement.id.to_string());
+        state.log_news(format!("Achievement unlocked: {}.", achievement.name));
+        unlocked.push(achievement);
+    }
+    unlocked
+}
+
+/// Every achievement, unlocked ones ticked and locked ones with how far
+/// along they are.
+pub fn list_text(state: &GameState) -> String {
+    ACHIEVEMENTS
+        .iter()
+        .map(|achievement| {
+            if state.achievements.is_unlocked(achievement) {
+                format!("[x] {}\n    {}", achievement.name, achievement.description)
+            } else {
+                let (current, target) = achievement.condition.progress(state);
+                format!(
+                    "[ ] {}\n    {} ({}/{})",
+                    achievement.name,
+                    achievement.description,
+                    current.min(target),
+                    target
+                )
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn summary_text(state: &GameState) -> String {
+    let recent: Vec<&str> = state
+        .achievements
+        .unlocked
+        .iter()
+        .rev()
+        .filter_map(|id| ACHIEVEMENTS.iter().find(|a| a.id == id))
+        .map(|achievement| achievement.name)
+        .collect();
+    let mut text = format!(
+        "Unlocked {} of {}",
+        state.achievements.unlocked.len(),
+        ACHIEVEMENTS.len()
+    );
+    if !recent.is_empty() {
+        text = format!("{}\n\nMost recent first:\n{}", text, recent.join("\n"));
+    }
+    text
+}
@@ -0,0 +1,181 @@
+use crate::inventory::Quantity;
+use crate::money::format_money;
+use crate::roulette::Bet;
+use crate::state::GameState;
+
+/// A game action that can be held back behind a confirmation dialog.
+/// Anything irreversible belongs here so it gets the same y/n treatment no
+/// matter which page starts it, and so does anything that spends money,
+/// which is asked about once it's a big enough share of the player's cash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    DiscardItem(usize),
+    /// Sell this many of the stack at this index.
+    SellItems(usize, Quantity),
+    SellProperty(usize),
+    /// Buy one of the City shop entry at this index.
+    BuyItem(usize),
+    BuyProperty(usize),
+    /// Add this bet to the roulette slip.
+    PlaceBet(Bet),
+    SpinSlots,
+    /// Deal a blackjack hand for the table's bet.
+    DealBlackjack,
+    /// Throw the whole game away and start again.
+    ResetGame,
+    /// Save and leave. Asked about so a stray Esc doesn't end the session.
+    Quit,
+}
+
+impl Action {
+    /// Destructive actions wait for a yes before they run.
+    pub fn is_destructive(self) -> bool {
+        match self {
+            Action::DiscardItem(_)
+            | Action::SellItems(..)
+            | Action::SellProperty(_)
+            | Action::ResetGame
+            | Action::Quit => true,
+            Action::BuyItem(_)
+            | Action::BuyProperty(_)
+            | Action::PlaceBet(_)
+            | Action::SpinSlots
+            | Action::DealBlackjack => false,
+        }
+    }
+
+    /// What the action would spend, for actions that spend money.
+    pub fn cost(self, state: &GameState) -> Option<u64> {
+        match self {
+            Action::BuyItem(index) => state.shop.entries.get(index).map(|entry| entry.price),
+            Action::BuyProperty(index) => state
+                .real_estate
+                .properties
+                .get(index)
+                .map(|property| property.price),
+            Action::PlaceBet(bet) => Some(bet.amount),
+            Action::SpinSlots => Some(state.slots.bet_amount()),
+            Action::DealBlackjack => Some(state.blackjack.bet_amount()),
+            Action::DiscardItem(_)
+            | Action::SellItems(..)
+            | Action::SellProperty(_)
+            | Action::ResetGame
+            | Action::Quit => None,
+        }
+    }
+
+    /// Whether the action spends more than the confirm threshold's share of
+    /// the player's cash. Spends they can't afford at all aren't asked
+    /// about; they just fail.
+    pub fn is_overspend(self, state: &GameState) -> bool {
+        let money = state.player.money;
+        let percent = state.settings.confirm_spend_percent();
+        self.cost(state)
+            .is_some_and(|cost| cost <= money && cost * 100 > money * percent)
+    }
+
+    /// Whether `undo` can take the action back once it's done.
+    pub fn is_reversible(self) -> bool {
+        match self {
+            Action::DiscardItem(_)
+            | Action::SellItems(..)
+            | Action::SellProperty(_)
+            | Action::BuyItem(_)
+            | Action::BuyProperty(_) => true,
+            Action::PlaceBet(_)
+            | Action::SpinSlots
+            | Action::DealBlackjack
+            | Action::ResetGame
+            | Action::Quit => false,
+        }
+    }
+
+    /// The question the dialog asks, naming what will be lost.
+    pub fn prompt(self, state: &GameState) -> String {
+        match self {
+            Action::DiscardItem(index) => match state.inventory.items.get(index) {
+                Some(stack) => format!("Throw away one {}?", stack.item.name()),
+                None => "Throw away this item?".to_string(),
+            },
+            Action::SellItems(index, quantity) => match state.inventory.items.get(index) {
+                Some(stack) => {
+                    let count = quantity.of(stack.quantity);
+                    format!(
+                        "Sell {} {} for {}?",
+                        count,
+                        stack.item.name(),
+                        format_money(stack.item.resale_value().saturating_mul(u64::from(count)))
+                    )
+                }
+                None => "Sell these items?".to_string(),
+            },
+            Action::SellProperty(index) => match state.real_estate.properties.get(index) {
+                Some(property) => format!(
+                    "Sell the {} for {}? It lists at {} today.",
+                    property.name.to_lowercase(),
+                    format_money(property.resale_price()),
+                    format_money(property.price)
+                ),
+                None => "Sell this property?".to_string(),
+            },
+            Action::ResetGame => "Start a new game? ALL progress will be lost and the save \
+                                  file deleted. This can't be undone."
+                .to_string(),
+            Action::Quit => "Quit? The game is saved first.".to_string(),
+            Action::BuyItem(_)
+            | Action::BuyProperty(_)
+            | Action::PlaceBet(_)
+            | Action::SpinSlots
+            | Action::DealBlackjack => {
+                let cost = self.cost(state).unwrap_or(0);
+                format!(
+                    "{} for {}? You'd have {} left.",
+                    self.spend_text(state),
+                    format_money(cost),
+                    format_money(state.player.money.saturating_sub(cost))
+                )
+            }
+        }
+    }
+
+    /// What a spending action buys or bets on, for its prompt.
+    fn spend_text(self, state: &GameState) -> String {
+        match self {
+            Action::BuyItem(index) => match state.shop.entries.get(index) {
+                Some(entry) => format!("Buy {}", entry.item.name()),
+                None => "Buy this item".to_string(),
+            },
+            Action::BuyProperty(index) => match state.real_estate.properties.get(index) {
+                Some(property) => format!("Buy the {}", property.name.to_lowercase()),
+                None => "Buy this property".to_string(),
+            },
+            Action::PlaceBet(bet) => format!("Bet on {}", bet.kind),
+            Action::SpinSlots => "Spin the slots".to_string(),
+            Action::DealBlackjack => "Deal a blackjack hand".to_string(),
+            Action::DiscardItem(_)
+            | Action::SellItems(..)
+            | Action::SellProperty(_)
+            | Action::ResetGame
+            | Action::Quit => String::new(),
+        }
+    }
+}
+
+/// A destructive action waiting on the player's answer. While one is open it
+/// takes every key: y runs the action, n or Esc drops it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfirmDialog {
+    pub action: Action,
+    pub prompt: String,
+}
+
+impl ConfirmDialog {
+    /// A dialog for `action`, or `None` if it's safe to run straight away.
+    pub fn for_action(action: Action, state: &GameState) -> Option<ConfirmDialog> {
+        let ask = action.is_destructive() || action.is_overspend(state);
+        ask.then(|| ConfirmDialog {
+            action,
+            prompt: action.prompt(state),
+        })
+    }
+}
@@ -0,0 +1,399 @@
+use std::collections::HashMap;
+use std::time::Instant;
+
+use ratatui::widgets::ListState;
+
+use crate::action::ConfirmDialog;
+use crate::command::CommandResult;
+use crate::completion::Completer;
+use crate::demo::Demo;
+use crate::difficulty::Difficulty;
+use crate::error::{self, RustyError};
+use crate::flash::MoneyFlash;
+use crate::history::CommandHistory;
+use crate::keys::{Keymap, Mode};
+use crate::palette::Palette;
+use crate::repeat::Repeater;
+use crate::state::GameState;
+use crate::toast::Toasts;
+use crate::transition::PageTransitions;
+use crate::tutorial::Tutorial;
+use crate::ui::AppLayout;
+use crate::undo::{self, UndoStack};
+use crate::visits::PageVisits;
+
+/// The panel keys go to, which is the one whose border is lit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Focus {
+    Menu,
+    Input,
+    /// The open page's list; see `Mode::List`.
+    PageList,
+    /// A dialog or overlay on top of everything, which takes every key.
+    Overlay,
+}
+
+/// Everything the main loop works on: the game itself plus the state of the
+/// screen around it. Only `GameState` is saved; the rest starts fresh each
+/// run.
+pub struct App {
+    pub game: GameState,
+    /// The menu as the settings lay it out; see `sync_menu`.
+    pub menu_items: Vec<&'static str>,
+    /// Index into `menu_items` of the open page. Kept private so the menu's
+    /// `ListState` can't drift from it; change it with the `select` methods.
+    selected: usize,
+    /// A hidden page opened with `goto`, shown instead of the menu's pick
+    /// until the menu is used again.
+    hidden_open: Option<&'static str>,
+    pub menu_state: ListState,
+    pub mode: Mode,
+    pub keymap: Keymap,
+    pub input: String,
+    /// Byte offset of the Input box's cursor into `input`, always on a
+    /// character boundary. Kept private so it can't point past the text;
+    /// edit the input through the methods below.
+    cursor: usize,
+    pub history: CommandHistory,
+    pub completer: Completer,
+    pub show_help: bool,
+    /// Scroll offset of the notifications overlay, while it's open.
+    pub notifications: Option<u16>,
+    pub confirm: Option<ConfirmDialog>,
+    /// The Ctrl-P command palette, while it's open.
+    pub palette: Option<Palette>,
+    /// The highlighted row of the new-game difficulty picker, while it's
+    /// open.
+    pub difficulty_picker: Option<Difficulty>,
+    /// The walkthrough, while it's running.
+    pub tutorial: Option<Tutorial>,
+    /// Menu filter typed after `/`, while it's open.
+    pub filter: Option<String>,
+    /// First digit of a two-digit menu jump and when it was typed.
+    pub pending_jump: Option<(usize, Instant)>,
+    pub toasts: Toasts,
+    pub undo: UndoStack,
+    /// Result of the last typed command, shown under the content.
+    pub output: error::Result<CommandResult>,
+    /// Scroll offset of each page's content, kept when switching pages.
+    pub scroll_offsets: HashMap<&'static str, u16>,
+    /// Scroll position of each page's list, for pages that have one. The
+    /// selection itself lives in `GameState`; see `move_list`.
+    pub list_states: HashMap<&'static str, ListState>,
+    /// Layout of the last frame drawn, used to hit-test mouse clicks.
+    pub last_layout: Option<AppLayout>,
+    pub transitions: PageTransitions,
+    pub money_flash: MoneyFlash,
+    pub repeater: Repeater,
+    /// When the player last pressed a key or used the mouse.
+    pub last_input: Instant,
+    /// Attract mode, while it's playing.
+    pub demo: Option<Demo>,
+    /// The clock is stopped and only resuming does anything.
+    pub paused: bool,
+    /// Pages opened so far, for Back and Forward.
+    pub visits: PageVisits,
+}
+
+impl App {
+    /// A new game opens on the tutorial.
+    pub fn new(game: GameState) -> Self {
+        let tutorial = (!game.tutorial_done).then(Tutorial::default);
+        let menu_items = game.settings.menu_items();
+        Self {
+            game,
+            menu_items,
+            selected: 0,
+            hidden_open: None,
+            menu_state: ListState::default().with_selected(Some(0)),
+            mode: Mode::default(),
+            keymap: Keymap::default(),
+            input: String::new(),
+            cursor: 0,
+            history: CommandHistory::default(),
+            completer: Completer::default(),
+            show_help: false,
+            notifications: None,
+            confirm: None,
+            palette: None,
+            difficulty_picker: None,
+            tutorial,
+            filter: None,
+            pending_jump: None,
+            toasts: Toasts::default(),
+            undo: UndoStack::default(),
+            output: Ok(CommandResult::Empty),
+            scroll_offsets: HashMap::new(),
+            list_states: HashMap::new(),
+            last_layout: None,
+            transitions: PageTransitions::default(),
+            money_flash: MoneyFlash::default(),
+            repeater: Repeater::default(),
+            last_input: Instant::now(),
+            demo: None,
+            paused: false,
+            visits: PageVisits::default(),
+        }
+    }
+
+    /// Where keys go right now. Anything drawn over the panels takes them
+    /// first; otherwise it's down to the mode. Typing in the menu filter
+    /// counts as the menu.
+    pub fn focus(&self) -> Focus {
+        let overlay = self.paused
+            || self.show_help
+            || self.notifications.is_some()
+            || self.difficulty_picker.is_some()
+            || self.tutorial.is_some()
+            || self.confirm.is_some()
+            || self.palette.is_some();
+        match self.mode {
+            _ if overlay => Focus::Overlay,
+            Mode::Navigate => Focus::Menu,
+            Mode::Input => Focus::Input,
+            Mode::List => Focus::PageList,
+        }
+    }
+
+    pub fn current_page(&self) -> &'static str {
+        self.hidden_open
+            .unwrap_or_else(|| self.menu_items[self.selected])
+    }
+
+    /// Index into `menu_items` of the open page.
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Opens the menu entry at `index`, or the last one if it's past the end.
+    pub fn select(&mut self, index: usize) {
+        self.hidden_open = None;
+        self.selected = index.min(self.menu_items.len().saturating_sub(1));
+        self.menu_state.select(Some(self.selected));
+    }
+
+    /// Opens `page`, whether the menu lists it or not.
+    pub fn open_page(&mut self, page: &'static str) {
+        match self.menu_items.iter().position(|item| *item == page) {
+            Some(index) => self.select(index),
+            None => {
+                self.hidden_open = Some(page);
+                self.menu_state.select(None);
+            }
+        }
+    }
+
+    /// Picks up changes to the menu order or hidden pages. The open page
+    /// stays open; if it was just hidden, the selection moves to the page
+    /// that took its place.
+    pub fn sync_menu(&mut self) {
+        let items = self.game.settings.menu_items();
+        if items == self.menu_items {
+            return;
+        }
+        let current = self.current_page();
+        let on_hidden = self.hidden_open.is_some();
+        let previous = self.selected;
+        self.menu_items = items;
+        match self.menu_items.iter().position(|item| *item == current) {
+            Some(index) => self.select(index),
+            None if on_hidden => {
+                self.selected = previous.min(self.menu_items.len().saturating_sub(1));
+            }
+            None => self.select(previous),
+        }
+    }
+
+    /// Moves down the menu, wrapping to the top if the setting allows.
+    pub fn select_next(&mut self) {
+        self.select_by(1, self.game.settings.wrap_navigation);
+    }
+
+    /// Moves up the menu, wrapping to the bottom if the setting allows.
+    pub fn select_prev(&mut self) {
+        self.select_by(-1, self.game.settings.wrap_navigation);
+    }
+
+    /// Moves the selection by `delta` entries, wrapping around the ends if
+    /// `wrap` is set and stopping at them otherwise.
+    pub fn select_by(&mut self, delta: isize, wrap: bool) {
+        if self.menu_items.is_empty() {
+            return;
+        }
+        self.select(step(self.selected, delta, self.menu_items.len(), wrap));
+    }
+
+    /// Moves the open page's list selection by `delta` rows, closing any
+    /// prompt about the old one. Does nothing on pages without a list.
+    pub fn move_list(&mut self, delta: isize, wrap: bool) {
+        let page = self.current_page();
+        let game = &mut self.game;
+        let (selected, len) = match page {
+            "Items" => {
+                game.item_prompt = false;
+                (&mut game.item_selected, game.inventory.items.len())
+            }
+            "City" => {
+                game.shop_prompt = false;
+                (&mut game.shop_selected, game.shop.entries.len())
+            }
+            _ => return,
+        };
+        if len > 0 {
+            *selected = step(*selected, delta, len, wrap);
+        }
+    }
+
+    /// Keeps the undo stack honest after an action that started from
+    /// `before`: a reversible one that changed something becomes the next
+    /// thing to undo, and anything else that did leaves nothing to go back
+    /// to.
+    pub fn track_undo(&mut self, before: GameState, reversible: bool) {
+        // Nothing to push or clear, or nothing changed at all: the cheap
+        // checks go first since this runs on every key.
+        if (!reversible && self.undo.is_empty()) || before == self.game {
+            return;
+        }
+        if !undo::progress_changed(&before, &self.game) {
+            return;
+        }
+        if reversible {
+            let depth = self.game.settings.undo_depth();
+            self.undo.push(before, depth);
+        } else {
+            self.undo.clear();
+        }
+    }
+
+    /// Takes back the last reversible action, for the `u` key and the `undo`
+    /// command.
+    pub fn undo_last(&mut self) -> error::Result<CommandResult> {
+        if self.undo.undo(&mut self.game) {
+            Ok(CommandResult::Message("Undone.".to_string()))
+        } else {
+            Err(RustyError::game("Nothing to undo"))
+        }
+    }
+
+    /// Replaces the typed text, leaving the cursor at its end.
+    pub fn set_input(&mut self, line: impl Into<String>) {
+        self.input = line.into();
+        self.cursor = self.input.len();
+    }
+
+    pub fn clear_input(&mut self) {
+        self.input.clear();
+        self.cursor = 0;
+    }
+
+    /// The cursor's position in characters, for drawing it.
+    pub fn cursor_column(&self) -> usize {
+        self.input[..self.cursor].chars().count()
+    }
+
+    /// Types `c` at the cursor.
+    pub fn insert_char(&mut self, c: char) {
+        self.input.insert(self.cursor, c);
+        self.cursor += c.len_utf8();
+    }
+
+    /// Removes the character before the cursor.
+    pub fn backspace(&mut self) {
+        if let Some(c) = self.input[..self.cursor].chars().next_back() {
+            self.cursor -= c.len_utf8();
+            self.input.remove(self.cursor);
+        }
+    }
+
+    /// Removes the character under the cursor.
+    pub fn delete(&mut self) {
+        if self.cursor < self.input.len() {
+            self.input.remove(self.cursor);
+        }
+    }
+
+    pub fn cursor_left(&mut self) {
+        if let Some(c) = self.input[..self.cursor].chars().next_back() {
+            self.cursor -= c.len_utf8();
+        }
+    }
+
+    pub fn cursor_right(&mut self) {
+        if let Some(c) = self.input[self.cursor..].chars().next() {
+            self.cursor += c.len_utf8();
+        }
+    }
+
+    pub fn cursor_home(&mut self) {
+        self.cursor = 0;
+    }
+
+    pub fn cursor_end(&mut self) {
+        self.cursor = self.input.len();
+    }
+
+    /// The open page's scroll offset.
+    pub fn scroll_offset(&mut self) -> &mut u16 {
+        self.scroll_offsets.entry(self.current_page()).or_insert(0)
+    }
+}
+
+/// `index` moved by `delta` entries of `len`, wrapping around the ends if
+/// `wrap` is set and stopping at them otherwise. `len` must not be zero.
+fn step(index: usize, delta: isize, len: usize, wrap: bool) -> usize {
+    let target = index as isize + delta;
+    if wrap {
+        target.rem_euclid(len as isize) as usize
+    } else {
+        target.clamp(0, len as isize - 1) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::PAGES;
+
+    fn app() -> App {
+        App::new(GameState::default())
+    }
+
+    #[test]
+    fn select_by_wraps_at_both_ends() {
+        let mut app = app();
+        let last = app.menu_items.len() - 1;
+        app.select_by(-1, true);
+        assert_eq!(app.selected(), last);
+        app.select_by(1, true);
+        assert_eq!(app.selected(), 0);
+        app.select_by(-1, false);
+        assert_eq!(app.selected(), 0);
+        app.select(last);
+        app.select_by(1, false);
+        assert_eq!(app.selected(), last);
+    }
+
+    #[test]
+    fn hiding_the_open_page_selects_the_one_in_its_place() {
+        let mut app = app();
+        app.select(2);
+        let next = app.menu_items[3];
+        app.game.settings.hide_page(PAGES[2]).unwrap();
+        app.sync_menu();
+        assert_eq!(app.selected(), 2);
+        assert_eq!(app.current_page(), next);
+    }
+
+    #[test]
+    fn open_page_opens_hidden_pages() {
+        let mut app = app();
+        app.game.settings.hide_page("Bank").unwrap();
+        app.sync_menu();
+        app.select(4);
+        app.open_page("Bank");
+        assert_eq!(app.current_page(), "Bank");
+        assert_eq!(app.menu_state.selected(), None);
+        app.select_next();
+        assert_eq!(app.current_page(), app.menu_items[5]);
+    }
+}
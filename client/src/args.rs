@@ -0,0 +1,104 @@
+//! Command-line flags, read and checked before the terminal is taken over
+//! so any mistake can be reported on stderr like any other tool's.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::error::RustyError;
+use crate::save;
+
+pub const USAGE: &str = "\
+Usage: rusty [--save <path>] [--new]
+
+  --save <path>  Play the save at <path> instead of picking a slot
+  --new          Start a new game, ignoring any existing save
+  -h, --help     Show this message";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Args {
+    /// Save file to play, bypassing the slot picker.
+    pub save: Option<PathBuf>,
+    /// Start afresh even if the save already holds a game.
+    pub new_game: bool,
+    pub help: bool,
+}
+
+#[derive(Debug)]
+pub enum ArgsError {
+    UnknownFlag(String),
+    MissingValue(&'static str),
+    /// `--save` named a directory.
+    IsDirectory(PathBuf),
+    /// `--save` named a file in a directory that doesn't exist.
+    NoDirectory(PathBuf),
+    /// `--save` named a file that isn't a save we can read.
+    Unreadable(PathBuf, RustyError),
+    /// `--new` without `--save`, and every slot holds a game.
+    NoFreeSlot,
+}
+
+impl fmt::Display for ArgsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArgsError::UnknownFlag(flag) => write!(f, "unknown argument '{}'", flag),
+            ArgsError::MissingValue(flag) => write!(f, "{} needs a value", flag),
+            ArgsError::IsDirectory(path) => {
+                write!(f, "{} is a directory, not a save file", path.display())
+            }
+            ArgsError::NoDirectory(path) => {
+                write!(f, "there's no directory to save {} in", path.display())
+            }
+            ArgsError::Unreadable(path, err) => {
+                write!(f, "couldn't load {}: {}", path.display(), err)
+            }
+            ArgsError::NoFreeSlot => write!(
+                f,
+                "all {} save slots are taken; delete one or use --save <path>",
+                save::MAX_SLOTS
+            ),
+        }
+    }
+}
+
+/// Reads the flags from `args`, which shouldn't include the program name.
+pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Args, ArgsError> {
+    let mut parsed = Args::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--save" => {
+                let path = args.next().ok_or(ArgsError::MissingValue("--save"))?;
+                parsed.save = Some(PathBuf::from(path));
+            }
+            "--new" => parsed.new_game = true,
+            "-h" | "--help" => parsed.help = true,
+            _ => return Err(ArgsError::UnknownFlag(arg)),
+        }
+    }
+    Ok(parsed)
+}
+
+/// The save the flags pick, or `None` to let the player choose a slot. A
+/// `--save` file that exists has to load, unless `--new` is going to
+/// replace it anyway.
+pub fn save_path(args: &Args, dir: &Path) -> Result<Option<PathBuf>, ArgsError> {
+    let Some(path) = &args.save else {
+        if !args.new_game {
+            return Ok(None);
+        }
+        let slot = save::free_slot(&save::list_slots(dir)).ok_or(ArgsError::NoFreeSlot)?;
+        return Ok(Some(save::slot_path(dir, slot)));
+    };
+    if path.is_dir() {
+        return Err(ArgsError::IsDirectory(path.clone()));
+    }
+    let parent = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty());
+    if parent.is_some_and(|parent| !parent.is_dir()) {
+        return Err(ArgsError::NoDirectory(path.clone()));
+    }
+    if path.exists() && !args.new_game {
+        save::load_game(path).map_err(|err| ArgsError::Unreadable(path.clone(), err))?;
+    }
+    Ok(Some(path.clone()))
+}
@@ -0,0 +1,113 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::money::format_money;
+use crate::state::GameState;
+
+/// Interest paid on the balance each in-game day, in hundredths of a
+/// percent: 50 is 0.5% a day.
+pub const DAILY_INTEREST_BASIS_POINTS: u64 = 50;
+
+/// Money kept safe from spending, growing a little every day.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Bank {
+    pub balance: u64,
+}
+
+impl Bank {
+    /// What tomorrow's interest will be on today's balance, rounded down.
+    pub fn daily_interest(&self) -> u64 {
+        self.balance.saturating_mul(DAILY_INTEREST_BASIS_POINTS) / 10_000
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BankError {
+    /// Moving nothing is rejected rather than logged.
+    EmptyAmount,
+    NotEnoughCash {
+        needed: u64,
+    },
+    NotEnoughBanked {
+        needed: u64,
+    },
+}
+
+impl fmt::Display for BankError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BankError::EmptyAmount => write!(f, "Enter at least $1"),
+            BankError::NotEnoughCash { needed } => {
+                write!(f, "Not enough cash: that needs {}", format_money(*needed))
+            }
+            BankError::NotEnoughBanked { needed } => {
+                write!(
+                    f,
+                    "Not enough in the bank: that needs {}",
+                    format_money(*needed)
+                )
+            }
+        }
+    }
+}
+
+/// Moves `amount` of cash into the bank. Returns the new balance.
+pub fn deposit(state: &mut GameState, amount: u64) -> Result<u64, BankError> {
+    if amount == 0 {
+        return Err(BankError::EmptyAmount);
+    }
+    if state.player.money < amount {
+        return Err(BankError::NotEnoughCash { needed: amount });
+    }
+    state.player.money -= amount;
+    state.bank.balance += amount;
+    Ok(state.bank.balance)
+}
+
+/// Takes `amount` out of the bank as cash. Returns the new balance.
+pub fn withdraw(state: &mut GameState, amount: u64) -> Result<u64, BankError> {
+    if amount == 0 {
+        return Err(BankError::EmptyAmount);
+    }
+    if state.bank.balance < amount {
+        return Err(BankError::NotEnoughBanked { needed: amount });
+    }
+    state.bank.balance -= amount;
+    state.player.money += amount;
+    Ok(state.bank.balance)
+}
+
+/// Adds a day's interest to the balance. Called once at the start of each
+/// in-game day; returns true if anything was paid.
+pub fn pay_interest(state: &mut GameState) -> bool {
+    let interest = state.bank.daily_interest();
+    if interest == 0 {
+        return false;
+    }
+    state.bank.balance += interest;
+    state.log_news(format!(
+        "The bank paid {} interest for day {}.",
+        format_money(interest),
+        state.clock.day()
+    ));
+    true
+}
+
+pub fn balance_text(state: &GameState) -> String {
+    let bank = &state.bank;
+    format!(
+        "Balance: {}\nCash: {}\n\nInterest: {}.{:02}% a day\nTomorrow: +{}",
+        format_money(bank.balance),
+        format_money(state.player.money),
+        DAILY_INTEREST_BASIS_POINTS / 100,
+        DAILY_INTEREST_BASIS_POINTS % 100,
+        format_money(bank.daily_interest())
+    )
+}
+
+pub fn help_text() -> &'static str {
+    "Money in the bank can't be spent, but it earns interest every day.\n\n\
+     deposit <amount|all>\nwithdraw <amount|all>"
+}
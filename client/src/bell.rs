@@ -0,0 +1,69 @@
+//! The terminal bell, rung for the moments worth hearing about: levelling
+//! up, getting locked up and unlocking an achievement. It's off unless the
+//! player turns it on, and held quiet while they grind so a run of actions
+//! doesn't turn into a run of beeps.
+
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+use crate::jail;
+use crate::settings::Settings;
+use crate::state::GameState;
+
+/// Rings closer together than this are dropped.
+pub const BELL_COOLDOWN: Duration = Duration::from_secs(2);
+
+/// Writes the bell character straight to the terminal. It isn't drawn, so
+/// the screen ratatui is keeping track of stays as it was.
+pub fn beep(out: &mut impl Write) -> io::Result<()> {
+    out.write_all(b"\x07")?;
+    out.flush()
+}
+
+/// The parts of the game the bell listens to, as they were at one moment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cues {
+    level: u32,
+    jailed: bool,
+    achievements: usize,
+}
+
+impl Cues {
+    pub fn of(state: &GameState, now: Instant) -> Self {
+        Self {
+            level: state.player.level,
+            jailed: jail::is_jailed(state, now),
+            achievements: state.achievements.unlocked.len(),
+        }
+    }
+
+    /// Whether going from `self` to `later` is worth a ring.
+    pub fn rings_for(self, later: Cues) -> bool {
+        later.level > self.level
+            || (later.jailed && !self.jailed)
+            || later.achievements > self.achievements
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Bell {
+    last_rung: Option<Instant>,
+}
+
+impl Bell {
+    /// Rings unless the settings say not to or it rang moments ago.
+    pub fn ring(&mut self, out: &mut impl Write, settings: &Settings, now: Instant) {
+        if !settings.bell || settings.quiet {
+            return;
+        }
+        if self
+            .last_rung
+            .is_some_and(|at| now.duration_since(at) < BELL_COOLDOWN)
+        {
+            return;
+        }
+        self.last_rung = Some(now);
+        // A terminal that can't beep just stays silent.
+        let _ = beep(out);
+    }
+}
@@ -0,0 +1,395 @@
+use std::fmt;
+
+use rand::Rng;
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+
+use crate::casino::BETS;
+use crate::money::format_money;
+use crate::player::PlayerStats;
+
+/// Decks in the shoe.
+pub const SHOE_DECKS: usize = 2;
+/// The shoe is rebuilt and shuffled before a deal once it drops below this.
+pub const RESHUFFLE_AT: usize = 20;
+/// The dealer draws until reaching at least this score.
+pub const DEALER_STANDS_ON: u32 = 17;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Suit {
+    Spades,
+    Hearts,
+    Diamonds,
+    Clubs,
+}
+
+impl Suit {
+    pub const ALL: [Suit; 4] = [Suit::Spades, Suit::Hearts, Suit::Diamonds, Suit::Clubs];
+
+    pub fn symbol(self) -> char {
+        match self {
+            Suit::Spades => '♠',
+            Suit::Hearts => '♥',
+            Suit::Diamonds => '♦',
+            Suit::Clubs => '♣',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Card {
+    /// 1 (ace) through 13 (king).
+    pub rank: u8,
+    pub suit: Suit,
+}
+
+impl Card {
+    pub fn label(self) -> String {
+        let rank = match self.rank {
+            1 => "A".to_string(),
+            11 => "J".to_string(),
+            12 => "Q".to_string(),
+            13 => "K".to_string(),
+            n => n.to_string(),
+        };
+        format!("{}{}", rank, self.suit.symbol())
+    }
+
+    /// Blackjack value with aces counted as 1; `Hand::score` promotes them.
+    fn value(self) -> u32 {
+        u32::from(self.rank.min(10))
+    }
+}
+
+/// The dealer's shoe. Draws come off the end.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Deck {
+    cards: Vec<Card>,
+}
+
+impl Deck {
+    pub fn shuffled(rng: &mut impl Rng) -> Deck {
+        let mut cards: Vec<Card> = (0..SHOE_DECKS)
+            .flat_map(|_| Suit::ALL)
+            .flat_map(|suit| (1..=13).map(move |rank| Card { rank, suit }))
+            .collect();
+        cards.shuffle(rng);
+        Deck { cards }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Swaps in a fresh shoe if this one is running low.
+    pub fn reshuffle_if_low(&mut self, rng: &mut impl Rng) {
+        if self.len() < RESHUFFLE_AT {
+            *self = Deck::shuffled(rng);
+        }
+    }
+
+    pub fn draw(&mut self, rng: &mut impl Rng) -> Card {
+        if self.cards.is_empty() {
+            *self = Deck::shuffled(rng);
+        }
+        self.cards.pop().expect("a fresh shoe is never empty")
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Hand {
+    pub cards: Vec<Card>,
+}
+
+impl Hand {
+    /// Best total not over 21 where possible, counting one ace as 11 when it
+    /// fits.
+    pub fn score(&self) -> u32 {
+        let hard: u32 = self.cards.iter().map(|card| card.value()).sum();
+        let has_ace = self.cards.iter().any(|card| card.rank == 1);
+        if has_ace && hard + 10 <= 21 {
+            hard + 10
+        } else {
+            hard
+        }
+    }
+
+    pub fn is_bust(&self) -> bool {
+        self.score() > 21
+    }
+
+    pub fn is_blackjack(&self) -> bool {
+        self.cards.len() == 2 && self.score() == 21
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Blackjack,
+    Win,
+    Push,
+    Lose,
+    Bust,
+}
+
+impl Outcome {
+    /// What comes back to the player on `bet`, stake included.
+    pub fn payout(self, bet: u64) -> u64 {
+        match self {
+            Outcome::Blackjack => bet + bet * 3 / 2,
+            Outcome::Win => bet * 2,
+            Outcome::Push => bet,
+            Outcome::Lose | Outcome::Bust => 0,
+        }
+    }
+
+    pub fn describe(self) -> &'static str {
+        match self {
+            Outcome::Blackjack => "Blackjack!",
+            Outcome::Win => "You win",
+            Outcome::Push => "Push",
+            Outcome::Lose => "Dealer wins",
+            Outcome::Bust => "Bust",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlackjackError {
+    NotEnoughMoney {
+        bet: u64,
+    },
+    /// A hand is already being played.
+    RoundInProgress,
+    /// No hand to hit or stand on, or the player already stood or bust.
+    NotYourTurn,
+}
+
+impl fmt::Display for BlackjackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BlackjackError::NotEnoughMoney { bet } => {
+                write!(f, "You can't cover a {} bet", format_money(*bet))
+            }
+            BlackjackError::RoundInProgress => write!(f, "Finish the current hand first"),
+            BlackjackError::NotYourTurn => write!(f, "Deal a new hand first"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Round {
+    bet: u64,
+    player: Hand,
+    dealer: Hand,
+    outcome: Option<Outcome>,
+}
+
+/// Table state. Only the bet and the running total are saved; a hand in
+/// progress is dropped on quit along with its stake.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Blackjack {
+    /// Index into `casino::BETS`.
+    pub bet: usize,
+    pub net: i64,
+    #[serde(skip)]
+    deck: Deck,
+    #[serde(skip)]
+    round: Option<Round>,
+}
+
+impl Blackjack {
+    pub fn bet_amount(&self) -> u64 {
+        BETS[self.bet.min(BETS.len() - 1)]
+    }
+
+    /// The bet can't change while a hand is being played.
+    pub fn raise_bet(&mut self) {
+        if !self.in_play() {
+            self.bet = (self.bet + 1).min(BETS.len() - 1);
+        }
+    }
+
+    pub fn lower_bet(&mut self) {
+        if !self.in_play() {
+            self.bet = self.bet.saturating_sub(1);
+        }
+    }
+
+    /// True while the player can still hit or stand.
+    pub fn in_play(&self) -> bool {
+        self.round
+            .as_ref()
+            .is_some_and(|round| round.outcome.is_none())
+    }
+
+    /// Takes the bet and deals two cards each. A natural on either side
+    /// settles the hand straight away.
+    pub fn deal(
+        &mut self,
+        stats: &mut PlayerStats,
+        rng: &mut impl Rng,
+    ) -> Result<Option<Outcome>, BlackjackError> {
+        if self.in_play() {
+            return Err(BlackjackError::RoundInProgress);
+        }
+        let bet = self.bet_amount();
+        if stats.money < bet {
+            return Err(BlackjackError::NotEnoughMoney { bet });
+        }
+
+        stats.money -= bet;
+        self.net -= bet as i64;
+        self.deck.reshuffle_if_low(rng);
+        let mut round = Round {
+            bet,
+            ..Round::default()
+        };
+        for _ in 0..2 {
+            round.player.cards.push(self.deck.draw(rng));
+            round.dealer.cards.push(self.deck.draw(rng));
+        }
+        self.round = Some(round);
+
+        let player = self.player_hand().is_some_and(Hand::is_blackjack);
+        let dealer = self.dealer_hand().is_some_and(Hand::is_blackjack);
+        Ok(match (player, dealer) {
+            (true, true) => Some(self.settle(stats, Outcome::Push)),
+            (true, false) => Some(self.settle(stats, Outcome::Blackjack)),
+            (false, true) => Some(self.settle(stats, Outcome::Lose)),
+            (false, false) => None,
+        })
+    }
+
+    /// Draws a card for the player. Returns the outcome if that ended the
+    /// hand.
+    pub fn hit(
+        &mut self,
+        stats: &mut PlayerStats,
+        rng: &mut impl Rng,
+    ) -> Result<Option<Outcome>, BlackjackError> {
+        if !self.in_play() {
+            return Err(BlackjackError::NotYourTurn);
+        }
+        let card = self.deck.draw(rng);
+        let round = self.round.as_mut().expect("in_play checked the round");
+        round.player.cards.push(card);
+
+        if round.player.is_bust() {
+            return Ok(Some(self.settle(stats, Outcome::Bust)));
+        }
+        Ok(None)
+    }
+
+    /// Ends the player's turn and plays out the dealer's hand.
+    pub fn stand(
+        &mut self,
+        stats: &mut PlayerStats,
+        rng: &mut impl Rng,
+    ) -> Result<Outcome, BlackjackError> {
+        if !self.in_play() {
+            return Err(BlackjackError::NotYourTurn);
+        }
+        let round = self.round.as_mut().expect("in_play checked the round");
+        while round.dealer.score() < DEALER_STANDS_ON {
+            round.dealer.cards.push(self.deck.draw(rng));
+        }
+
+        let (player, dealer) = (round.player.score(), round.dealer.score());
+        let outcome = if round.dealer.is_bust() || player > dealer {
+            Outcome::Win
+        } else if player == dealer {
+            Outcome::Push
+        } else {
+            Outcome::Lose
+        };
+        Ok(self.settle(stats, outcome))
+    }
+
+    fn settle(&mut self, stats: &mut PlayerStats, outcome: Outcome) -> Outcome {
+        let round = self.round.as_mut().expect("settling needs a round");
+        let won = outcome.payout(round.bet);
+        stats.money += won;
+        self.net += won as i64;
+        round.outcome = Some(outcome);
+        outcome
+    }
+
+    pub fn player_hand(&self) -> Option<&Hand> {
+        self.round.as_ref().map(|round| &round.player)
+    }
+
+    pub fn dealer_hand(&self) -> Option<&Hand> {
+        self.round.as_ref().map(|round| &round.dealer)
+    }
+
+    pub fn outcome(&self) -> Option<Outcome> {
+        self.round.as_ref().and_then(|round| round.outcome)
+    }
+}
+
+/// Draws `cards` side by side as little boxed faces. With `hide_hole`, the
+/// second card is shown face down.
+fn card_faces(cards: &[Card], hide_hole: bool) -> String {
+    let mut top = Vec::new();
+    let mut middle = Vec::new();
+    let mut bottom = Vec::new();
+    for (i, card) in cards.iter().enumerate() {
+        let face = if hide_hole && i == 1 {
+            "░░░".to_string()
+        } else {
+            format!("{:<3}", card.label())
+        };
+        top.push("┌───┐".to_string());
+        middle.push(format!("│{}│", face));
+        bottom.push("└───┘".to_string());
+    }
+    [top, middle, bottom].map(|row| row.join(" ")).join("\n")
+}
+
+pub fn table_text(table: &Blackjack) -> String {
+    let (Some(player), Some(dealer)) = (table.player_hand(), table.dealer_hand()) else {
+        return "Blackjack\n\nd: deal  Left/Right: change bet\nb: switch to roulette".to_string();
+    };
+
+    let hide_hole = table.in_play();
+    let dealer_score = if hide_hole {
+        "?".to_string()
+    } else {
+        dealer.score().to_string()
+    };
+    let controls = if hide_hole {
+        "h: hit  s: stand"
+    } else {
+        "d: deal again  b: switch to roulette"
+    };
+    format!(
+        "Dealer ({})\n{}\n\nYou ({})\n{}\n\n{}",
+        dealer_score,
+        card_faces(&dealer.cards, hide_hole),
+        player.score(),
+        card_faces(&player.cards, false),
+        controls
+    )
+}
+
+pub fn summary_text(table: &Blackjack) -> String {
+    let last = match table.outcome() {
+        Some(outcome) => outcome.describe(),
+        None if table.in_play() => "Your move",
+        None => "No hands yet",
+    };
+    let net = if table.net < 0 {
+        format!("-{}", format_money(table.net.unsigned_abs()))
+    } else {
+        format!("+{}", format_money(table.net.unsigned_abs()))
+    };
+    format!(
+        "Bet: {}\nResult: {}\nWin/loss: {}\n\nBlackjack pays 3:2, dealer stands on {}.",
+        format_money(table.bet_amount()),
+        last,
+        net,
+        DEALER_STANDS_ON
+    )
+}
@@ -0,0 +1,171 @@
+use crate::clock::{self, MINUTES_PER_DAY, MINUTES_PER_HOUR};
+use crate::money::format_money;
+use crate::state::GameState;
+
+/// When an event happens, in game time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Schedule {
+    /// Every day at this hour.
+    Daily { hour: u64 },
+    /// Once a week; `weekday` 0 is Monday.
+    Weekly { weekday: u64, hour: u64 },
+    /// A single occurrence at this many game minutes since day 1, 00:00.
+    OneTime { at: u64 },
+}
+
+impl Schedule {
+    /// The first occurrence strictly after `after`, if there is one.
+    pub fn next_after(self, after: u64) -> Option<u64> {
+        let (period, offset) = match self {
+            Schedule::Daily { hour } => (MINUTES_PER_DAY, hour * MINUTES_PER_HOUR),
+            Schedule::Weekly { weekday, hour } => (
+                7 * MINUTES_PER_DAY,
+                weekday * MINUTES_PER_DAY + hour * MINUTES_PER_HOUR,
+            ),
+            Schedule::OneTime { at } => return (at > after).then_some(at),
+        };
+        let cycles = if after < offset {
+            0
+        } else {
+            (after - offset) / period + 1
+        };
+        Some(offset + cycles * period)
+    }
+}
+
+pub struct Event {
+    pub name: &'static str,
+    pub schedule: Schedule,
+    /// Money granted when the event comes round, if any.
+    pub reward: Option<u64>,
+}
+
+pub const EVENTS: [Event; 5] = [
+    Event {
+        name: "Morning market",
+        schedule: Schedule::Daily { hour: 8 },
+        reward: None,
+    },
+    Event {
+        name: "Gym happy hour",
+        schedule: Schedule::Daily { hour: 18 },
+        reward: None,
+    },
+    Event {
+        name: "Friday bonus",
+        schedule: Schedule::Weekly {
+            weekday: 4,
+            hour: 17,
+        },
+        reward: Some(100),
+    },
+    Event {
+        name: "Casino night",
+        schedule: Schedule::Weekly {
+            weekday: 5,
+            hour: 20,
+        },
+        reward: None,
+    },
+    Event {
+        name: "City festival",
+        schedule: Schedule::OneTime {
+            at: 2 * MINUTES_PER_DAY + 12 * MINUTES_PER_HOUR,
+        },
+        reward: Some(250),
+    },
+];
+
+/// Every occurrence in `(from, to]`, in time order.
+pub fn occurrences(from: u64, to: u64) -> Vec<(u64, &'static Event)> {
+    let mut due = Vec::new();
+    for event in &EVENTS {
+        let mut after = from;
+        while let Some(at) = event.schedule.next_after(after).filter(|&at| at <= to) {
+            due.push((at, event));
+            after = at;
+        }
+    }
+    due.sort_by_key(|(at, _)| *at);
+    due
+}
+
+/// Announces events that happened after the `since` game time, up to the
+/// clock's current time, and pays their rewards. Returns true if any fired.
+pub fn trigger(state: &mut GameState, since: u64) -> bool {
+    let due = occurrences(since, state.clock.minutes());
+    for (_, event) in &due {
+        match event.reward {
+            Some(reward) => {
+                state.player.money += reward;
+                state.log_news(format!(
+                    "{}! You received {}.",
+                    event.name,
+                    format_money(reward)
+                ));
+            }
+            None => state.log_news(format!("{} has started.", event.name)),
+        }
+    }
+    !due.is_empty()
+}
+
+pub fn today_text(now: u64) -> String {
+    let start = (clock::day_of(now) - 1) * MINUTES_PER_DAY;
+    // `occurrences` excludes its start, so begin one minute early to catch
+    // midnight events.
+    let today = occurrences(start.saturating_sub(1), start + MINUTES_PER_DAY - 1);
+    if today.is_empty() {
+        return "Nothing on today.".to_string();
+    }
+    today
+        .iter()
+        .map(|(at, event)| {
+            let status = if *at <= now { "done" } else { "" };
+            format!("{}  {:<16} {}", time_of_day(*at), event.name, status)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The next `count` events after `now`.
+pub fn upcoming_text(now: u64, count: usize) -> String {
+    let mut upcoming: Vec<(u64, &Event)> = EVENTS
+        .iter()
+        .flat_map(|event| {
+            let mut after = now;
+            std::iter::from_fn(move || {
+                let at = event.schedule.next_after(after)?;
+                after = at;
+                Some((at, event))
+            })
+            .take(count)
+        })
+        .collect();
+    upcoming.sort_by_key(|(at, _)| *at);
+    upcoming.truncate(count);
+
+    if upcoming.is_empty() {
+        return "Nothing scheduled.".to_string();
+    }
+    upcoming
+        .iter()
+        .map(|(at, event)| {
+            let reward = event
+                .reward
+                .map(|reward| format!(" (+{})", format_money(reward)))
+                .unwrap_or_default();
+            format!("{}  {}{}", clock::format_time(*at), event.name, reward)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn time_of_day(minutes: u64) -> String {
+    let of_day = minutes % MINUTES_PER_DAY;
+    format!(
+        "{:02}:{:02}",
+        of_day / MINUTES_PER_HOUR,
+        of_day % MINUTES_PER_HOUR
+    )
+}
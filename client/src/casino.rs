@@ -0,0 +1,215 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::money::format_money;
+use crate::player::PlayerStats;
+
+/// Which table the Casino page is showing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CasinoGame {
+    #[default]
+    Slots,
+    Blackjack,
+    Roulette,
+}
+
+impl CasinoGame {
+    /// The table after this one, for the page's `b` key.
+    pub fn next(self) -> CasinoGame {
+        match self {
+            CasinoGame::Slots => CasinoGame::Blackjack,
+            CasinoGame::Blackjack => CasinoGame::Roulette,
+            CasinoGame::Roulette => CasinoGame::Slots,
+        }
+    }
+}
+
+/// Reel faces, with what three of a kind pays as a multiple of the bet.
+/// Any two matching faces give the bet back.
+pub const PAYOUTS: [(&str, u64); 5] = [
+    ("Cherry", 3),
+    ("Lemon", 4),
+    ("Bell", 6),
+    ("Bar", 10),
+    ("Seven", 20),
+];
+
+/// Bets the slots page cycles through.
+pub const BETS: [u64; 4] = [10, 25, 50, 100];
+
+/// How long each reel keeps spinning; reel `i` stops after `(i + 1)` of these.
+pub const REEL_SPIN: Duration = Duration::from_millis(400);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlotsError {
+    NotEnoughMoney {
+        bet: u64,
+    },
+    /// The reels haven't stopped from the last spin yet.
+    StillSpinning,
+}
+
+impl fmt::Display for SlotsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SlotsError::NotEnoughMoney { bet } => {
+                write!(f, "You can't cover a {} bet", format_money(*bet))
+            }
+            SlotsError::StillSpinning => write!(f, "The reels are still spinning"),
+        }
+    }
+}
+
+/// A spin whose result is decided but still being animated.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Spin {
+    started: Instant,
+    bet: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Slots {
+    /// Index into `BETS`.
+    pub bet: usize,
+    /// Faces the reels last stopped on, as indexes into `PAYOUTS`.
+    pub reels: [usize; 3],
+    /// What the last settled spin paid out, if the reels have ever stopped.
+    pub last_payout: Option<u64>,
+    /// Running total of winnings minus bets.
+    pub net: i64,
+    #[serde(skip)]
+    spin: Option<Spin>,
+}
+
+impl Slots {
+    pub fn bet_amount(&self) -> u64 {
+        BETS[self.bet.min(BETS.len() - 1)]
+    }
+
+    pub fn raise_bet(&mut self) {
+        self.bet = (self.bet + 1).min(BETS.len() - 1);
+    }
+
+    pub fn lower_bet(&mut self) {
+        self.bet = self.bet.saturating_sub(1);
+    }
+
+    pub fn is_spinning(&self) -> bool {
+        self.spin.is_some()
+    }
+
+    /// Takes the bet and rolls the reels. The result stays hidden until
+    /// `finish_spin` settles it once the animation has played.
+    pub fn spin(
+        &mut self,
+        stats: &mut PlayerStats,
+        now: Instant,
+        rng: &mut impl Rng,
+    ) -> Result<(), SlotsError> {
+        if self.is_spinning() {
+            return Err(SlotsError::StillSpinning);
+        }
+        let bet = self.bet_amount();
+        if stats.money < bet {
+            return Err(SlotsError::NotEnoughMoney { bet });
+        }
+
+        stats.money -= bet;
+        self.net -= bet as i64;
+        self.reels = [(); 3].map(|_| rng.random_range(0..PAYOUTS.len()));
+        self.spin = Some(Spin { started: now, bet });
+        Ok(())
+    }
+
+    /// Pays out a spin whose reels have all stopped. Returns the payout so
+    /// the caller can announce it.
+    pub fn finish_spin(&mut self, stats: &mut PlayerStats, now: Instant) -> Option<u64> {
+        let spin = self.spin?;
+        if now.saturating_duration_since(spin.started) < REEL_SPIN * self.reels.len() as u32 {
+            return None;
+        }
+
+        self.spin = None;
+        let won = payout(self.reels, spin.bet);
+        stats.money += won;
+        self.net += won as i64;
+        self.last_payout = Some(won);
+        Some(won)
+    }
+
+    /// The faces to draw right now: stopped reels show their result, the rest
+    /// tick through the faces.
+    pub fn visible_reels(&self, now: Instant) -> [&'static str; 3] {
+        let mut faces = self.reels.map(|face| PAYOUTS[face].0);
+        if let Some(spin) = self.spin {
+            let elapsed = now.saturating_duration_since(spin.started);
+            let ticks = (elapsed.as_millis() / 80) as usize;
+            for (i, face) in faces.iter_mut().enumerate() {
+                if elapsed < REEL_SPIN * (i as u32 + 1) {
+                    *face = PAYOUTS[(ticks + i * 2) % PAYOUTS.len()].0;
+                }
+            }
+        }
+        faces
+    }
+}
+
+/// What `reels` pay back on a `bet`, including the stake itself.
+pub fn payout(reels: [usize; 3], bet: u64) -> u64 {
+    let [a, b, c] = reels;
+    if a == b && b == c {
+        bet * PAYOUTS[a].1
+    } else if a == b || b == c || a == c {
+        bet
+    } else {
+        0
+    }
+}
+
+pub fn slots_text(slots: &Slots, now: Instant) -> String {
+    let faces = slots
+        .visible_reels(now)
+        .map(|face| format!("[{:^8}]", face))
+        .join(" ");
+    let mut lines = vec![
+        "Slots".to_string(),
+        String::new(),
+        faces,
+        String::new(),
+        "s: spin  Left/Right: change bet".to_string(),
+        "b: switch to blackjack".to_string(),
+        String::new(),
+        "Three of a kind pays:".to_string(),
+    ];
+    lines.extend(
+        PAYOUTS
+            .iter()
+            .map(|(face, multiplier)| format!("  {:<8} x{}", face, multiplier)),
+    );
+    lines.push("Any pair returns the bet.".to_string());
+    lines.join("\n")
+}
+
+pub fn slots_summary_text(slots: &Slots) -> String {
+    let last = match slots.last_payout {
+        _ if slots.is_spinning() => "Spinning...".to_string(),
+        Some(0) => "No win".to_string(),
+        Some(won) => format!("Won {}", format_money(won)),
+        None => "No spins yet".to_string(),
+    };
+    let net = if slots.net < 0 {
+        format!("-{}", format_money(slots.net.unsigned_abs()))
+    } else {
+        format!("+{}", format_money(slots.net.unsigned_abs()))
+    };
+    format!(
+        "Bet: {}\nLast result: {}\nWin/loss: {}",
+        format_money(slots.bet_amount()),
+        last,
+        net
+    )
+}
@@ -0,0 +1,60 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+pub const MINUTES_PER_HOUR: u64 = 60;
+pub const MINUTES_PER_DAY: u64 = 24 * MINUTES_PER_HOUR;
+
+const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+/// In-game time. One game minute passes per real second of play, so a game
+/// day lasts 24 real minutes. Only time spent with the game open counts, and
+/// the total is saved so the calendar carries on where it left off.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameClock {
+    elapsed: Duration,
+}
+
+impl GameClock {
+    /// Moves the clock on by `real` time; called once per main loop tick.
+    pub fn advance(&mut self, real: Duration) {
+        self.elapsed += real;
+    }
+
+    /// Real time played, for timing things against the game clock.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Game minutes since day 1, 00:00.
+    pub fn minutes(&self) -> u64 {
+        self.elapsed.as_secs()
+    }
+
+    pub fn day(&self) -> u64 {
+        day_of(self.minutes())
+    }
+}
+
+/// Day number of a game time, starting from day 1.
+pub fn day_of(minutes: u64) -> u64 {
+    minutes / MINUTES_PER_DAY + 1
+}
+
+/// Day 1 is a Monday.
+pub fn weekday_of(minutes: u64) -> &'static str {
+    WEEKDAYS[((day_of(minutes) - 1) % 7) as usize]
+}
+
+/// `Day 3 (Wed) 14:05`.
+pub fn format_time(minutes: u64) -> String {
+    let of_day = minutes % MINUTES_PER_DAY;
+    format!(
+        "Day {} ({}) {:02}:{:02}",
+        day_of(minutes),
+        weekday_of(minutes),
+        of_day / MINUTES_PER_HOUR,
+        of_day % MINUTES_PER_HOUR
+    )
+}
@@ -0,0 +1,146 @@
+//! What the terminal can show. Themes are written with whatever colors look
+//! best, and anything the terminal can't show is swapped for the nearest
+//! color it can, so a truecolor theme still reads on a 16-color terminal.
+
+use std::env;
+use std::sync::OnceLock;
+
+use ratatui::style::{Color, Style};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ColorDepth {
+    /// The 16 named ANSI colors.
+    Basic,
+    /// The xterm 256-color palette.
+    Indexed,
+    /// Any RGB color.
+    TrueColor,
+}
+
+impl ColorDepth {
+    pub fn name(self) -> &'static str {
+        match self {
+            ColorDepth::Basic => "16 colors",
+            ColorDepth::Indexed => "256 colors",
+            ColorDepth::TrueColor => "truecolor (24-bit)",
+        }
+    }
+
+    /// `RUSTY_COLORS`'s value, which overrides detection.
+    fn parse(value: &str) -> Option<ColorDepth> {
+        match value.to_lowercase().as_str() {
+            "16" | "basic" => Some(ColorDepth::Basic),
+            "256" => Some(ColorDepth::Indexed),
+            "truecolor" | "24bit" => Some(ColorDepth::TrueColor),
+            _ => None,
+        }
+    }
+}
+
+/// Works the depth out from the environment: `RUSTY_COLORS` if it's set to
+/// something we know, then `COLORTERM`, then `TERM`.
+fn detect() -> ColorDepth {
+    if let Some(depth) = env::var("RUSTY_COLORS")
+        .ok()
+        .and_then(|value| ColorDepth::parse(&value))
+    {
+        return depth;
+    }
+    let colorterm = env::var("COLORTERM").unwrap_or_default().to_lowercase();
+    if colorterm == "truecolor" || colorterm == "24bit" {
+        return ColorDepth::TrueColor;
+    }
+    if env::var("TERM").unwrap_or_default().contains("256color") {
+        return ColorDepth::Indexed;
+    }
+    ColorDepth::Basic
+}
+
+/// The terminal's color depth, detected the first time it's asked for.
+pub fn depth() -> ColorDepth {
+    static DEPTH: OnceLock<ColorDepth> = OnceLock::new();
+    *DEPTH.get_or_init(detect)
+}
+
+/// The 16 named colors with xterm's default values for them, in palette
+/// order.
+const BASIC: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// Channel values of the 6x6x6 cube at indices 16-231.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// The RGB value of a 256-palette index.
+fn indexed_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => BASIC[usize::from(index)].1,
+        16..=231 => {
+            let i = index - 16;
+            (
+                CUBE_LEVELS[usize::from(i / 36)],
+                CUBE_LEVELS[usize::from(i / 6 % 6)],
+                CUBE_LEVELS[usize::from(i % 6)],
+            )
+        }
+        _ => {
+            let level = 8 + (index - 232) * 10;
+            (level, level, level)
+        }
+    }
+}
+
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let channel = |x: u8, y: u8| (i32::from(x) - i32::from(y)).unsigned_abs().pow(2);
+    channel(a.0, b.0) + channel(a.1, b.1) + channel(a.2, b.2)
+}
+
+/// The closest entry of the 256-color palette to `rgb`.
+fn nearest_indexed(rgb: (u8, u8, u8)) -> u8 {
+    (0..=255)
+        .min_by_key(|&index| distance(indexed_rgb(index), rgb))
+        .unwrap_or(0)
+}
+
+fn nearest_basic(rgb: (u8, u8, u8)) -> Color {
+    BASIC
+        .iter()
+        .min_by_key(|(_, value)| distance(*value, rgb))
+        .map(|(color, _)| *color)
+        .unwrap_or(Color::Reset)
+}
+
+/// `color` as `depth` can show it.
+pub fn fit(color: Color, depth: ColorDepth) -> Color {
+    match (color, depth) {
+        (Color::Rgb(r, g, b), ColorDepth::Indexed) => Color::Indexed(nearest_indexed((r, g, b))),
+        (Color::Rgb(r, g, b), ColorDepth::Basic) => nearest_basic((r, g, b)),
+        (Color::Indexed(index), ColorDepth::Basic) => nearest_basic(indexed_rgb(index)),
+        _ => color,
+    }
+}
+
+/// `style` with its colors fitted to `depth`.
+pub fn fit_style(style: Style, depth: ColorDepth) -> Style {
+    Style {
+        fg: style.fg.map(|color| fit(color, depth)),
+        bg: style.bg.map(|color| fit(color, depth)),
+        underline_color: style.underline_color.map(|color| fit(color, depth)),
+        ..style
+    }
+}
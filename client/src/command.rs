@@ -0,0 +1,1035 @@
+use std::fmt;
+use std::path::PathBuf;
+use std::time::Instant;
+
+use rand::Rng;
+
+use crate::action::Action;
+use crate::bank;
+use crate::color;
+use crate::crime::{self, Crime, CrimeOutcome};
+use crate::daily;
+use crate::deadline;
+use crate::decay;
+use crate::education;
+use crate::error::{self, RustyError};
+use crate::faction::{self, FACTION_COST};
+use crate::fight::{self, FightOutcome};
+use crate::forums;
+use crate::gym::{self, TRAIN_ENERGY, TrainingEntry};
+use crate::hospital;
+use crate::inventory::Quantity;
+use crate::jail;
+use crate::job;
+use crate::money::format_money;
+use crate::player::Stat;
+use crate::referral;
+use crate::roulette::{self, Bet, BetKind};
+use crate::rules;
+use crate::state::{GameState, PAGES};
+use crate::theme::ThemeName;
+
+/// Outcome of a line typed into the Input box.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CommandResult {
+    /// Blank input; nothing to report.
+    Empty,
+    /// The command ran and has something to say.
+    Message(String),
+    /// Open this page, even if it's hidden from the menu.
+    Goto(&'static str),
+    /// The command ran, but it went badly for the player: caught, beaten
+    /// up or put in the hospital.
+    Setback(String),
+    /// The command wants to run this action, once the player confirms it
+    /// if it's one that needs confirming.
+    Confirm(Action),
+    /// Play the first-launch tutorial again.
+    Tutorial,
+    /// Take back the last reversible action.
+    Undo,
+    /// Write the game state as JSON to this file.
+    Export(PathBuf),
+    /// Stop the clock until the player resumes.
+    Pause,
+}
+
+/// Every command with a one-line description; feeds both `help` and the
+/// help overlay.
+pub const COMMANDS: &[(&str, &str)] = &[
+    ("goto <page>", "Open a page by name or part of one"),
+    ("train <stat> [energy]", "Train at the Gym"),
+    ("buy item <n>", "Buy the nth item in the City shop"),
+    (
+        "use [n|all] <item>",
+        "Use one or more of an item you're carrying",
+    ),
+    ("sell [n|all] <item>", "Sell one or more of an item"),
+    ("apply <job>", "Apply for a job by title"),
+    (
+        "bet <target> [amount]",
+        "Add a roulette bet: red, black, odd, even or 0-36",
+    ),
+    ("deposit <amount|all>", "Put cash in the bank"),
+    ("withdraw <amount|all>", "Take money out of the bank"),
+    ("faction create|join <name>", "Found or join a faction"),
+    (
+        "faction deposit <amount>",
+        "Pay into your faction's treasury",
+    ),
+    ("faction leave", "Leave your faction"),
+    ("daily", "Claim today's daily reward"),
+    ("claim", "Collect rewards for your recruits"),
+    ("reply <text>", "Reply to the selected Forums thread"),
+    ("report <rule> <npc>", "Report an NPC for breaking a rule"),
+    (
+        "recruit <name>",
+        "Debug: pretend a player joined with your code",
+    ),
+    ("set wrap on|off", "Wrap menu navigation at the ends"),
+    ("set tick <ms>", "Time between screen updates (10-1000)"),
+    ("set autosave <secs>", "Time between autosaves (30-3600)"),
+    ("set undo <steps>", "Actions undo can take back (0-10)"),
+    ("set decay on|off", "Let untrained stats drop each day"),
+    (
+        "set decay-rate <n>",
+        "Daily decay in tenths of a percent (1-50)",
+    ),
+    ("set mouse on|off", "Let the app use the mouse"),
+    ("set transitions on|off", "Slide between pages"),
+    ("set repeat on|off", "Hold the train or crime key to repeat"),
+    ("set demo <secs|off>", "Play a demo after this long idle"),
+    (
+        "set spend <percent>",
+        "Confirm buys and bets over this share of cash",
+    ),
+    (
+        "set bell on|off",
+        "Beep on level-ups, jail and achievements",
+    ),
+    ("set quiet on|off", "Silence the bell for now"),
+    (
+        "set currency <symbol>",
+        "Symbol written before money, such as $ or €",
+    ),
+    (
+        "set separator <name>",
+        "Thousands separator: comma, dot, space, apostrophe or none",
+    ),
+    ("menu up|down <page>", "Move a page up or down the menu"),
+    (
+        "menu hide|show <page>",
+        "Hide a page from the menu, or bring it back",
+    ),
+    ("theme <name>", "Switch color theme"),
+    ("colors", "Show how many colors the terminal supports"),
+    (
+        "export <path>",
+        "Write the game state as JSON, for bug reports",
+    ),
+    ("reset", "Start a new game, deleting the save"),
+    ("tutorial", "Replay the walkthrough for new players"),
+    ("undo", "Take back the last buy, sell or discard"),
+    ("pause", "Stop the clock until you resume"),
+    ("help", "List the commands"),
+];
+
+/// Longest command line, in characters, the parser will look at.
+pub const MAX_INPUT_LEN: usize = 200;
+
+/// Why a command line was turned away before it was parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputError {
+    /// Longer than `MAX_INPUT_LEN`; holds the length it was.
+    TooLong(usize),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::TooLong(len) => write!(
+                f,
+                "Input too long ({} characters, max {})",
+                len, MAX_INPUT_LEN
+            ),
+        }
+    }
+}
+
+/// The command line as the parser sees it: control characters stripped and
+/// the ends trimmed. Anything past `MAX_INPUT_LEN` is almost certainly a
+/// stray paste, so it's rejected rather than cut short.
+pub fn clean_input(input: &str) -> Result<String, InputError> {
+    let cleaned: String = input.chars().filter(|c| !c.is_control()).collect();
+    let cleaned = cleaned.trim();
+    let len = cleaned.chars().count();
+    if len > MAX_INPUT_LEN {
+        return Err(InputError::TooLong(len));
+    }
+    Ok(cleaned.to_string())
+}
+
+impl From<InputError> for RustyError {
+    fn from(err: InputError) -> Self {
+        RustyError::Game(err.to_string())
+    }
+}
+
+/// Turns a command down, with `reason` for the player.
+fn refuse<T>(reason: impl fmt::Display) -> error::Result<T> {
+    Err(RustyError::game(reason))
+}
+
+/// Cleans `input`, tokenizes it on whitespace and runs it against `state`.
+/// The verb is matched case-insensitively; errors name the token that wasn't
+/// understood. `now` is the frame's time, for checking jail and hospital.
+pub fn parse_command(
+    input: &str,
+    state: &mut GameState,
+    now: Instant,
+) -> error::Result<CommandResult> {
+    let input = clean_input(input)?;
+    let mut tokens = input.split_whitespace();
+    let Some(verb) = tokens.next() else {
+        return Ok(CommandResult::Empty);
+    };
+    let args: Vec<&str> = tokens.collect();
+
+    match verb.to_lowercase().as_str() {
+        "help" => {
+            let usages: Vec<&str> = COMMANDS.iter().map(|(usage, _)| *usage).collect();
+            Ok(CommandResult::Message(format!(
+                "Commands: {}",
+                usages.join(", ")
+            )))
+        }
+        "goto" => goto(&args, state, now),
+        "train" => train(&args, state, now),
+        "buy" => buy(&args),
+        "use" => use_item(&args, state),
+        "sell" => sell_item(&args, state),
+        "deposit" => deposit(&args, state),
+        "bet" => bet(&args, state),
+        "withdraw" => withdraw(&args, state),
+        "apply" => apply(&args, state),
+        "faction" => faction(&args, state),
+        "daily" => claim_daily(state),
+        "claim" => claim_rewards(state),
+        "recruit" => recruit(&args, state),
+        "report" => report(&args, state),
+        "reply" => reply(&args, state),
+        "set" => set(&args, state),
+        "menu" => menu(&args, state),
+        "theme" => theme(&args, state),
+        "colors" => Ok(CommandResult::Message(format!(
+            "Terminal colors: {}. Set RUSTY_COLORS to 16, 256 or truecolor to override.",
+            color::depth().name()
+        ))),
+        "reset" => Ok(CommandResult::Confirm(Action::ResetGame)),
+        "tutorial" => Ok(CommandResult::Tutorial),
+        "undo" => Ok(CommandResult::Undo),
+        "pause" => Ok(CommandResult::Pause),
+        "export" => export(&args),
+        _ => refuse(format!("Unknown command '{}'", verb)),
+    }
+}
+
+/// Whether `undo` can take back what the command line `input` does.
+pub fn is_reversible(input: &str) -> bool {
+    matches!(
+        input
+            .split_whitespace()
+            .next()
+            .map(str::to_lowercase)
+            .as_deref(),
+        Some("buy")
+    )
+}
+
+fn goto(args: &[&str], state: &GameState, now: Instant) -> error::Result<CommandResult> {
+    if args.is_empty() {
+        return refuse("Usage: goto <page>".to_string());
+    }
+
+    let name = args.join(" ");
+    match match_pages(&name).as_slice() {
+        [page] => match state.blocked_reason(page, now) {
+            Some(reason) => refuse(reason.to_string()),
+            None => Ok(CommandResult::Goto(page)),
+        },
+        [] => refuse(format!("Unknown page '{}'", name)),
+        pages => refuse(format!("'{}' could be {}", name, pages.join(", "))),
+    }
+}
+
+/// Pages `name` could mean, ignoring case. The closest kind of match wins:
+/// the whole name, then the start of it (`hosp`), then any part of it
+/// (`fame`), then its letters in order (`hof`).
+fn match_pages(name: &str) -> Vec<&'static str> {
+    let name = name.to_lowercase();
+    let is_subsequence = |page: &str| {
+        let mut letters = page.chars();
+        name.chars().all(|c| letters.any(|p| p == c))
+    };
+    let tiers: [&dyn Fn(&str) -> bool; 4] = [
+        &|page| page == name,
+        &|page| page.starts_with(&name),
+        &|page| page.contains(&name),
+        &is_subsequence,
+    ];
+    for tier in tiers {
+        let pages: Vec<&'static str> = PAGES
+            .iter()
+            .filter(|page| tier(&page.to_lowercase()))
+            .copied()
+            .collect();
+        if !pages.is_empty() {
+            return pages;
+        }
+    }
+    Vec::new()
+}
+
+fn train(args: &[&str], state: &mut GameState, now: Instant) -> error::Result<CommandResult> {
+    let (name, energy) = match args {
+        [name] => (*name, TRAIN_ENERGY),
+        [name, energy] => match energy.parse() {
+            Ok(energy) => (*name, energy),
+            Err(_) => return refuse(format!("Invalid energy amount '{}'", energy)),
+        },
+        _ => return refuse("Usage: train <stat> [energy]".to_string()),
+    };
+
+    if let Some(reason) = state.blocked_reason("Gym", now) {
+        return refuse(reason.to_string());
+    }
+    match Stat::parse(name) {
+        Some(stat) => train_stat(state, stat, energy),
+        None => refuse(format!("Unknown stat '{}'", name)),
+    }
+}
+
+/// Trains `stat` at the basic rate. Used by the `train` command.
+pub fn train_stat(state: &mut GameState, stat: Stat, energy: u32) -> error::Result<CommandResult> {
+    let result = gym::train_stat(stat, energy, 1.0, &mut state.player);
+    record_training(state, stat, result)
+}
+
+/// One session on the machine at `index` in `gym::MACHINES`. Used by the Gym
+/// page's train key.
+pub fn train_on_machine(state: &mut GameState, index: usize) -> error::Result<CommandResult> {
+    let machine = &gym::MACHINES[index];
+    let result = gym::train_on(machine, &mut state.player);
+    record_training(state, machine.stat, result)
+}
+
+/// Logs a finished session in the Gym's training log and for stat decay.
+fn record_training(
+    state: &mut GameState,
+    stat: Stat,
+    result: Result<u32, gym::TrainError>,
+) -> error::Result<CommandResult> {
+    match result {
+        Ok(gain) => {
+            gym::log_training(&mut state.training_log, TrainingEntry { stat, gain });
+            decay::record_training(state, stat);
+            Ok(CommandResult::Message(format!(
+                "Trained {}: +{}",
+                stat.name(),
+                gain
+            )))
+        }
+        Err(err) => refuse(err),
+    }
+}
+
+fn buy(args: &[&str]) -> error::Result<CommandResult> {
+    match args {
+        ["item", number] => match number.parse::<usize>() {
+            Ok(n @ 1..) => Ok(CommandResult::Confirm(Action::BuyItem(n - 1))),
+            _ => refuse(format!("Invalid item number '{}'", number)),
+        },
+        [other, ..] => refuse(format!("Unknown token '{}'", other)),
+        [] => refuse("Usage: buy item <n>".to_string()),
+    }
+}
+
+/// The stack named after an optional quantity, as `use` and `sell` take
+/// them: `5 candy`, `all candy` or just `candy` for one.
+fn quantity_and_stack(args: &[&str], state: &GameState) -> Result<(Quantity, usize), String> {
+    let (quantity, name) = match args {
+        [first, rest @ ..] if !rest.is_empty() => match Quantity::parse(first) {
+            Some(quantity) => (quantity, rest.join(" ")),
+            None => (Quantity::default(), args.join(" ")),
+        },
+        _ => (Quantity::default(), args.join(" ")),
+    };
+    state
+        .inventory
+        .items
+        .iter()
+        .position(|stack| stack.item.name().eq_ignore_ascii_case(&name))
+        .map(|index| (quantity, index))
+        .ok_or_else(|| format!("You have no '{}'", name))
+}
+
+fn use_item(args: &[&str], state: &mut GameState) -> error::Result<CommandResult> {
+    if args.is_empty() {
+        return refuse("Usage: use [n|all] <item>".to_string());
+    }
+    let (quantity, index) = match quantity_and_stack(args, state) {
+        Ok(found) => found,
+        Err(err) => return refuse(err),
+    };
+    use_items(state, index, quantity)
+}
+
+/// Uses `quantity` of the stack at `index`. Used by the `use` command and
+/// the Items page's use key.
+pub fn use_items(
+    state: &mut GameState,
+    index: usize,
+    quantity: Quantity,
+) -> error::Result<CommandResult> {
+    match state
+        .inventory
+        .use_items(index, quantity, &mut state.player)
+    {
+        Some((item, 1)) => Ok(CommandResult::Message(format!("Used {}.", item.name()))),
+        Some((item, count)) => Ok(CommandResult::Message(format!(
+            "Used {} {}.",
+            count,
+            item.name()
+        ))),
+        None => Ok(CommandResult::Empty),
+    }
+}
+
+fn sell_item(args: &[&str], state: &GameState) -> error::Result<CommandResult> {
+    if args.is_empty() {
+        return refuse("Usage: sell [n|all] <item>".to_string());
+    }
+    match quantity_and_stack(args, state) {
+        Ok((quantity, index)) => Ok(CommandResult::Confirm(Action::SellItems(index, quantity))),
+        Err(err) => refuse(err),
+    }
+}
+
+fn apply(args: &[&str], state: &mut GameState) -> error::Result<CommandResult> {
+    if args.is_empty() {
+        return refuse("Usage: apply <job>".to_string());
+    }
+
+    let title = args.join(" ");
+    let Some(index) = job::find(&title) else {
+        return refuse(format!("Unknown job '{}'", title));
+    };
+    match job::apply(state, index) {
+        Ok(job) => Ok(CommandResult::Message(format!(
+            "You're now a {}, earning {} every {}s.",
+            job.title,
+            format_money(job.salary_per_cycle),
+            job::PAY_INTERVAL.as_secs()
+        ))),
+        Err(err) => refuse(err),
+    }
+}
+
+/// Works the current job's task at `index`. Used by the Job page's work key.
+pub fn complete_task(state: &mut GameState, index: usize) -> error::Result<CommandResult> {
+    match job::complete_task(state, index) {
+        Ok(bonus) => Ok(CommandResult::Message(format!(
+            "Task done: +{}",
+            format_money(bonus)
+        ))),
+        Err(err) => refuse(err),
+    }
+}
+
+/// Attacks the City target at `index`. Used by the City page's attack key.
+pub fn attack(
+    state: &mut GameState,
+    index: usize,
+    now: Instant,
+    rng: &mut impl Rng,
+) -> error::Result<CommandResult> {
+    match fight::attack(state, index, now, rng) {
+        Ok((target, FightOutcome::Won { rounds, .. })) => Ok(CommandResult::Message(format!(
+            "You beat the {} in {} rounds: +{}",
+            target.name.to_lowercase(),
+            rounds,
+            format_money(target.reward_money)
+        ))),
+        Ok((target, FightOutcome::Lost { .. })) => Ok(CommandResult::Setback(format!(
+            "The {} beat you. Hospitalized for {}.",
+            target.name.to_lowercase(),
+            deadline::format_remaining(target.stay)
+        ))),
+        Err(err) => refuse(err),
+    }
+}
+
+/// Buys the property at `index`. Used by the Properties page's buy key.
+pub fn buy_property(state: &mut GameState, index: usize) -> error::Result<CommandResult> {
+    match state.real_estate.buy(index, &mut state.player) {
+        Ok(property) => Ok(CommandResult::Message(format!(
+            "Bought the {} for {}.",
+            property.name.to_lowercase(),
+            format_money(property.price)
+        ))),
+        Err(err) => refuse(err),
+    }
+}
+
+/// Carries out an `Action`, once any confirmation it needed has been given.
+pub fn run_action(
+    state: &mut GameState,
+    action: Action,
+    now: Instant,
+    rng: &mut impl Rng,
+) -> error::Result<CommandResult> {
+    match action {
+        Action::BuyItem(index) => buy_item(state, index),
+        Action::BuyProperty(index) => buy_property(state, index),
+        Action::PlaceBet(bet) => place_roulette_bet(state, bet),
+        Action::SpinSlots => spin_slots(state, now, rng),
+        Action::DealBlackjack => play_blackjack(state, 'd', rng),
+        Action::DiscardItem(index) => match state.inventory.discard_item(index) {
+            Some(item) => Ok(CommandResult::Message(format!(
+                "Discarded {}.",
+                item.name()
+            ))),
+            None => Ok(CommandResult::Empty),
+        },
+        Action::SellItems(index, quantity) => {
+            match state
+                .inventory
+                .sell_items(index, quantity, &mut state.player)
+            {
+                Ok((item, count, paid)) => Ok(CommandResult::Message(format!(
+                    "Sold {} {} for {}.",
+                    count,
+                    item.name(),
+                    format_money(paid)
+                ))),
+                Err(err) => refuse(err),
+            }
+        }
+        Action::SellProperty(index) => match state.real_estate.sell(index, &mut state.player) {
+            Ok(paid) => Ok(CommandResult::Message(format!(
+                "Sold for {}.",
+                format_money(paid)
+            ))),
+            Err(err) => refuse(err),
+        },
+        Action::ResetGame => {
+            // Preferences aren't progress, so they carry over. So does having
+            // seen the tutorial: the controls haven't changed.
+            *state = GameState {
+                theme: state.theme,
+                settings: state.settings.clone(),
+                tutorial_done: state.tutorial_done,
+                ..GameState::default()
+            };
+            state.log_news("A new arrival steps off the bus. Nobody remembers the last one.");
+            Ok(CommandResult::Message("Started a new game.".to_string()))
+        }
+        // Leaving is up to the main loop; there's nothing to change here.
+        Action::Quit => Ok(CommandResult::Empty),
+    }
+}
+
+/// Pays to leave the hospital at once. Used by the Hospital page's pay key.
+pub fn pay_for_recovery(state: &mut GameState, now: Instant) -> error::Result<CommandResult> {
+    if !hospital::is_hospitalized(state, now) {
+        return refuse("You are not in the hospital.");
+    }
+    match hospital::pay_for_recovery(state, now) {
+        Ok(cost) => Ok(CommandResult::Message(format!(
+            "Paid {} for instant recovery.",
+            format_money(cost)
+        ))),
+        Err(cost) => refuse(format!(
+            "Recovery costs {}; you can't afford it.",
+            format_money(cost)
+        )),
+    }
+}
+
+/// Tries to break out of jail. Used by the Jail page's escape key.
+pub fn escape_jail(
+    state: &mut GameState,
+    now: Instant,
+    rng: &mut impl Rng,
+) -> error::Result<CommandResult> {
+    match jail::attempt_escape(state, now, rng) {
+        Some(true) => Ok(CommandResult::Message("You escaped!".to_string())),
+        Some(false) => Ok(CommandResult::Setback(format!(
+            "Escape failed! {} added to your sentence.",
+            deadline::format_remaining(jail::ESCAPE_PENALTY)
+        ))),
+        None => refuse("You are not in jail."),
+    }
+}
+
+/// Enrolls in the course at `index`. Used by the Education page's enroll key.
+pub fn enroll(state: &mut GameState, index: usize, now: Instant) -> error::Result<CommandResult> {
+    match education::enroll(state, index, now) {
+        Ok(course) => Ok(CommandResult::Message(format!(
+            "Enrolled in {}. It takes {}.",
+            course.name,
+            deadline::format_remaining(course.duration)
+        ))),
+        Err(err) => refuse(err),
+    }
+}
+
+/// Starts a slots spin at the current bet. Used by the Casino page's spin key;
+/// the result is announced by `slots_result` once the reels stop.
+pub fn spin_slots(
+    state: &mut GameState,
+    now: Instant,
+    rng: &mut impl Rng,
+) -> error::Result<CommandResult> {
+    match state.slots.spin(&mut state.player, now, rng) {
+        Ok(()) => Ok(CommandResult::Message(format!(
+            "Spinning for {}...",
+            format_money(state.slots.bet_amount())
+        ))),
+        Err(err) => refuse(err),
+    }
+}
+
+/// Runs a blackjack action for the Casino page: `d` deals, `h` hits and `s`
+/// stands.
+pub fn play_blackjack(
+    state: &mut GameState,
+    action: char,
+    rng: &mut impl Rng,
+) -> error::Result<CommandResult> {
+    let table = &mut state.blackjack;
+    let result = match action {
+        'd' => table.deal(&mut state.player, rng),
+        'h' => table.hit(&mut state.player, rng),
+        _ => table.stand(&mut state.player, rng).map(Some),
+    };
+    match result {
+        Ok(Some(outcome)) => {
+            let bet = table.bet_amount();
+            let paid = outcome.payout(bet);
+            if paid > bet {
+                state.casino_wins += 1;
+            }
+            match paid {
+                0 => Ok(CommandResult::Message(format!(
+                    "{}. Lost {}.",
+                    outcome.describe(),
+                    format_money(bet)
+                ))),
+                won => Ok(CommandResult::Message(format!(
+                    "{}. Paid {}.",
+                    outcome.describe(),
+                    format_money(won)
+                ))),
+            }
+        }
+        Ok(None) => Ok(CommandResult::Message(
+            match state.blackjack.player_hand() {
+                Some(hand) => format!("You have {}. Hit or stand?", hand.score()),
+                None => String::new(),
+            },
+        )),
+        Err(err) => refuse(err),
+    }
+}
+
+/// Adds `bet` to the roulette slip. Used by the Casino page and `bet`.
+pub fn place_roulette_bet(state: &mut GameState, bet: Bet) -> error::Result<CommandResult> {
+    match state.roulette.place(bet, &state.player) {
+        Ok(()) => Ok(CommandResult::Message(format!(
+            "{} on {}. Slip total: {}.",
+            format_money(bet.amount),
+            bet.kind,
+            format_money(state.roulette.staked())
+        ))),
+        Err(err) => refuse(err),
+    }
+}
+
+/// Spins the roulette wheel for everything on the slip.
+pub fn spin_roulette(state: &mut GameState, rng: &mut impl Rng) -> error::Result<CommandResult> {
+    match state.roulette.spin(&mut state.player, rng) {
+        Ok(spin) => {
+            let landed = format!("{} {}", spin.number, roulette::color_of(spin.number).name());
+            if spin.net() > 0 {
+                state.casino_wins += 1;
+            }
+            match spin.net() {
+                net if net > 0 => Ok(CommandResult::Message(format!(
+                    "{}! You're up {} on that spin.",
+                    landed,
+                    format_money(net.unsigned_abs())
+                ))),
+                0 => Ok(CommandResult::Message(format!(
+                    "{}. You broke even.",
+                    landed
+                ))),
+                net => Ok(CommandResult::Message(format!(
+                    "{}. You're down {} on that spin.",
+                    landed,
+                    format_money(net.unsigned_abs())
+                ))),
+            }
+        }
+        Err(err) => refuse(err),
+    }
+}
+
+fn bet(args: &[&str], state: &mut GameState) -> error::Result<CommandResult> {
+    let usage = || refuse("Usage: bet <red|black|odd|even|0-36> [amount]".to_string());
+    let (target, amount) = match args {
+        [target] => (target, state.roulette.bet_amount()),
+        [target, amount] => match amount.parse() {
+            Ok(amount) if amount > 0 => (target, amount),
+            _ => return refuse(format!("Invalid amount '{}'", amount)),
+        },
+        _ => return usage(),
+    };
+    match BetKind::parse(target) {
+        Some(kind) => Ok(CommandResult::Confirm(Action::PlaceBet(Bet {
+            kind,
+            amount,
+        }))),
+        None => refuse(format!("Can't bet on '{}'", target)),
+    }
+}
+
+/// Describes a finished slots spin and counts a win towards the casino
+/// achievements. A pair only hands the stake back, so it isn't one.
+pub fn slots_result(state: &mut GameState, won: u64) -> error::Result<CommandResult> {
+    if won > state.slots.bet_amount() {
+        state.casino_wins += 1;
+    }
+    match won {
+        0 => Ok(CommandResult::Message("No luck this time.".to_string())),
+        won => Ok(CommandResult::Message(format!(
+            "The slots paid out {}!",
+            format_money(won)
+        ))),
+    }
+}
+
+fn faction(args: &[&str], state: &mut GameState) -> error::Result<CommandResult> {
+    let result = match args {
+        ["create", name @ ..] if !name.is_empty() => {
+            faction::create(state, &name.join(" ")).map(|faction| {
+                format!(
+                    "Founded {} for {}.",
+                    faction.name,
+                    format_money(FACTION_COST)
+                )
+            })
+        }
+        ["join", name @ ..] if !name.is_empty() => faction::join(state, &name.join(" "))
+            .map(|faction| format!("Welcome to {}.", faction.name)),
+        ["leave"] => faction::leave(state).map(|name| format!("You left {}.", name)),
+        ["deposit", amount] => match amount.parse() {
+            Ok(amount) => faction::deposit(state, amount).map(|treasury| {
+                format!(
+                    "Deposited {}. Treasury: {}.",
+                    format_money(amount),
+                    format_money(treasury)
+                )
+            }),
+            Err(_) => return refuse(format!("Invalid amount '{}'", amount)),
+        },
+        _ => {
+            return refuse(
+                "Usage: faction create|join <name>, faction deposit <amount>, faction leave"
+                    .to_string(),
+            );
+        }
+    };
+    match result {
+        Ok(message) => Ok(CommandResult::Message(message)),
+        Err(err) => refuse(err),
+    }
+}
+
+/// Claims the daily reward; the `daily` command and the Home page's key.
+pub fn claim_daily(state: &mut GameState) -> error::Result<CommandResult> {
+    match daily::claim(state) {
+        Ok(claim) => Ok(CommandResult::Message(format!(
+            "Daily reward: {} and {} energy. Streak: {} day(s).",
+            format_money(claim.money),
+            claim.energy,
+            claim.streak
+        ))),
+        Err(err) => refuse(err),
+    }
+}
+
+/// Pays out every recruit not yet claimed for; the `claim` command and the
+/// Recruit Citizens page's key.
+pub fn claim_rewards(state: &mut GameState) -> error::Result<CommandResult> {
+    match referral::claim_rewards(state) {
+        Ok((count, paid)) => Ok(CommandResult::Message(format!(
+            "Claimed {} for {} recruit(s).",
+            format_money(paid),
+            count
+        ))),
+        Err(err) => refuse(err),
+    }
+}
+
+/// Stands in for a real sign-up until there's a server to report them.
+fn recruit(args: &[&str], state: &mut GameState) -> error::Result<CommandResult> {
+    if args.is_empty() {
+        return refuse("Usage: recruit <name>".to_string());
+    }
+    let name = args.join(" ");
+    match referral::add_recruit(state, &name) {
+        Ok(()) => Ok(CommandResult::Message(format!(
+            "{} joined with your code. Claim {} on the Recruit Citizens page.",
+            name,
+            format_money(referral::RECRUIT_REWARD)
+        ))),
+        Err(err) => refuse(err),
+    }
+}
+
+const REPORT_FILED: &str = "Report filed. A moderator will look at it tomorrow.";
+
+/// Files the report filled in on the Rules page.
+pub fn file_report(state: &mut GameState) -> error::Result<CommandResult> {
+    match rules::file_selected(state) {
+        Ok(()) => Ok(CommandResult::Message(REPORT_FILED.to_string())),
+        Err(err) => refuse(err),
+    }
+}
+
+fn report(args: &[&str], state: &mut GameState) -> error::Result<CommandResult> {
+    let [rule, target @ ..] = args else {
+        return refuse("Usage: report <rule> <npc>".to_string());
+    };
+    if target.is_empty() {
+        return refuse("Usage: report <rule> <npc>".to_string());
+    }
+    match rules::file(state, &target.join(" "), rule) {
+        Ok(()) => Ok(CommandResult::Message(REPORT_FILED.to_string())),
+        Err(err) => refuse(err),
+    }
+}
+
+fn reply(args: &[&str], state: &mut GameState) -> error::Result<CommandResult> {
+    match forums::reply(state, &args.join(" ")) {
+        Ok(title) => Ok(CommandResult::Message(format!("Replied to \"{}\".", title))),
+        Err(err) => refuse(err),
+    }
+}
+
+/// `export <path>`, with a leading `~/` standing for the home directory.
+fn export(args: &[&str]) -> error::Result<CommandResult> {
+    let path = args.join(" ");
+    if path.is_empty() {
+        return refuse("Usage: export <path>".to_string());
+    }
+    let path = match (path.strip_prefix("~/"), std::env::var_os("HOME")) {
+        (Some(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => PathBuf::from(path),
+    };
+    Ok(CommandResult::Export(path))
+}
+
+fn deposit(args: &[&str], state: &mut GameState) -> error::Result<CommandResult> {
+    let amount = match args {
+        ["all"] => state.player.money,
+        [amount] => match amount.parse() {
+            Ok(amount) => amount,
+            Err(_) => return refuse(format!("Invalid amount '{}'", amount)),
+        },
+        _ => return refuse("Usage: deposit <amount|all>".to_string()),
+    };
+    match bank::deposit(state, amount) {
+        Ok(balance) => Ok(CommandResult::Message(format!(
+            "Deposited {}. Balance: {}.",
+            format_money(amount),
+            format_money(balance)
+        ))),
+        Err(err) => refuse(err),
+    }
+}
+
+fn withdraw(args: &[&str], state: &mut GameState) -> error::Result<CommandResult> {
+    let amount = match args {
+        ["all"] => state.bank.balance,
+        [amount] => match amount.parse() {
+            Ok(amount) => amount,
+            Err(_) => return refuse(format!("Invalid amount '{}'", amount)),
+        },
+        _ => return refuse("Usage: withdraw <amount|all>".to_string()),
+    };
+    match bank::withdraw(state, amount) {
+        Ok(balance) => Ok(CommandResult::Message(format!(
+            "Withdrew {}. Balance: {}.",
+            format_money(amount),
+            format_money(balance)
+        ))),
+        Err(err) => refuse(err),
+    }
+}
+
+fn set(args: &[&str], state: &mut GameState) -> error::Result<CommandResult> {
+    match args {
+        [name, value] => match state.settings.set(&name.to_lowercase(), value) {
+            Ok(message) => Ok(CommandResult::Message(format!("{}.", message))),
+            Err(err) => refuse(err),
+        },
+        _ => refuse("Usage: set <setting> <value>".to_string()),
+    }
+}
+
+const MENU_USAGE: &str = "Usage: menu up|down|hide|show <page>";
+
+fn menu(args: &[&str], state: &mut GameState) -> error::Result<CommandResult> {
+    let [verb, page @ ..] = args else {
+        return refuse(MENU_USAGE.to_string());
+    };
+    if page.is_empty() {
+        return refuse(MENU_USAGE.to_string());
+    }
+    let page = page.join(" ");
+    let settings = &mut state.settings;
+    let result = match verb.to_lowercase().as_str() {
+        "up" => settings.move_page(&page, true),
+        "down" => settings.move_page(&page, false),
+        "hide" => settings.hide_page(&page),
+        "show" => settings.show_page(&page),
+        _ => return refuse(MENU_USAGE.to_string()),
+    };
+    match result {
+        Ok(message) => Ok(CommandResult::Message(format!("{}.", message))),
+        Err(err) => refuse(err),
+    }
+}
+
+fn theme(args: &[&str], state: &mut GameState) -> error::Result<CommandResult> {
+    let names: Vec<&str> = ThemeName::ALL.iter().map(|theme| theme.name()).collect();
+    match args {
+        [name] => match ThemeName::parse(name) {
+            Some(theme) => {
+                state.theme = theme;
+                Ok(CommandResult::Message(format!(
+                    "Theme set to {}.",
+                    theme.name()
+                )))
+            }
+            None => refuse(format!(
+                "Unknown theme '{}'. Try: {}",
+                name,
+                names.join(", ")
+            )),
+        },
+        _ => Ok(CommandResult::Message(format!(
+            "Current theme: {}. Available: {}",
+            state.theme.name(),
+            names.join(", ")
+        ))),
+    }
+}
+
+/// Buys one unit of the shop entry at `index`. Shared by `buy item <n>` and
+/// the City page's buy prompt.
+pub fn buy_item(state: &mut GameState, index: usize) -> error::Result<CommandResult> {
+    match state
+        .shop
+        .buy(index, &mut state.player, &mut state.inventory)
+    {
+        Ok(item) => Ok(CommandResult::Message(format!("Bought {}.", item.name()))),
+        Err(err) => refuse(err),
+    }
+}
+
+/// Attempts `crime` and describes how it went. Used by the Crimes page's
+/// commit key.
+pub fn commit_crime(
+    state: &mut GameState,
+    crime: &Crime,
+    now: Instant,
+    rng: &mut impl Rng,
+) -> error::Result<CommandResult> {
+    match crime::commit_crime(crime, state, now, rng) {
+        Ok(CrimeOutcome::Success { money, found }) => Ok(CommandResult::Message(match found {
+            Some(item) => format!(
+                "{} succeeded: +{}, found a {}",
+                crime.name,
+                format_money(money),
+                item.name()
+            ),
+            None => format!("{} succeeded: +{}", crime.name, format_money(money)),
+        })),
+        Ok(CrimeOutcome::Failed) => Ok(CommandResult::Message(format!("{} failed.", crime.name))),
+        Ok(CrimeOutcome::Jailed { sentence }) => Ok(CommandResult::Setback(format!(
+            "{} failed and you were caught! Jailed for {}.",
+            crime.name,
+            deadline::format_remaining(sentence)
+        ))),
+        Ok(CrimeOutcome::Hospitalized { stay }) => Ok(CommandResult::Setback(format!(
+            "{} went badly wrong. Hospitalized for {}.",
+            crime.name,
+            deadline::format_remaining(stay)
+        ))),
+        Err(err) => refuse(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn goto_and_train_check_jail_at_the_given_time() {
+        let now = Instant::now();
+        let mut state = GameState {
+            jail_until: Some(now + Duration::from_secs(30)),
+            ..GameState::default()
+        };
+        assert!(matches!(
+            parse_command("goto gym", &mut state, now),
+            Err(RustyError::Game(_))
+        ));
+        assert!(matches!(
+            parse_command("train strength", &mut state, now),
+            Err(RustyError::Game(_))
+        ));
+        let later = now + Duration::from_secs(31);
+        assert!(matches!(
+            parse_command("goto gym", &mut state, later),
+            Ok(CommandResult::Goto("Gym"))
+        ));
+    }
+
+    #[test]
+    fn rejected_commands_are_errors() {
+        let mut state = GameState::default();
+        let now = Instant::now();
+        let message = |result: error::Result<CommandResult>| result.unwrap_err().to_string();
+        assert_eq!(
+            message(parse_command("dance", &mut state, now)),
+            "Unknown command 'dance'"
+        );
+        assert_eq!(
+            message(parse_command("buy item 0", &mut state, now)),
+            "Invalid item number '0'"
+        );
+        assert_eq!(
+            message(parse_command("deposit", &mut state, now)),
+            "Usage: deposit <amount|all>"
+        );
+        assert!(matches!(
+            parse_command("buy item 1", &mut state, now),
+            Ok(CommandResult::Confirm(Action::BuyItem(0)))
+        ));
+    }
+}
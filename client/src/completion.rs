@@ -0,0 +1,193 @@
+use crate::command::COMMANDS;
+use crate::faction::FactionBackend;
+use crate::job::JOBS;
+use crate::player::Stat;
+use crate::state::{GameState, PAGES};
+use crate::theme::ThemeName;
+
+/// The ways `prefix` could go on from the word being typed at its end, each
+/// the whole line with that word filled in. The first word completes to a
+/// command; later ones to whatever that command takes, so `goto` offers page
+/// names and `use` the items being carried. Matching ignores case.
+pub fn complete(prefix: &str, state: &GameState) -> Vec<String> {
+    let line = prefix.trim_start();
+    let Some((verb, rest)) = line.split_once(char::is_whitespace) else {
+        let mut verbs: Vec<&str> = COMMANDS
+            .iter()
+            .filter_map(|(usage, _)| usage.split_whitespace().next())
+            .collect();
+        verbs.dedup();
+        return matching(verbs.into_iter().map(String::from), line, "");
+    };
+
+    let rest = rest.trim_start();
+    let head = format!("{} ", verb);
+    let names: Vec<String> = match verb.to_lowercase().as_str() {
+        "goto" => PAGES.iter().map(|page| page.to_string()).collect(),
+        "use" | "sell" => state
+            .inventory
+            .items
+            .iter()
+            .map(|stack| stack.item.name().to_string())
+            .collect(),
+        "train" => Stat::ALL
+            .iter()
+            .map(|stat| stat.name().to_lowercase())
+            .collect(),
+        "apply" => JOBS.iter().map(|job| job.title.to_string()).collect(),
+        "theme" => ThemeName::ALL
+            .iter()
+            .map(|theme| theme.name().to_string())
+            .collect(),
+        "faction" => match rest.split_once(char::is_whitespace) {
+            Some(("join", name)) => {
+                let head = format!("{}join ", head);
+                let names = state.factions.list().into_iter().map(|f| f.name);
+                return matching(names, name.trim_start(), &head);
+            }
+            Some(_) => Vec::new(),
+            None => subcommands("faction"),
+        },
+        "menu" => match rest.split_once(char::is_whitespace) {
+            Some((sub, page)) => {
+                let head = format!("{}{} ", head, sub);
+                let names = PAGES.iter().map(|page| page.to_string());
+                return matching(names, page.trim_start(), &head);
+            }
+            None => subcommands("menu"),
+        },
+        verb => subcommands(verb),
+    };
+    matching(names.into_iter(), rest, &head)
+}
+
+/// The words `verb`'s usages in `COMMANDS` list straight after it, such as
+/// `set`'s setting names. Placeholders like `<n>` aren't words to complete.
+fn subcommands(verb: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    for (usage, _) in COMMANDS {
+        let mut words = usage.split_whitespace();
+        if words.next() != Some(verb) {
+            continue;
+        }
+        let Some(word) = words.next() else {
+            continue;
+        };
+        if word.starts_with(['<', '[']) {
+            continue;
+        }
+        for name in word.split('|') {
+            if !names.iter().any(|known| known == name) {
+                names.push(name.to_string());
+            }
+        }
+    }
+    names
+}
+
+/// `head` followed by each of `names` that starts with `word`.
+fn matching(names: impl Iterator<Item = String>, word: &str, head: &str) -> Vec<String> {
+    let word = word.to_lowercase();
+    names
+        .filter(|name| name.to_lowercase().starts_with(&word))
+        .map(|name| format!("{}{}", head, name))
+        .collect()
+}
+
+/// Tab completion for the Input box. The first Tab completes as far as the
+/// candidates agree; pressing it again without typing cycles through them.
+#[derive(Debug, Default)]
+pub struct Completer {
+    candidates: Vec<String>,
+    /// The candidate last put in the Input box, while cycling.
+    index: Option<usize>,
+    /// The Input text this completer last produced. Anything else means the
+    /// player has typed since, and completion starts over.
+    last: String,
+}
+
+impl Completer {
+    /// The Input text after a Tab press, or `None` if nothing fits.
+    pub fn tab(&mut self, input: &str, state: &GameState) -> Option<String> {
+        if self.candidates.len() > 1 && input == self.last {
+            let index = self.index.map_or(0, |i| (i + 1) % self.candidates.len());
+            self.index = Some(index);
+            self.last = self.candidates[index].clone();
+            return Some(self.last.clone());
+        }
+
+        self.candidates = complete(input, state);
+        self.index = None;
+        let line = match self.candidates.as_slice() {
+            [] => return None,
+            [only] => format!("{} ", only),
+            [first, rest @ ..] => {
+                let shared = rest
+                    .iter()
+                    .fold(first.as_str(), |shared, other| common_prefix(shared, other));
+                if shared.len() > input.len() {
+                    shared.to_string()
+                } else {
+                    input.to_string()
+                }
+            }
+        };
+        self.last = line.clone();
+        Some(line)
+    }
+
+    /// The candidates to list over the page, with the one being cycled to,
+    /// while `input` is still what the completer left there. Each is cut down
+    /// to the words being completed, since the start of the line is shared.
+    pub fn choices(&self, input: &str) -> Option<(Vec<&str>, Option<usize>)> {
+        if self.candidates.len() < 2 || input != self.last {
+            return None;
+        }
+        let shared = self.candidates[1..]
+            .iter()
+            .fold(self.candidates[0].as_str(), |shared, other| {
+                common_prefix(shared, other)
+            });
+        let head = shared.rfind(' ').map_or(0, |i| i + 1);
+        let words = self
+            .candidates
+            .iter()
+            .map(|candidate| &candidate[head..])
+            .collect();
+        Some((words, self.index))
+    }
+}
+
+fn common_prefix<'a>(a: &'a str, b: &str) -> &'a str {
+    let len = a
+        .char_indices()
+        .zip(b.chars())
+        .find(|((_, x), y)| x != y)
+        .map_or(a.len().min(b.len()), |((i, _), _)| i);
+    &a[..len]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_offers_every_setting_in_commands() {
+        let state = GameState::default();
+        assert_eq!(complete("set tr", &state), ["set transitions"]);
+        let offered = complete("set ", &state);
+        for (usage, _) in COMMANDS {
+            if let Some(rest) = usage.strip_prefix("set ") {
+                let name = rest.split_whitespace().next().unwrap();
+                assert!(offered.contains(&format!("set {}", name)), "{}", usage);
+            }
+        }
+    }
+
+    #[test]
+    fn subcommands_split_alternatives() {
+        assert_eq!(subcommands("menu"), ["up", "down", "hide", "show"]);
+        assert_eq!(subcommands("buy"), ["item"]);
+        assert!(subcommands("train").is_empty());
+    }
+}
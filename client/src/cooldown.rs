@@ -0,0 +1,32 @@
+//! Waits between goes at the same action, such as a crime, so it can't be
+//! repeated back to back. Times are on the game clock, which stops while the
+//! game is paused or closed, so a cooldown saved half done picks up where it
+//! left off.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Cooldowns {
+    /// Game clock time each action can be used again, by action id.
+    ready_at: HashMap<String, Duration>,
+}
+
+impl Cooldowns {
+    /// Starts `id`'s cooldown of `length` at game time `now`.
+    pub fn start(&mut self, id: &str, length: Duration, now: Duration) {
+        self.ready_at.retain(|_, at| *at > now);
+        if !length.is_zero() {
+            self.ready_at.insert(id.to_string(), now + length);
+        }
+    }
+
+    /// Game time left before `id` can be used again, or `None` once it can.
+    pub fn remaining(&self, id: &str, now: Duration) -> Option<Duration> {
+        let left = self.ready_at.get(id)?.saturating_sub(now);
+        (!left.is_zero()).then_some(left)
+    }
+}
@@ -0,0 +1,427 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use rand::Rng;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::Line;
+use serde::{Deserialize, Serialize};
+
+use crate::difficulty::Difficulty;
+use crate::drops::{Drop, DropTable};
+use crate::inventory::Item;
+use crate::money::format_money;
+use crate::state::GameState;
+use crate::{deadline, hospital, level};
+
+/// How many outcomes the "Crime success history" box keeps.
+pub const CRIME_HISTORY_LEN: usize = 10;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Crime {
+    pub name: &'static str,
+    pub min_level: u32,
+    /// Probability in `0.0..=1.0` that the crime succeeds.
+    pub success_chance: f64,
+    pub reward_money: u64,
+    /// Experience for pulling it off.
+    pub xp: u64,
+    /// Probability that a failed attempt ends in jail.
+    pub jail_risk: f64,
+    /// Probability that a failed attempt the player escapes from still ends
+    /// in the hospital.
+    pub injury_risk: f64,
+    pub energy_cost: u32,
+    pub sentence: Duration,
+    /// Game time after an attempt, whatever came of it, before the crime
+    /// can be tried again.
+    pub cooldown: Duration,
+    /// Rolled once on every success.
+    pub drops: DropTable,
+}
+
+pub const CRIMES: &[Crime] = &[
+    Crime {
+        name: "Search for cash",
+        min_level: 1,
+        success_chance: 0.9,
+        reward_money: 20,
+        xp: 5,
+        jail_risk: 0.05,
+        injury_risk: 0.0,
+        energy_cost: 5,
+        sentence: Duration::from_secs(30),
+        cooldown: Duration::from_secs(10),
+        drops: DropTable {
+            nothing: 80,
+            drops: &[
+                Drop {
+                    item: Item::Newspaper,
+                    weight: 15,
+                },
+                Drop {
+                    item: Item::Candy,
+                    weight: 5,
+                },
+            ],
+        },
+    },
+    Crime {
+        name: "Shoplift",
+        min_level: 1,
+        success_chance: 0.7,
+        reward_money: 75,
+        xp: 10,
+        jail_risk: 0.3,
+        injury_risk: 0.1,
+        energy_cost: 10,
+        sentence: Duration::from_secs(60),
+        cooldown: Duration::from_secs(20),
+        drops: DropTable {
+            nothing: 60,
+            drops: &[
+                Drop {
+                    item: Item::Candy,
+                    weight: 25,
+                },
+                Drop {
+                    item: Item::EnergyDrink,
+                    weight: 12,
+                },
+                Drop {
+                    item: Item::FirstAidKit,
+                    weight: 3,
+                },
+            ],
+        },
+    },
+    Crime {
+        name: "Pickpocket",
+        min_level: 3,
+        success_chance: 0.55,
+        reward_money: 200,
+        xp: 25,
+        jail_risk: 0.4,
+        injury_risk: 0.25,
+        energy_cost: 15,
+        sentence: Duration::from_secs(120),
+        cooldown: Duration::from_secs(45),
+        drops: DropTable {
+            nothing: 60,
+            drops: &[
+                Drop {
+                    item: Item::EnergyDrink,
+                    weight: 25,
+                },
+                Drop {
+                    item: Item::FirstAidKit,
+                    weight: 12,
+                },
+                Drop {
+                    item: Item::LuckyCharm,
+                    weight: 3,
+                },
+            ],
+        },
+    },
+    Crime {
+        name: "Car theft",
+        min_level: 5,
+        success_chance: 0.4,
+        reward_money: 800,
+        xp: 60,
+        jail_risk: 0.6,
+        injury_risk: 0.4,
+        energy_cost: 25,
+        sentence: Duration::from_secs(300),
+        cooldown: Duration::from_secs(90),
+        drops: DropTable {
+            nothing: 50,
+            drops: &[
+                Drop {
+                    item: Item::EnergyDrink,
+                    weight: 25,
+                },
+                Drop {
+                    item: Item::FirstAidKit,
+                    weight: 18,
+                },
+                Drop {
+                    item: Item::LuckyCharm,
+                    weight: 7,
+                },
+            ],
+        },
+    },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CrimeOutcome {
+    /// `found` is what the crime's drop table turned up, if anything.
+    Success {
+        money: u64,
+        found: Option<Item>,
+    },
+    Failed,
+    Jailed {
+        sentence: Duration,
+    },
+    Hospitalized {
+        stay: Duration,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrimeError {
+    LevelTooLow { required: u32 },
+    NotEnoughEnergy,
+    CoolingDown { left: Duration },
+}
+
+impl fmt::Display for CrimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CrimeError::LevelTooLow { required } => write!(f, "Requires level {}", required),
+            CrimeError::NotEnoughEnergy => write!(f, "Not enough energy"),
+            CrimeError::CoolingDown { left } => {
+                write!(
+                    f,
+                    "Lying low: try again in {}",
+                    deadline::format_remaining(*left)
+                )
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrimeRecord {
+    pub name: String,
+    pub success: bool,
+    pub money: u64,
+}
+
+/// Attempts `crime`, spending its energy cost and starting its cooldown.
+/// Successes pay out and add crime experience; failures may land the player
+/// in jail or the hospital, with sentences counted from `now`. Every
+/// attempt is recorded in the history.
+pub fn commit_crime(
+    crime: &Crime,
+    state: &mut GameState,
+    now: Instant,
+    rng: &mut impl Rng,
+) -> Result<CrimeOutcome, CrimeError> {
+    if state.player.level < crime.min_level {
+        return Err(CrimeError::LevelTooLow {
+            required: crime.min_level,
+        });
+    }
+    let game_now = state.clock.elapsed();
+    if let Some(left) = state.cooldowns.remaining(crime.name, game_now) {
+        return Err(CrimeError::CoolingDown { left });
+    }
+    if !state.player.spend_energy(crime.energy_cost) {
+        return Err(CrimeError::NotEnoughEnergy);
+    }
+    state.crimes_committed += 1;
+    state.cooldowns.start(crime.name, crime.cooldown, game_now);
+
+    let outcome = if rng.random_bool(success_chance(crime, state.difficulty)) {
+        state.player.money += crime.reward_money;
+        state.crime_xp += 1;
+        state.log_news(format!(
+            "{} pulled off for {}.",
+            crime.name,
+            format_money(crime.reward_money)
+        ));
+        level::award_xp(state, crime.xp);
+        let found = crime.drops.roll(rng);
+        if let Some(item) = found {
+            state.inventory.add(item, 1);
+            state.log_news(format!(
+                "Found a {} during a {}.",
+                item.name(),
+                crime.name.to_lowercase()
+            ));
+        }
+        CrimeOutcome::Success {
+            money: crime.reward_money,
+            found,
+        }
+    } else if rng.random_bool(crime.jail_risk) {
+        state.jail_until = Some(now + crime.sentence);
+        state.jail_term_secs = crime.sentence.as_secs();
+        state.log_news(format!(
+            "Caught during a {} and jailed for {}.",
+            crime.name.to_lowercase(),
+            deadline::format_remaining(crime.sentence)
+        ));
+        CrimeOutcome::Jailed {
+            sentence: crime.sentence,
+        }
+    } else if rng.random_bool(crime.injury_risk) {
+        hospital::admit(
+            state,
+            now,
+            crime.sentence,
+            format!("Injured during a botched {}", crime.name.to_lowercase()),
+        );
+        CrimeOutcome::Hospitalized {
+            stay: crime.sentence,
+        }
+    } else {
+        CrimeOutcome::Failed
+    };
+
+    let (success, money) = match outcome {
+        CrimeOutcome::Success { money, .. } => (true, money),
+        _ => (false, 0),
+    };
+    record_crime(
+        &mut state.crime_history,
+        CrimeRecord {
+            name: crime.name.to_string(),
+            success,
+            money,
+        },
+    );
+    Ok(outcome)
+}
+
+/// `crime`'s chance of success once the difficulty is taken into account.
+pub fn success_chance(crime: &Crime, difficulty: Difficulty) -> f64 {
+    (crime.success_chance * difficulty.crime_modifier()).clamp(0.0, 1.0)
+}
+
+fn record_crime(history: &mut VecDeque<CrimeRecord>, record: CrimeRecord) {
+    history.push_front(record);
+    history.truncate(CRIME_HISTORY_LEN);
+}
+
+/// The Crimes page's list. Crimes still cooling down at game time `now` are
+/// dimmed and show how long is left.
+pub fn crime_list_lines(state: &GameState, now: Duration, selected: usize) -> Vec<Line<'static>> {
+    CRIMES
+        .iter()
+        .enumerate()
+        .map(|(i, crime)| {
+            let marker = if i == selected { ">" } else { " " };
+            if state.player.level < crime.min_level {
+                return Line::from(format!(
+                    "{} {:<16} (level {})",
+                    marker, crime.name, crime.min_level
+                ));
+            }
+            let stats = format!(
+                "{} {:<16} {:>3.0}%  {:<7} {}E",
+                marker,
+                crime.name,
+                success_chance(crime, state.difficulty) * 100.0,
+                format_money(crime.reward_money),
+                crime.energy_cost
+            );
+            match state.cooldowns.remaining(crime.name, now) {
+                Some(left) => Line::styled(
+                    format!("{}  {}", stats, deadline::format_remaining(left)),
+                    Style::new().add_modifier(Modifier::DIM),
+                ),
+                None => Line::from(stats),
+            }
+        })
+        .collect()
+}
+
+pub fn crime_history_text(history: &VecDeque<CrimeRecord>) -> String {
+    if history.is_empty() {
+        return "No crimes committed yet.".to_string();
+    }
+    history
+        .iter()
+        .map(|record| {
+            if record.success {
+                format!("{:<16} +{}", record.name, format_money(record.money))
+            } else {
+                format!("{:<16} failed", record.name)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jail;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    const SURE_THING: Crime = Crime {
+        name: "Sure thing",
+        min_level: 1,
+        success_chance: 1.0,
+        reward_money: 50,
+        xp: 5,
+        jail_risk: 0.0,
+        injury_risk: 0.0,
+        energy_cost: 5,
+        sentence: Duration::from_secs(90),
+        cooldown: Duration::from_secs(10),
+        drops: DropTable {
+            nothing: 0,
+            drops: &[Drop {
+                item: Item::Candy,
+                weight: 1,
+            }],
+        },
+    };
+
+    #[test]
+    fn success_pays_out_and_drops() {
+        let mut rng = StdRng::seed_from_u64(6);
+        let mut state = GameState::default();
+        state.inventory.items.clear();
+        let money = state.player.money;
+        let outcome = commit_crime(&SURE_THING, &mut state, Instant::now(), &mut rng);
+        assert_eq!(
+            outcome,
+            Ok(CrimeOutcome::Success {
+                money: 50,
+                found: Some(Item::Candy),
+            })
+        );
+        assert_eq!(state.player.money, money + 50);
+        assert_eq!(state.inventory.items[0].item, Item::Candy);
+        assert_eq!(state.inventory.items[0].quantity, 1);
+        assert_eq!(state.crime_xp, 1);
+        assert!(state.crime_history[0].success);
+        assert_eq!(
+            commit_crime(&SURE_THING, &mut state, Instant::now(), &mut rng),
+            Err(CrimeError::CoolingDown {
+                left: SURE_THING.cooldown
+            })
+        );
+    }
+
+    #[test]
+    fn failure_can_end_in_jail() {
+        let crime = Crime {
+            success_chance: 0.0,
+            jail_risk: 1.0,
+            ..SURE_THING
+        };
+        let mut rng = StdRng::seed_from_u64(6);
+        let mut state = GameState::default();
+        let now = Instant::now();
+        let outcome = commit_crime(&crime, &mut state, now, &mut rng);
+        assert_eq!(
+            outcome,
+            Ok(CrimeOutcome::Jailed {
+                sentence: crime.sentence
+            })
+        );
+        assert_eq!(state.jail_until, Some(now + crime.sentence));
+        assert!(jail::is_jailed(&state, now));
+        assert_eq!(state.crime_xp, 0);
+        assert!(!state.crime_history[0].success);
+    }
+}
@@ -0,0 +1,130 @@
+//! The daily reward: claimable once per in-game day, and worth more for
+//! every day in a row it's claimed.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::money::format_money;
+use crate::state::GameState;
+
+/// Paid for a claim with no streak behind it.
+pub const BASE_MONEY: u64 = 100;
+pub const ENERGY: u32 = 20;
+/// Extra money for each day the streak has run beyond the first.
+pub const STREAK_BONUS: u64 = 25;
+/// The bonus stops growing once a streak gets this long.
+pub const MAX_STREAK_BONUS_DAYS: u32 = 7;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DailyReward {
+    /// In-game day of the last claim.
+    pub last_claimed: Option<u64>,
+    /// Days in a row claimed, ending with `last_claimed`.
+    pub streak: u32,
+    pub best_streak: u32,
+}
+
+impl DailyReward {
+    pub fn is_available(&self, today: u64) -> bool {
+        self.last_claimed.is_none_or(|day| day < today)
+    }
+
+    /// The streak as of `today`: it's broken once a whole day goes by
+    /// without a claim.
+    pub fn current_streak(&self, today: u64) -> u32 {
+        match self.last_claimed {
+            Some(day) if day + 1 >= today => self.streak,
+            _ => 0,
+        }
+    }
+
+    /// What the streak becomes if the player claims on `today`.
+    fn next_streak(&self, today: u64) -> u32 {
+        match self.last_claimed {
+            Some(day) if day + 1 == today => self.streak + 1,
+            _ => 1,
+        }
+    }
+}
+
+/// Money paid for the claim that makes a streak `streak` days long.
+pub fn reward_money(streak: u32) -> u64 {
+    let bonus_days = streak.saturating_sub(1).min(MAX_STREAK_BONUS_DAYS);
+    BASE_MONEY + STREAK_BONUS * u64::from(bonus_days)
+}
+
+/// What a claim paid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Claim {
+    pub money: u64,
+    pub energy: u32,
+    pub streak: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DailyError {
+    AlreadyClaimed,
+}
+
+impl fmt::Display for DailyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DailyError::AlreadyClaimed => {
+                write!(f, "Today's reward is already claimed; come back tomorrow")
+            }
+        }
+    }
+}
+
+/// Pays today's reward and moves the streak on. Only one claim per day.
+pub fn claim(state: &mut GameState) -> Result<Claim, DailyError> {
+    let today = state.clock.day();
+    let daily = &mut state.daily;
+    if !daily.is_available(today) {
+        return Err(DailyError::AlreadyClaimed);
+    }
+    let streak = daily.next_streak(today);
+    daily.last_claimed = Some(today);
+    daily.streak = streak;
+    daily.best_streak = daily.best_streak.max(streak);
+
+    let money = reward_money(streak);
+    state.player.money += money;
+    state.player.restore_energy(ENERGY);
+    state.log_news(format!(
+        "Claimed the daily reward: {} and {} energy (streak {}).",
+        format_money(money),
+        ENERGY,
+        streak
+    ));
+    Ok(Claim {
+        money,
+        energy: ENERGY,
+        streak,
+    })
+}
+
+pub fn reward_text(state: &GameState) -> String {
+    let today = state.clock.day();
+    let daily = &state.daily;
+    let streak = daily.current_streak(today);
+    let status = if daily.is_available(today) {
+        let next = daily.next_streak(today);
+        format!(
+            "Ready to claim: {} and {} energy",
+            format_money(reward_money(next)),
+            ENERGY
+        )
+    } else {
+        format!(
+            "Claimed today. Tomorrow: {}",
+            format_money(reward_money(streak + 1))
+        )
+    };
+    format!(
+        "Daily reward\n{}\nStreak: {} day(s)  Best: {}",
+        status, streak, daily.best_streak
+    )
+}
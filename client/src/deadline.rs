@@ -0,0 +1,37 @@
+//! Serde helpers for `Option<Instant>` deadlines. An `Instant` means nothing
+//! outside the process that created it, so it is stored as the number of
+//! seconds left and turned back into an `Instant` relative to load time.
+
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+pub fn serialize<S: Serializer>(deadline: &Option<Instant>, s: S) -> Result<S::Ok, S::Error> {
+    deadline
+        .map(|at| at.saturating_duration_since(Instant::now()).as_secs_f64())
+        .serialize(s)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Option<Instant>, D::Error> {
+    let Some(secs) = Option::<f64>::deserialize(d)? else {
+        return Ok(None);
+    };
+    let left = Duration::try_from_secs_f64(secs).map_err(serde::de::Error::custom)?;
+    Ok(Some(Instant::now() + left))
+}
+
+/// Time left until `deadline`, or `None` once it has passed.
+pub fn remaining(deadline: Option<Instant>, now: Instant) -> Option<Duration> {
+    let left = deadline?.saturating_duration_since(now);
+    (!left.is_zero()).then_some(left)
+}
+
+/// Compact countdown such as `4m 05s` or `12s`.
+pub fn format_remaining(left: Duration) -> String {
+    let secs = left.as_secs() + u64::from(left.subsec_nanos() > 0);
+    if secs >= 60 {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{}s", secs)
+    }
+}
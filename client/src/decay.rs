@@ -0,0 +1,91 @@
+//! Optional stat decay: with the setting on, each battle stat that wasn't
+//! trained during an in-game day loses a small share of itself when the day
+//! ends, down to a floor.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::player::Stat;
+use crate::state::GameState;
+
+/// Decay never takes a stat below this: what every new character starts
+/// with.
+pub const STAT_FLOOR: u32 = 10;
+
+/// The stats that decayed at the start of `day`, and by how much.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Decay {
+    pub day: u64,
+    pub losses: Vec<(Stat, u32)>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DecayLog {
+    /// The last in-game day each stat was trained on.
+    pub trained_on: HashMap<Stat, u64>,
+    pub last: Option<Decay>,
+}
+
+/// What a stat at `value` loses in a day at `permille` tenths of a percent:
+/// always at least a point while it's above the floor, never past it.
+pub fn loss(value: u32, permille: u64) -> u32 {
+    if value <= STAT_FLOOR {
+        return 0;
+    }
+    let share = (u64::from(value) * permille).div_ceil(1000);
+    share.min(u64::from(value - STAT_FLOOR)) as u32
+}
+
+/// Notes that `stat` was trained today, sparing it from tonight's decay.
+pub fn record_training(state: &mut GameState, stat: Stat) {
+    let today = state.clock.day();
+    state.decay.trained_on.insert(stat, today);
+}
+
+/// Decays the stats that went untrained through the day that just ended.
+/// Called once at the start of each in-game day; does nothing unless the
+/// setting is on. Returns true if any stat went down.
+pub fn apply(state: &mut GameState) -> bool {
+    if !state.settings.stat_decay {
+        return false;
+    }
+    let today = state.clock.day();
+    let yesterday = today.saturating_sub(1);
+    let permille = (state.settings.decay_rate() * state.difficulty.decay_percent() / 100).max(1);
+    let mut losses = Vec::new();
+    for stat in Stat::ALL {
+        if state.decay.trained_on.get(&stat) == Some(&yesterday) {
+            continue;
+        }
+        let value = state.player.stat_mut(stat);
+        let lost = loss(*value, permille);
+        if lost > 0 {
+            *value -= lost;
+            losses.push((stat, lost));
+        }
+    }
+    if losses.is_empty() {
+        return false;
+    }
+    state.decay.last = Some(Decay { day: today, losses });
+    true
+}
+
+/// A note for the Home page when stats decayed this morning.
+pub fn recent_text(state: &GameState) -> Option<String> {
+    let decay = state.decay.last.as_ref()?;
+    if decay.day != state.clock.day() {
+        return None;
+    }
+    let losses: Vec<String> = decay
+        .losses
+        .iter()
+        .map(|(stat, lost)| format!("{} -{}", stat.name(), lost))
+        .collect();
+    Some(format!(
+        "Out of practice overnight:\n{}\n\nTrain at the Gym to keep your stats up.",
+        losses.join("\n")
+    ))
+}
@@ -0,0 +1,61 @@
+//! Attract mode: after the player has left the game alone for a while, it
+//! plays itself, stepping through the menu and training now and then. It
+//! runs on a copy of the game that's thrown away when any key is pressed, so
+//! the real game and its save never see it.
+
+use std::time::{Duration, Instant};
+
+use rand::Rng;
+
+use crate::command;
+use crate::gym::MACHINES;
+use crate::state::GameState;
+
+/// How long each page stays up before the demo moves on.
+pub const DEMO_STEP: Duration = Duration::from_secs(4);
+
+#[derive(Debug)]
+pub struct Demo {
+    /// The copy the demo plays with; shown in place of the real game.
+    pub game: GameState,
+    /// The page that was open when the demo started, to go back to.
+    pub prior_page: &'static str,
+    last_tick: Instant,
+    next_step: Instant,
+}
+
+impl Demo {
+    pub fn start(game: &GameState, prior_page: &'static str, now: Instant) -> Self {
+        Self {
+            game: game.clone(),
+            prior_page,
+            last_tick: now,
+            next_step: now + DEMO_STEP,
+        }
+    }
+
+    /// Keeps the copy's clock running, and once a step is due does a little
+    /// something in it. Returns the menu index to show next when it's time
+    /// to move on from `current` of `pages` entries.
+    pub fn tick(
+        &mut self,
+        current: usize,
+        pages: usize,
+        now: Instant,
+        rng: &mut impl Rng,
+    ) -> Option<usize> {
+        self.game.clock.advance(now.duration_since(self.last_tick));
+        self.last_tick = now;
+        if now < self.next_step || pages == 0 {
+            return None;
+        }
+        self.next_step = now + DEMO_STEP;
+        // Topped up first so the demo never stalls on a tired player.
+        self.game.player.energy = self.game.player.max_energy;
+        if rng.random_bool(0.5) {
+            let machine = rng.random_range(0..MACHINES.len());
+            let _ = command::train_on_machine(&mut self.game, machine);
+        }
+        Some((current + 1) % pages)
+    }
+}
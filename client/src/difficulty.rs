@@ -0,0 +1,106 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::player::ENERGY_REGEN_INTERVAL;
+use crate::state::GameState;
+
+/// Picked when a new game starts and kept for its whole run. Systems read
+/// their modifiers from here rather than checking the level themselves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Difficulty {
+    Easy,
+    /// Saves from before difficulty existed play as Normal.
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Hard",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "More starting cash, faster energy, easier crimes, gentle decay.",
+            Difficulty::Normal => "The game as intended.",
+            Difficulty::Hard => "Less cash, slower energy, riskier crimes, harsher decay.",
+        }
+    }
+
+    /// The row after this one, wrapping around.
+    pub fn next(self) -> Difficulty {
+        let index = Difficulty::ALL.iter().position(|&d| d == self).unwrap_or(0);
+        Difficulty::ALL[(index + 1) % Difficulty::ALL.len()]
+    }
+
+    pub fn prev(self) -> Difficulty {
+        let index = Difficulty::ALL.iter().position(|&d| d == self).unwrap_or(0);
+        Difficulty::ALL[(index + Difficulty::ALL.len() - 1) % Difficulty::ALL.len()]
+    }
+
+    pub fn starting_money(self) -> u64 {
+        match self {
+            Difficulty::Easy => 1_000,
+            Difficulty::Normal => 500,
+            Difficulty::Hard => 250,
+        }
+    }
+
+    /// Game time between points of energy.
+    pub fn regen_interval(self) -> Duration {
+        match self {
+            Difficulty::Easy => ENERGY_REGEN_INTERVAL * 2 / 3,
+            Difficulty::Normal => ENERGY_REGEN_INTERVAL,
+            Difficulty::Hard => ENERGY_REGEN_INTERVAL * 3 / 2,
+        }
+    }
+
+    /// Multiplies every crime's success chance.
+    pub fn crime_modifier(self) -> f64 {
+        match self {
+            Difficulty::Easy => 1.15,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 0.85,
+        }
+    }
+
+    /// Percentage of the stat decay rate setting that actually applies.
+    pub fn decay_percent(self) -> u64 {
+        match self {
+            Difficulty::Easy => 50,
+            Difficulty::Normal => 100,
+            Difficulty::Hard => 200,
+        }
+    }
+}
+
+/// Sets a freshly started game up for `difficulty`.
+pub fn start(state: &mut GameState, difficulty: Difficulty) {
+    state.difficulty = difficulty;
+    state.player.money = difficulty.starting_money();
+}
+
+/// The new-game picker's rows, `selected` marked.
+pub fn picker_text(selected: Difficulty) -> String {
+    Difficulty::ALL
+        .iter()
+        .map(|&difficulty| {
+            let marker = if difficulty == selected { ">" } else { " " };
+            format!(
+                "{} {:<7} {}",
+                marker,
+                difficulty.name(),
+                difficulty.description()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
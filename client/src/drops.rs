@@ -0,0 +1,117 @@
+//! Item drops. Each crime and mission has its own table of what it can turn
+//! up, rolled with the caller's RNG so a seeded one always drops the same.
+
+use rand::Rng;
+
+use crate::inventory::Item;
+
+/// An item a table can drop, weighted against the table's other entries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Drop {
+    pub item: Item,
+    pub weight: u32,
+}
+
+/// A weighted list of possible drops. Rarer items get smaller weights, and
+/// most tables make finding nothing the likeliest outcome.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DropTable {
+    /// Weight of dropping nothing at all.
+    pub nothing: u32,
+    pub drops: &'static [Drop],
+}
+
+impl DropTable {
+    /// Picks at most one item.
+    pub fn roll(&self, rng: &mut impl Rng) -> Option<Item> {
+        let total = self.nothing + self.drops.iter().map(|drop| drop.weight).sum::<u32>();
+        if total == 0 {
+            return None;
+        }
+        let mut roll = rng.random_range(0..total);
+        if roll < self.nothing {
+            return None;
+        }
+        roll -= self.nothing;
+        for drop in self.drops {
+            if roll < drop.weight {
+                return Some(drop.item);
+            }
+            roll -= drop.weight;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    const ROLLS: usize = 200;
+
+    #[test]
+    fn a_lone_drop_always_drops() {
+        let table = DropTable {
+            nothing: 0,
+            drops: &[Drop {
+                item: Item::LuckyCharm,
+                weight: 3,
+            }],
+        };
+        let mut rng = StdRng::seed_from_u64(86);
+        for _ in 0..ROLLS {
+            assert_eq!(table.roll(&mut rng), Some(Item::LuckyCharm));
+        }
+    }
+
+    #[test]
+    fn zero_weight_never_drops() {
+        let table = DropTable {
+            nothing: 1,
+            drops: &[
+                Drop {
+                    item: Item::LuckyCharm,
+                    weight: 0,
+                },
+                Drop {
+                    item: Item::Candy,
+                    weight: 1,
+                },
+            ],
+        };
+        let mut rng = StdRng::seed_from_u64(86);
+        let rolls: Vec<_> = (0..ROLLS).map(|_| table.roll(&mut rng)).collect();
+        assert!(!rolls.contains(&Some(Item::LuckyCharm)));
+        assert!(rolls.contains(&Some(Item::Candy)));
+        assert!(rolls.contains(&None));
+    }
+
+    #[test]
+    fn can_drop_nothing() {
+        let mut rng = StdRng::seed_from_u64(86);
+        let nothing_but_nothing = DropTable {
+            nothing: 5,
+            drops: &[],
+        };
+        let empty = DropTable {
+            nothing: 0,
+            drops: &[],
+        };
+        for _ in 0..ROLLS {
+            assert_eq!(nothing_but_nothing.roll(&mut rng), None);
+            assert_eq!(empty.roll(&mut rng), None);
+        }
+    }
+
+    #[test]
+    fn same_seed_same_drops() {
+        let table = crate::crime::CRIMES[1].drops;
+        let rolls = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            (0..ROLLS).map(|_| table.roll(&mut rng)).collect::<Vec<_>>()
+        };
+        assert_eq!(rolls(86), rolls(86));
+    }
+}
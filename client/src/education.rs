@@ -0,0 +1,161 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::deadline;
+use crate::state::GameState;
+
+pub struct Course {
+    pub name: &'static str,
+    pub duration: Duration,
+    /// Added to the player's skills on graduation.
+    pub skill_unlocked: &'static str,
+}
+
+pub const COURSES: [Course; 4] = [
+    Course {
+        name: "First Aid",
+        duration: Duration::from_secs(120),
+        skill_unlocked: "First aid",
+    },
+    Course {
+        name: "Self Defense",
+        duration: Duration::from_secs(180),
+        skill_unlocked: "Self defense",
+    },
+    Course {
+        name: "Mechanics 101",
+        duration: Duration::from_secs(300),
+        skill_unlocked: "Mechanics",
+    },
+    Course {
+        name: "Business Studies",
+        duration: Duration::from_secs(600),
+        skill_unlocked: "Management",
+    },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnrollError {
+    NoSuchCourse,
+    /// Another course is still running.
+    AlreadyEnrolled,
+    AlreadyCompleted,
+}
+
+impl fmt::Display for EnrollError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EnrollError::NoSuchCourse => write!(f, "No such course"),
+            EnrollError::AlreadyEnrolled => write!(f, "You are already taking a course"),
+            EnrollError::AlreadyCompleted => write!(f, "You have already completed that course"),
+        }
+    }
+}
+
+/// The course being taken right now, if any.
+pub fn active_course(state: &GameState) -> Option<&'static Course> {
+    state.course.and_then(|index| COURSES.get(index))
+}
+
+/// Starts the course at `index`. Only one course can run at a time.
+pub fn enroll(
+    state: &mut GameState,
+    index: usize,
+    now: Instant,
+) -> Result<&'static Course, EnrollError> {
+    let course = COURSES.get(index).ok_or(EnrollError::NoSuchCourse)?;
+    if state.course.is_some() {
+        return Err(EnrollError::AlreadyEnrolled);
+    }
+    if state
+        .completed_courses
+        .iter()
+        .any(|name| name == course.name)
+    {
+        return Err(EnrollError::AlreadyCompleted);
+    }
+
+    state.course = Some(index);
+    state.enrolled_until = Some(now + course.duration);
+    Ok(course)
+}
+
+/// Graduates the player once the active course has run its length. Returns
+/// true on graduation so the caller knows something changed.
+pub fn complete_if_finished(state: &mut GameState, now: Instant) -> bool {
+    let Some(course) = active_course(state) else {
+        return false;
+    };
+    if deadline::remaining(state.enrolled_until, now).is_some() {
+        return false;
+    }
+
+    state.course = None;
+    state.enrolled_until = None;
+    state.completed_courses.push(course.name.to_string());
+    state.player.skills.push(course.skill_unlocked.to_string());
+    state.log_news(format!(
+        "Graduated from {} and learned {}.",
+        course.name,
+        course.skill_unlocked.to_lowercase()
+    ));
+    true
+}
+
+/// Seconds of the active course done so far, and its full length.
+pub fn progress(state: &GameState, now: Instant) -> Option<(u64, u64)> {
+    let course = active_course(state)?;
+    let left = deadline::remaining(state.enrolled_until, now).unwrap_or_default();
+    let total = course.duration.as_secs();
+    Some((total.saturating_sub(left.as_secs()), total))
+}
+
+pub fn course_list_text(state: &GameState) -> String {
+    let mut lines: Vec<String> = COURSES
+        .iter()
+        .enumerate()
+        .map(|(i, course)| {
+            let marker = if i == state.course_selected { ">" } else { " " };
+            let status = if state.course == Some(i) {
+                "enrolled"
+            } else if state
+                .completed_courses
+                .iter()
+                .any(|name| name == course.name)
+            {
+                "done"
+            } else {
+                ""
+            };
+            format!(
+                "{} {:<18} {:>4}s  {}",
+                marker,
+                course.name,
+                course.duration.as_secs(),
+                status
+            )
+        })
+        .collect();
+    lines.push(String::new());
+    lines.push("Press e to enroll in the selected course.".to_string());
+    lines.join("\n")
+}
+
+pub fn completed_text(state: &GameState) -> String {
+    if state.completed_courses.is_empty() {
+        return "No courses completed yet.".to_string();
+    }
+    state
+        .completed_courses
+        .iter()
+        .map(|name| {
+            let skill = COURSES
+                .iter()
+                .find(|course| course.name == name)
+                .map(|course| course.skill_unlocked)
+                .unwrap_or("?");
+            format!("{:<18} {}", name, skill)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
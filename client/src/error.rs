@@ -0,0 +1,79 @@
+//! The error type for anything that can fail outside a single subsystem:
+//! reading and writing files, (de)serializing saves, and game rules turning
+//! a command down. Subsystems with their own error enums convert into it at
+//! the edges.
+
+use std::fmt;
+use std::io;
+
+use crate::save;
+
+pub type Result<T> = std::result::Result<T, RustyError>;
+
+#[derive(Debug)]
+pub enum RustyError {
+    Io(io::Error),
+    /// A save or export that couldn't be turned into JSON or back.
+    Serde(serde_json::Error),
+    /// The game itself said no; the message is meant for the player.
+    Game(String),
+    /// A save written by a newer build, with this save version.
+    NewerSave(u64),
+}
+
+impl RustyError {
+    /// The game turning something down, with `reason` for the player.
+    pub fn game(reason: impl fmt::Display) -> Self {
+        RustyError::Game(reason.to_string())
+    }
+
+    /// Whether this is a file that doesn't exist, which callers loading an
+    /// optional file treat as "nothing there yet".
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, RustyError::Io(err) if err.kind() == io::ErrorKind::NotFound)
+    }
+
+    /// Whether this is a save too new to read, which mustn't be written
+    /// over.
+    pub fn is_newer_save(&self) -> bool {
+        matches!(self, RustyError::NewerSave(_))
+    }
+}
+
+impl fmt::Display for RustyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RustyError::Io(err) => write!(f, "{}", err),
+            RustyError::Serde(err) => write!(f, "bad save data: {}", err),
+            RustyError::Game(message) => write!(f, "{}", message),
+            RustyError::NewerSave(version) => write!(
+                f,
+                "saved by a newer rusty (save version {}; this one reads up to {})",
+                version,
+                save::SAVE_VERSION
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RustyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RustyError::Io(err) => Some(err),
+            RustyError::Serde(err) => Some(err),
+            RustyError::Game(_) | RustyError::NewerSave(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for RustyError {
+    fn from(err: io::Error) -> Self {
+        RustyError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for RustyError {
+    fn from(err: serde_json::Error) -> Self {
+        RustyError::Serde(err)
+    }
+}
@@ -0,0 +1,228 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::money::format_money;
+use crate::state::GameState;
+
+/// What it costs to found a faction.
+pub const FACTION_COST: u64 = 1_000;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Faction {
+    pub name: String,
+    pub members: Vec<String>,
+    pub treasury: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FactionError {
+    NoSuchFaction(String),
+    NameTaken(String),
+    AlreadyInFaction,
+    NotInFaction,
+    NotEnoughMoney {
+        needed: u64,
+    },
+    /// Deposits of nothing are rejected rather than logged.
+    EmptyDeposit,
+}
+
+impl fmt::Display for FactionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FactionError::NoSuchFaction(name) => write!(f, "No faction called '{}'", name),
+            FactionError::NameTaken(name) => write!(f, "'{}' is already taken", name),
+            FactionError::AlreadyInFaction => write!(f, "Leave your current faction first"),
+            FactionError::NotInFaction => write!(f, "You aren't in a faction"),
+            FactionError::NotEnoughMoney { needed } => {
+                write!(f, "Not enough money: that needs {}", format_money(*needed))
+            }
+            FactionError::EmptyDeposit => write!(f, "Deposit at least $1"),
+        }
+    }
+}
+
+/// Where factions live. Only the local directory exists today; a networked
+/// one would implement the same calls against a server.
+pub trait FactionBackend {
+    fn list(&self) -> Vec<Faction>;
+    fn find(&self, name: &str) -> Option<Faction>;
+    fn create(&mut self, name: &str, founder: &str) -> Result<Faction, FactionError>;
+    fn join(&mut self, name: &str, member: &str) -> Result<Faction, FactionError>;
+    fn leave(&mut self, name: &str, member: &str) -> Result<(), FactionError>;
+    /// Adds `amount` to the treasury and returns the new balance.
+    fn deposit(&mut self, name: &str, amount: u64) -> Result<u64, FactionError>;
+}
+
+/// Factions kept in the save file, seeded with a couple of rivals to join.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LocalFactions {
+    pub factions: Vec<Faction>,
+}
+
+impl Default for LocalFactions {
+    fn default() -> Self {
+        let faction = |name: &str, members: &[&str], treasury| Faction {
+            name: name.to_string(),
+            members: members.iter().map(|member| member.to_string()).collect(),
+            treasury,
+        };
+        Self {
+            factions: vec![
+                faction("Iron Fist", &["Vex", "Moss", "Kira"], 12_000),
+                faction("Night Owls", &["Juno", "Pike"], 4_500),
+            ],
+        }
+    }
+}
+
+impl LocalFactions {
+    fn get_mut(&mut self, name: &str) -> Result<&mut Faction, FactionError> {
+        self.factions
+            .iter_mut()
+            .find(|faction| faction.name.eq_ignore_ascii_case(name))
+            .ok_or_else(|| FactionError::NoSuchFaction(name.to_string()))
+    }
+}
+
+impl FactionBackend for LocalFactions {
+    fn list(&self) -> Vec<Faction> {
+        self.factions.clone()
+    }
+
+    fn find(&self, name: &str) -> Option<Faction> {
+        self.factions
+            .iter()
+            .find(|faction| faction.name.eq_ignore_ascii_case(name))
+            .cloned()
+    }
+
+    fn create(&mut self, name: &str, founder: &str) -> Result<Faction, FactionError> {
+        if self.find(name).is_some() {
+            return Err(FactionError::NameTaken(name.to_string()));
+        }
+        let faction = Faction {
+            name: name.to_string(),
+            members: vec![founder.to_string()],
+            treasury: 0,
+        };
+        self.factions.push(faction.clone());
+        Ok(faction)
+    }
+
+    fn join(&mut self, name: &str, member: &str) -> Result<Faction, FactionError> {
+        let faction = self.get_mut(name)?;
+        if !faction.members.iter().any(|m| m == member) {
+            faction.members.push(member.to_string());
+        }
+        Ok(faction.clone())
+    }
+
+    fn leave(&mut self, name: &str, member: &str) -> Result<(), FactionError> {
+        let faction = self.get_mut(name)?;
+        faction.members.retain(|m| m != member);
+        Ok(())
+    }
+
+    fn deposit(&mut self, name: &str, amount: u64) -> Result<u64, FactionError> {
+        let faction = self.get_mut(name)?;
+        faction.treasury += amount;
+        Ok(faction.treasury)
+    }
+}
+
+/// The faction the player belongs to, if it still exists.
+pub fn current(state: &GameState) -> Option<Faction> {
+    state
+        .faction
+        .as_deref()
+        .and_then(|name| state.factions.find(name))
+}
+
+/// Founds `name` for `FACTION_COST` with the player as its only member.
+pub fn create(state: &mut GameState, name: &str) -> Result<Faction, FactionError> {
+    if current(state).is_some() {
+        return Err(FactionError::AlreadyInFaction);
+    }
+    if state.player.money < FACTION_COST {
+        return Err(FactionError::NotEnoughMoney {
+            needed: FACTION_COST,
+        });
+    }
+
+    let faction = state.factions.create(name, &state.player.name)?;
+    state.player.money -= FACTION_COST;
+    state.faction = Some(faction.name.clone());
+    state.log_news(format!("Founded the {} faction.", faction.name));
+    Ok(faction)
+}
+
+pub fn join(state: &mut GameState, name: &str) -> Result<Faction, FactionError> {
+    if current(state).is_some() {
+        return Err(FactionError::AlreadyInFaction);
+    }
+
+    let faction = state.factions.join(name, &state.player.name)?;
+    state.faction = Some(faction.name.clone());
+    state.log_news(format!("Joined the {} faction.", faction.name));
+    Ok(faction)
+}
+
+pub fn leave(state: &mut GameState) -> Result<String, FactionError> {
+    let faction = current(state).ok_or(FactionError::NotInFaction)?;
+    state.factions.leave(&faction.name, &state.player.name)?;
+    state.faction = None;
+    state.log_news(format!("Left the {} faction.", faction.name));
+    Ok(faction.name)
+}
+
+/// Moves `amount` of the player's money into their faction's treasury.
+pub fn deposit(state: &mut GameState, amount: u64) -> Result<u64, FactionError> {
+    let faction = current(state).ok_or(FactionError::NotInFaction)?;
+    if amount == 0 {
+        return Err(FactionError::EmptyDeposit);
+    }
+    if state.player.money < amount {
+        return Err(FactionError::NotEnoughMoney { needed: amount });
+    }
+
+    let treasury = state.factions.deposit(&faction.name, amount)?;
+    state.player.money -= amount;
+    Ok(treasury)
+}
+
+pub fn info_text(state: &GameState) -> String {
+    match current(state) {
+        Some(faction) => format!(
+            "Name: {}\nMembers: {}\nTreasury: {}\n\nfaction deposit <amount>\nfaction leave",
+            faction.name,
+            faction.members.len(),
+            format_money(faction.treasury)
+        ),
+        None => {
+            let mut lines = vec![
+                "You aren't in a faction.".to_string(),
+                String::new(),
+                format!(
+                    "faction create <name>  (costs {})",
+                    format_money(FACTION_COST)
+                ),
+                "faction join <name>".to_string(),
+                String::new(),
+                "Factions:".to_string(),
+            ];
+            lines.extend(state.factions.list().iter().map(|faction| {
+                format!("  {:<16} {} members", faction.name, faction.members.len())
+            }));
+            lines.join("\n")
+        }
+    }
+}
+
+pub fn members_text(state: &GameState) -> String {
+    match current(state) {
+        Some(faction) => faction.members.join("\n"),
+        None => "Join a faction to see its members.".to_string(),
+    }
+}
@@ -0,0 +1,339 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use rand::Rng;
+
+use crate::money::format_money;
+use crate::player::PlayerStats;
+use crate::state::GameState;
+use crate::{hospital, jail, level};
+
+/// Energy every attack costs, win or lose.
+pub const FIGHT_ENERGY: u32 = 10;
+/// Health every target starts a fight with. The player brings whatever
+/// health they have.
+pub const FIGHT_HEALTH: u32 = 100;
+/// Exchanges before the fight is called on points.
+pub const MAX_ROUNDS: u32 = 20;
+
+/// Someone in the City the player can pick a fight with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NpcStats {
+    pub name: &'static str,
+    pub strength: u32,
+    pub speed: u32,
+    pub defense: u32,
+    pub dexterity: u32,
+    pub reward_money: u64,
+    pub xp: u64,
+    /// How long a player who loses to them spends in the hospital.
+    pub stay: Duration,
+}
+
+/// Easiest first.
+pub const TARGETS: &[NpcStats] = &[
+    NpcStats {
+        name: "Drunk",
+        strength: 4,
+        speed: 3,
+        defense: 4,
+        dexterity: 3,
+        reward_money: 15,
+        xp: 5,
+        stay: Duration::from_secs(30),
+    },
+    NpcStats {
+        name: "Street thug",
+        strength: 10,
+        speed: 10,
+        defense: 10,
+        dexterity: 10,
+        reward_money: 60,
+        xp: 15,
+        stay: Duration::from_secs(60),
+    },
+    NpcStats {
+        name: "Bouncer",
+        strength: 25,
+        speed: 12,
+        defense: 30,
+        dexterity: 15,
+        reward_money: 180,
+        xp: 40,
+        stay: Duration::from_secs(120),
+    },
+    NpcStats {
+        name: "Gang enforcer",
+        strength: 50,
+        speed: 40,
+        defense: 45,
+        dexterity: 40,
+        reward_money: 600,
+        xp: 100,
+        stay: Duration::from_secs(300),
+    },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FightOutcome {
+    /// `health_left` is the player's, once the fight is over.
+    Won {
+        rounds: u32,
+        health_left: u32,
+    },
+    Lost {
+        rounds: u32,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FightError {
+    NotEnoughEnergy,
+    Locked(&'static str),
+}
+
+impl fmt::Display for FightError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FightError::NotEnoughEnergy => write!(f, "Not enough energy"),
+            FightError::Locked(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+/// Chance in `0.1..=0.95` that a swing lands: the attacker's dexterity
+/// against the defender's speed. Even stats hit two times in three.
+pub fn hit_chance(dexterity: u32, speed: u32) -> f64 {
+    let dexterity = f64::from(dexterity.max(1));
+    let speed = f64::from(speed.max(1));
+    (dexterity * 2.0 / (dexterity * 2.0 + speed)).clamp(0.1, 0.95)
+}
+
+/// Health a landed swing takes off: between 5 and 25, growing with how far
+/// the attacker's strength outweighs the defender's defense.
+pub fn damage(strength: u32, defense: u32) -> u32 {
+    let total = (u64::from(strength) + u64::from(defense)).max(1);
+    5 + (20 * u64::from(strength) / total) as u32
+}
+
+/// Fights it out, the attacker swinging first. Whoever drops to zero health
+/// loses; if nobody has after `MAX_ROUNDS`, the one with more health left
+/// wins, and a draw goes to the defender. Only the swings are random, so the
+/// same `rng` always gives the same fight.
+pub fn resolve_fight(
+    attacker: &PlayerStats,
+    defender: &NpcStats,
+    rng: &mut impl Rng,
+) -> FightOutcome {
+    let attack_hit = hit_chance(attacker.dexterity, defender.speed);
+    let attack_damage = damage(attacker.strength, defender.defense);
+    let defend_hit = hit_chance(defender.dexterity, attacker.speed);
+    let defend_damage = damage(defender.strength, attacker.defense);
+
+    let mut attacker_health = attacker.health;
+    let mut defender_health = FIGHT_HEALTH;
+    for rounds in 1..=MAX_ROUNDS {
+        if rng.random_bool(attack_hit) {
+            defender_health = defender_health.saturating_sub(attack_damage);
+            if defender_health == 0 {
+                return FightOutcome::Won {
+                    rounds,
+                    health_left: attacker_health,
+                };
+            }
+        }
+        if rng.random_bool(defend_hit) {
+            attacker_health = attacker_health.saturating_sub(defend_damage);
+            if attacker_health == 0 {
+                return FightOutcome::Lost { rounds };
+            }
+        }
+    }
+
+    on_points(attacker_health, defender_health)
+}
+
+/// How a fight that went the distance ends: more health left wins, and a
+/// draw goes to the defender.
+fn on_points(attacker_health: u32, defender_health: u32) -> FightOutcome {
+    if attacker_health > defender_health {
+        FightOutcome::Won {
+            rounds: MAX_ROUNDS,
+            health_left: attacker_health,
+        }
+    } else {
+        FightOutcome::Lost { rounds: MAX_ROUNDS }
+    }
+}
+
+/// Attacks the target at `index` in `TARGETS`, spending `FIGHT_ENERGY`. A win
+/// pays the target's reward; a loss puts the player in the hospital.
+pub fn attack(
+    state: &mut GameState,
+    index: usize,
+    now: Instant,
+    rng: &mut impl Rng,
+) -> Result<(&'static NpcStats, FightOutcome), FightError> {
+    if jail::is_jailed(state, now) {
+        return Err(FightError::Locked("You are in jail"));
+    }
+    if hospital::is_hospitalized(state, now) {
+        return Err(FightError::Locked("You are in the hospital"));
+    }
+    let target = &TARGETS[index.min(TARGETS.len() - 1)];
+    if !state.player.spend_energy(FIGHT_ENERGY) {
+        return Err(FightError::NotEnoughEnergy);
+    }
+
+    let outcome = resolve_fight(&state.player, target, rng);
+    match outcome {
+        FightOutcome::Won { health_left, .. } => {
+            state.player.health = health_left;
+            state.player.money = state.player.money.saturating_add(target.reward_money);
+            state.fights_won += 1;
+            state.log_news(format!(
+                "A {} was left on the pavement. The winner walked off {} richer.",
+                target.name.to_lowercase(),
+                format_money(target.reward_money)
+            ));
+            level::award_xp(state, target.xp);
+        }
+        FightOutcome::Lost { .. } => hospital::admit(
+            state,
+            now,
+            target.stay,
+            format!("Beaten up by a {}", target.name.to_lowercase()),
+        ),
+    }
+    Ok((target, outcome))
+}
+
+pub fn target_list_text(selected: usize) -> String {
+    let rows: Vec<String> = TARGETS
+        .iter()
+        .enumerate()
+        .map(|(i, target)| {
+            let marker = if i == selected { ">" } else { " " };
+            format!(
+                "{} {:<14} STR {:<3} DEF {:<3} {}",
+                marker,
+                target.name,
+                target.strength,
+                target.defense,
+                format_money(target.reward_money)
+            )
+        })
+        .collect();
+    format!(
+        "Pick a fight ({} energy)\n{}\n\nt: next target   a: attack",
+        FIGHT_ENERGY,
+        rows.join("\n")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    fn fighter(stat: u32, health: u32) -> PlayerStats {
+        PlayerStats {
+            strength: stat,
+            speed: stat,
+            defense: stat,
+            dexterity: stat,
+            health,
+            max_health: health.max(1),
+            ..PlayerStats::default()
+        }
+    }
+
+    #[test]
+    fn hit_chance_is_clamped() {
+        assert_eq!(hit_chance(1, 1_000), 0.1);
+        assert_eq!(hit_chance(1_000, 1), 0.95);
+        assert_eq!(hit_chance(0, 0), 2.0 / 3.0);
+        assert_eq!(hit_chance(10, 10), 2.0 / 3.0);
+    }
+
+    #[test]
+    fn damage_stays_between_5_and_25() {
+        assert_eq!(damage(0, 0), 5);
+        assert_eq!(damage(0, u32::MAX), 5);
+        assert_eq!(damage(u32::MAX, 0), 25);
+        assert_eq!(damage(10, 10), 15);
+        assert!((5..=25).contains(&damage(u32::MAX, u32::MAX)));
+    }
+
+    #[test]
+    fn same_seed_same_fight() {
+        let player = fighter(10, 100);
+        for target in TARGETS {
+            let fight = |seed| resolve_fight(&player, target, &mut StdRng::seed_from_u64(seed));
+            assert_eq!(fight(44), fight(44));
+        }
+    }
+
+    #[test]
+    fn knockouts_decide_the_fight() {
+        let mut rng = StdRng::seed_from_u64(44);
+        let champion = fighter(1_000, 100);
+        assert!(matches!(
+            resolve_fight(&champion, &TARGETS[0], &mut rng),
+            FightOutcome::Won { .. }
+        ));
+        let weakling = fighter(1, 1);
+        assert!(matches!(
+            resolve_fight(&weakling, &TARGETS[3], &mut rng),
+            FightOutcome::Lost { .. }
+        ));
+    }
+
+    #[test]
+    fn points_decide_a_fight_that_goes_the_distance() {
+        assert_eq!(
+            on_points(60, 40),
+            FightOutcome::Won {
+                rounds: MAX_ROUNDS,
+                health_left: 60
+            }
+        );
+        assert_eq!(on_points(40, 60), FightOutcome::Lost { rounds: MAX_ROUNDS });
+        assert_eq!(on_points(50, 50), FightOutcome::Lost { rounds: MAX_ROUNDS });
+    }
+
+    #[test]
+    fn losing_sends_the_player_to_hospital() {
+        let mut rng = StdRng::seed_from_u64(44);
+        let mut state = GameState {
+            player: fighter(1, 1),
+            ..GameState::default()
+        };
+        state.player.energy = FIGHT_ENERGY;
+        let now = Instant::now();
+        let (target, outcome) = attack(&mut state, 3, now, &mut rng).unwrap();
+        assert!(matches!(outcome, FightOutcome::Lost { .. }));
+        assert!(hospital::is_hospitalized(&state, now));
+        assert_eq!(state.hospital_until, Some(now + target.stay));
+        assert_eq!(state.player.energy, 0);
+        assert_eq!(
+            attack(&mut state, 0, now, &mut rng),
+            Err(FightError::Locked("You are in the hospital"))
+        );
+    }
+
+    #[test]
+    fn winning_pays_without_overflowing() {
+        let mut rng = StdRng::seed_from_u64(44);
+        let mut state = GameState {
+            player: fighter(1_000, 100),
+            ..GameState::default()
+        };
+        state.player.money = u64::MAX - 1;
+        let (_, outcome) = attack(&mut state, 0, Instant::now(), &mut rng).unwrap();
+        assert!(matches!(outcome, FightOutcome::Won { .. }));
+        assert_eq!(state.player.money, u64::MAX);
+        assert_eq!(state.fights_won, 1);
+    }
+}
@@ -0,0 +1,44 @@
+//! The status bar's money flash: green for a moment when money comes in, red
+//! when it goes out, timed on the game clock.
+
+use std::time::Duration;
+
+use ratatui::style::Style;
+
+use crate::theme::Theme;
+
+/// How long the money field stays colored after a change.
+pub const DURATION: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Default)]
+pub struct MoneyFlash {
+    /// Money as of the last `update`; `None` until the first one, so the
+    /// opening balance doesn't flash.
+    last: Option<u64>,
+    /// Whether the running flash is for a gain, and when it ends.
+    flash: Option<(bool, Duration)>,
+}
+
+impl MoneyFlash {
+    /// Compares `money` with last time and starts a flash if it changed.
+    pub fn update(&mut self, money: u64, now: Duration) {
+        if let Some(previous) = self.last.replace(money)
+            && previous != money
+        {
+            self.flash = Some((money > previous, now + DURATION));
+        }
+    }
+
+    /// Whether a flash still has time left. One left over from a clock that
+    /// has since been reset counts as finished.
+    pub fn is_running(&self, now: Duration) -> bool {
+        self.flash
+            .is_some_and(|(_, until)| now < until && until - now <= DURATION)
+    }
+
+    /// The money field's style while a flash runs.
+    pub fn style(&self, now: Duration, theme: &Theme) -> Option<Style> {
+        let (gain, _) = self.flash.filter(|_| self.is_running(now))?;
+        Some(if gain { theme.gain } else { theme.loss })
+    }
+}
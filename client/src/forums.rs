@@ -0,0 +1,156 @@
+use std::fmt;
+
+use ratatui::text::{Line, Text};
+use serde::{Deserialize, Serialize};
+
+use crate::state::GameState;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Post {
+    pub author: String,
+    pub body: String,
+}
+
+impl Post {
+    fn new(author: &str, body: &str) -> Self {
+        Self {
+            author: author.to_string(),
+            body: body.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Thread {
+    pub title: String,
+    /// Oldest first; the first post opened the thread.
+    pub posts: Vec<Post>,
+}
+
+impl Thread {
+    fn new(title: &str, posts: Vec<Post>) -> Self {
+        Self {
+            title: title.to_string(),
+            posts,
+        }
+    }
+}
+
+/// The town's message board. There's no server to talk to, so the threads
+/// are started by townsfolk and only the player's replies are new.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Forums {
+    pub threads: Vec<Thread>,
+}
+
+impl Default for Forums {
+    fn default() -> Self {
+        Self {
+            threads: vec![
+                Thread::new(
+                    "Welcome, new citizens!",
+                    vec![
+                        Post::new(
+                            "The Mayor",
+                            "Introduce yourselves here. Be civil; the Rules page \
+                             applies on the boards too.",
+                        ),
+                        Post::new("Mira", "Hi all! Just moved in above the bakery."),
+                    ],
+                ),
+                Thread::new(
+                    "Best way to train speed?",
+                    vec![
+                        Post::new("Dex", "Gym's packed every morning. Any tips?"),
+                        Post::new(
+                            "Old Tom",
+                            "Little and often. Don't burn all your energy in one go.",
+                        ),
+                    ],
+                ),
+                Thread::new(
+                    "Casino is rigged",
+                    vec![
+                        Post::new("Lucky Lou", "Lost $400 on red. Seven times. Rigged."),
+                        Post::new("Dealer Dan", "That's just how red works, Lou."),
+                    ],
+                ),
+                Thread::new(
+                    "Property prices",
+                    vec![Post::new(
+                        "Vera",
+                        "Is anyone else's rent income worth the upkeep, or is it just me?",
+                    )],
+                ),
+            ],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplyError {
+    Empty,
+    NoThread,
+}
+
+impl fmt::Display for ReplyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplyError::Empty => write!(f, "Usage: reply <text>"),
+            ReplyError::NoThread => write!(f, "Pick a thread on the Forums page first"),
+        }
+    }
+}
+
+/// Adds the player's reply to the thread highlighted on the Forums page.
+/// Returns the thread's title.
+pub fn reply(state: &mut GameState, body: &str) -> Result<String, ReplyError> {
+    let body = body.trim();
+    if body.is_empty() {
+        return Err(ReplyError::Empty);
+    }
+    let author = state.player.name.clone();
+    let thread = state
+        .forums
+        .threads
+        .get_mut(state.thread_selected)
+        .ok_or(ReplyError::NoThread)?;
+    thread.posts.push(Post::new(&author, body));
+    Ok(thread.title.clone())
+}
+
+/// One line per thread with `selected` marked and its post count.
+pub fn thread_list_text(forums: &Forums, selected: usize) -> Text<'static> {
+    if forums.threads.is_empty() {
+        return Text::raw("No threads.");
+    }
+    forums
+        .threads
+        .iter()
+        .enumerate()
+        .map(|(i, thread)| {
+            let marker = if i == selected { ">" } else { " " };
+            Line::raw(format!(
+                "{} {} ({})",
+                marker,
+                thread.title,
+                thread.posts.len()
+            ))
+        })
+        .collect::<Vec<_>>()
+        .into()
+}
+
+/// The selected thread's posts, oldest first.
+pub fn thread_text(forums: &Forums, selected: usize) -> String {
+    let Some(thread) = forums.threads.get(selected) else {
+        return "Nothing to read.".to_string();
+    };
+    let posts: Vec<String> = thread
+        .posts
+        .iter()
+        .map(|post| format!("{}:\n{}", post.author, post.body))
+        .collect();
+    format!("{}\n\n{}", thread.title, posts.join("\n\n"))
+}
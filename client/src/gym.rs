@@ -0,0 +1,215 @@
+use std::collections::VecDeque;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::player::{PlayerStats, Stat};
+
+/// Energy spent by a single press of the Gym's train key.
+pub const TRAIN_ENERGY: u32 = 10;
+/// How many entries the "Recent training log" keeps.
+pub const TRAINING_LOG_LEN: usize = 5;
+
+/// A piece of Gym equipment. Each trains one stat; the better ones cost more
+/// energy per session but pay back more than they cost, and only open up
+/// once the player has the level for them.
+pub struct GymMachine {
+    pub name: &'static str,
+    pub stat: Stat,
+    pub energy_cost: u32,
+    /// Scales the gain `train_stat` works out for the energy spent.
+    pub gain_multiplier: f64,
+    pub min_level: u32,
+}
+
+impl GymMachine {
+    /// Gain per point of energy compared with the basic machines.
+    pub fn efficiency(&self) -> f64 {
+        self.gain_multiplier * TRAIN_ENERGY as f64 / self.energy_cost as f64
+    }
+}
+
+/// Every machine, a basic one for each stat first.
+pub const MACHINES: [GymMachine; 9] = [
+    GymMachine {
+        name: "Free Weights",
+        stat: Stat::Strength,
+        energy_cost: TRAIN_ENERGY,
+        gain_multiplier: 1.0,
+        min_level: 1,
+    },
+    GymMachine {
+        name: "Treadmill",
+        stat: Stat::Speed,
+        energy_cost: TRAIN_ENERGY,
+        gain_multiplier: 1.0,
+        min_level: 1,
+    },
+    GymMachine {
+        name: "Sparring Pads",
+        stat: Stat::Defense,
+        energy_cost: TRAIN_ENERGY,
+        gain_multiplier: 1.0,
+        min_level: 1,
+    },
+    GymMachine {
+        name: "Agility Ladder",
+        stat: Stat::Dexterity,
+        energy_cost: TRAIN_ENERGY,
+        gain_multiplier: 1.0,
+        min_level: 1,
+    },
+    GymMachine {
+        name: "Power Rack",
+        stat: Stat::Strength,
+        energy_cost: 20,
+        gain_multiplier: 2.5,
+        min_level: 3,
+    },
+    GymMachine {
+        name: "Sprint Sled",
+        stat: Stat::Speed,
+        energy_cost: 20,
+        gain_multiplier: 2.5,
+        min_level: 3,
+    },
+    GymMachine {
+        name: "Heavy Bag",
+        stat: Stat::Defense,
+        energy_cost: 15,
+        gain_multiplier: 1.8,
+        min_level: 5,
+    },
+    GymMachine {
+        name: "Reaction Wall",
+        stat: Stat::Dexterity,
+        energy_cost: 15,
+        gain_multiplier: 1.8,
+        min_level: 5,
+    },
+    GymMachine {
+        name: "Olympic Platform",
+        stat: Stat::Strength,
+        energy_cost: 30,
+        gain_multiplier: 4.5,
+        min_level: 8,
+    },
+];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrainingEntry {
+    pub stat: Stat,
+    pub gain: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrainError {
+    /// Asked to train with zero energy.
+    NoEnergy,
+    /// The player has less energy than requested.
+    NotEnoughEnergy,
+    /// The machine needs this level.
+    Locked(u32),
+}
+
+impl fmt::Display for TrainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrainError::NoEnergy => write!(f, "You need to spend some energy to train"),
+            TrainError::NotEnoughEnergy => write!(f, "Not enough energy"),
+            TrainError::Locked(level) => write!(f, "That machine unlocks at level {}", level),
+        }
+    }
+}
+
+/// Spends `energy` on `stat` and returns the gain, scaled by `multiplier`.
+/// Gains shrink as the stat grows, but any successful session is worth at
+/// least one point.
+pub fn train_stat(
+    stat: Stat,
+    energy: u32,
+    multiplier: f64,
+    state: &mut PlayerStats,
+) -> Result<u32, TrainError> {
+    if energy == 0 {
+        return Err(TrainError::NoEnergy);
+    }
+    if !state.spend_energy(energy) {
+        return Err(TrainError::NotEnoughEnergy);
+    }
+
+    let current = state.stat(stat) as f64;
+    let gain = (energy as f64 * 50.0 / (current + 50.0) * multiplier)
+        .round()
+        .max(1.0) as u32;
+    *state.stat_mut(stat) += gain;
+    Ok(gain)
+}
+
+/// One session on `machine`, through the same math as `train_stat`.
+pub fn train_on(machine: &GymMachine, state: &mut PlayerStats) -> Result<u32, TrainError> {
+    if state.level < machine.min_level {
+        return Err(TrainError::Locked(machine.min_level));
+    }
+    train_stat(
+        machine.stat,
+        machine.energy_cost,
+        machine.gain_multiplier,
+        state,
+    )
+}
+
+/// Pushes a training result, dropping the oldest once the log is full.
+pub fn log_training(log: &mut VecDeque<TrainingEntry>, entry: TrainingEntry) {
+    log.push_front(entry);
+    log.truncate(TRAINING_LOG_LEN);
+}
+
+/// The player's stats over the recent training log.
+pub fn training_panel(stats: &PlayerStats, log: &VecDeque<TrainingEntry>) -> String {
+    let stats: Vec<String> = Stat::ALL
+        .iter()
+        .map(|&stat| format!("{:<10} {:>6}", stat.name(), stats.stat(stat)))
+        .collect();
+    format!(
+        "{}\n\nRecent training\n{}",
+        stats.join("\n"),
+        training_log_text(log)
+    )
+}
+
+/// Every machine with `selected` marked, its cost and how far it stretches
+/// a point of energy. Locked ones say what level they need.
+pub fn machines_text(stats: &PlayerStats, selected: usize) -> String {
+    MACHINES
+        .iter()
+        .enumerate()
+        .map(|(i, machine)| {
+            let marker = if i == selected { ">" } else { " " };
+            let status = if stats.level < machine.min_level {
+                format!("level {}", machine.min_level)
+            } else {
+                format!("{:.2}x per energy", machine.efficiency())
+            };
+            format!(
+                "{} {:<16} {:<10} {:>2} energy  {}",
+                marker,
+                machine.name,
+                machine.stat.name(),
+                machine.energy_cost,
+                status
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn training_log_text(log: &VecDeque<TrainingEntry>) -> String {
+    if log.is_empty() {
+        return "No training yet.".to_string();
+    }
+    log.iter()
+        .map(|entry| format!("{:<10} +{}", entry.stat.name(), entry.gain))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
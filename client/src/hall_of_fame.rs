@@ -0,0 +1,191 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::money::format_money;
+use crate::net_worth;
+use crate::state::GameState;
+
+/// What the leaderboard is ranked by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Metric {
+    #[default]
+    Wealth,
+    NetWorth,
+    Strength,
+    Level,
+}
+
+impl Metric {
+    pub const ALL: [Metric; 4] = [
+        Metric::Wealth,
+        Metric::NetWorth,
+        Metric::Strength,
+        Metric::Level,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Metric::Wealth => "Wealth",
+            Metric::NetWorth => "Net worth",
+            Metric::Strength => "Strength",
+            Metric::Level => "Level",
+        }
+    }
+
+    pub fn next(self) -> Metric {
+        let index = Metric::ALL.iter().position(|&m| m == self).unwrap_or(0);
+        Metric::ALL[(index + 1) % Metric::ALL.len()]
+    }
+
+    pub fn prev(self) -> Metric {
+        let index = Metric::ALL.iter().position(|&m| m == self).unwrap_or(0);
+        Metric::ALL[(index + Metric::ALL.len() - 1) % Metric::ALL.len()]
+    }
+
+    fn value(self, competitor: &Competitor) -> u64 {
+        match self {
+            Metric::Wealth => competitor.money,
+            Metric::NetWorth => competitor.net_worth.unwrap_or(competitor.money),
+            Metric::Strength => competitor.strength.into(),
+            Metric::Level => competitor.level.into(),
+        }
+    }
+
+    fn format(self, value: u64) -> String {
+        match self {
+            Metric::Wealth | Metric::NetWorth => format_money(value),
+            Metric::Strength | Metric::Level => value.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Competitor {
+    pub name: String,
+    pub money: u64,
+    pub strength: u32,
+    pub level: u32,
+    /// Everything the competitor owns. Rivals only keep cash, so for them
+    /// it's `None` and their money stands in.
+    #[serde(skip)]
+    pub net_worth: Option<u64>,
+    #[serde(skip)]
+    pub is_player: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RankedEntry {
+    /// 1-based. Tied entries share a rank and the next rank is skipped, so
+    /// two players tied for first are followed by third.
+    pub rank: usize,
+    pub name: String,
+    pub value: u64,
+    pub is_player: bool,
+}
+
+/// Ranks `entries` by `metric`, highest first. Ties are broken by name for a
+/// stable order but keep the same rank.
+pub fn leaderboard(entries: &[Competitor], metric: Metric) -> Vec<RankedEntry> {
+    let mut sorted: Vec<&Competitor> = entries.iter().collect();
+    sorted.sort_by(|a, b| {
+        metric
+            .value(b)
+            .cmp(&metric.value(a))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+
+    let mut ranked: Vec<RankedEntry> = Vec::with_capacity(sorted.len());
+    for (i, competitor) in sorted.into_iter().enumerate() {
+        let value = metric.value(competitor);
+        let rank = match ranked.last() {
+            Some(prev) if prev.value == value => prev.rank,
+            _ => i + 1,
+        };
+        ranked.push(RankedEntry {
+            rank,
+            name: competitor.name.clone(),
+            value,
+            is_player: competitor.is_player,
+        });
+    }
+    ranked
+}
+
+/// The NPC competition. Saved so their progress carries over between
+/// sessions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HallOfFame {
+    pub rivals: Vec<Competitor>,
+    #[serde(skip)]
+    pub metric: Metric,
+}
+
+impl Default for HallOfFame {
+    fn default() -> Self {
+        let rival = |name: &str, money, strength, level| Competitor {
+            name: name.to_string(),
+            money,
+            strength,
+            level,
+            net_worth: None,
+            is_player: false,
+        };
+        Self {
+            rivals: vec![
+                rival("Vex", 18_000, 60, 8),
+                rival("Kira", 9_500, 42, 6),
+                rival("Juno", 4_200, 25, 4),
+                rival("Moss", 2_000, 30, 3),
+                rival("Pike", 800, 15, 2),
+            ],
+            metric: Metric::default(),
+        }
+    }
+}
+
+/// Lets the rivals make a little progress for each of `cycles` pay cycles.
+pub fn grow_rivals(hall: &mut HallOfFame, cycles: u32, rng: &mut impl Rng) {
+    for _ in 0..cycles {
+        for rival in &mut hall.rivals {
+            rival.money += rng.random_range(0..=60);
+            rival.strength += u32::from(rng.random_bool(0.3));
+            rival.level += u32::from(rng.random_bool(0.02));
+        }
+    }
+}
+
+/// The rivals plus the player, ready for `leaderboard`.
+pub fn competitors(state: &GameState) -> Vec<Competitor> {
+    let mut entries = state.hall_of_fame.rivals.clone();
+    entries.push(Competitor {
+        name: state.player.name.clone(),
+        money: state.player.money,
+        strength: state.player.strength,
+        level: state.player.level,
+        net_worth: Some(net_worth::net_worth(state)),
+        is_player: true,
+    });
+    entries
+}
+
+pub fn format_entry(entry: &RankedEntry, metric: Metric) -> String {
+    format!(
+        "#{:<3} {:<12} {:>10}",
+        entry.rank,
+        entry.name,
+        metric.format(entry.value)
+    )
+}
+
+pub fn rank_summary(board: &[RankedEntry], metric: Metric) -> String {
+    match board.iter().find(|entry| entry.is_player) {
+        Some(entry) => format!(
+            "Your rank: #{} of {} by {}\n{}",
+            entry.rank,
+            board.len(),
+            metric.name().to_lowercase(),
+            metric.format(entry.value)
+        ),
+        None => "You aren't on the board.".to_string(),
+    }
+}
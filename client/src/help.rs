@@ -0,0 +1,114 @@
+use crate::command::COMMANDS;
+
+/// Every key the app responds to, as `(keys, what they do)`. The help overlay
+/// is built from this table, so add a row here whenever a binding changes.
+/// Rows for actions that `keys.toml` can rebind show the defaults.
+pub const KEYBINDINGS: &[(&str, &str)] = &[
+    ("? / F1", "Show or hide this help"),
+    (
+        "Tab",
+        "Complete the typed command, or switch typing / navigating",
+    ),
+    (": / i", "Start typing a command (navigate mode)"),
+    ("Esc", "Close this help or a prompt, stop typing, or quit"),
+    ("Ctrl-C", "Quit at once, without saving"),
+    ("Ctrl-P", "Search the commands and pages, and run one"),
+    ("y / n", "Answer an \"Are you sure?\" prompt"),
+    ("F5", "Save the game"),
+    ("u", "Undo the last buy, sell or discard (navigate mode)"),
+    ("n", "Show recent notifications (navigate mode)"),
+    (
+        "p",
+        "Pause the clock; the pause command works on every page",
+    ),
+    (
+        "Up / Down",
+        "Move through the menu, or recall commands while typing",
+    ),
+    ("k / j", "Move through the menu (navigate mode)"),
+    ("g / G", "First / last page (navigate mode)"),
+    ("1-24", "Jump to a page by number (navigate mode)"),
+    ("/", "Filter the menu; Enter picks the first match"),
+    (
+        "[ / ] or Alt-Left / Alt-Right",
+        "Back or forward through the pages you've visited",
+    ),
+    ("PageUp / PageDown", "Scroll the page content"),
+    (
+        "l",
+        "Move through the Items or shop list with Up / Down (Esc to leave)",
+    ),
+    (
+        "Left / Right",
+        "Change the selection on a page, or move the cursor while typing",
+    ),
+    (
+        "Home / End / Delete",
+        "Edit the typed command at the cursor",
+    ),
+    ("Enter", "Run the typed command, or open a page prompt"),
+    ("City: y / n", "Confirm or cancel a purchase"),
+    ("City: t / a", "Pick a target, or attack it"),
+    ("Home: c", "Claim the daily reward"),
+    ("Items: u / s / d / c", "Use, sell, discard one or cancel"),
+    (
+        "Items: + / - / a",
+        "Use or sell more at once, or the whole stack",
+    ),
+    ("Items: s", "Sort by rarity, or stop sorting"),
+    ("Job: w", "Work on the selected task"),
+    (
+        "Gym: t / Left / Right",
+        "Train on the selected machine, or pick one",
+    ),
+    ("Properties: b / s", "Buy or sell the selected property"),
+    ("Hall of Fame: Left / Right", "Change the ranking"),
+    ("Education: e", "Enroll in the selected course"),
+    ("Crimes: c", "Commit the selected crime"),
+    (
+        "Messages: Left / Right",
+        "Read the previous or next message",
+    ),
+    (
+        "Forums: Left / Right",
+        "Pick a thread; reply with the reply command",
+    ),
+    ("Settings: + / -", "Change the selected setting"),
+    ("Settings: r", "Start a new game"),
+    ("Casino: b", "Switch between slots, blackjack and roulette"),
+    (
+        "Casino: s",
+        "Spin the slots or the wheel, or stand in blackjack",
+    ),
+    ("Casino: d / h", "Deal or hit in blackjack"),
+    ("Casino: t / + / -", "Pick a roulette bet and number"),
+    ("Casino: p / x", "Place a roulette bet, or clear the slip"),
+    ("Jail: e", "Try to escape"),
+    ("Hospital: p", "Pay to leave early"),
+    ("Recruit Citizens: c", "Claim recruit rewards"),
+    ("Rules: Left / Right / t", "Pick the rule and who to report"),
+    ("Rules: r", "File the report"),
+];
+
+/// The overlay body: the key table followed by the command list.
+pub fn help_text() -> String {
+    let width = KEYBINDINGS
+        .iter()
+        .chain(COMMANDS)
+        .map(|(keys, _)| keys.len())
+        .max()
+        .unwrap_or(0);
+    let rows = |table: &[(&str, &str)]| {
+        table
+            .iter()
+            .map(|(keys, action)| format!("{:<width$}  {}", keys, action))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    format!(
+        "Keys\n{}\n\nCommands\n{}",
+        rows(KEYBINDINGS),
+        rows(COMMANDS)
+    )
+}
@@ -0,0 +1,57 @@
+/// Most commands the Input box remembers.
+pub const HISTORY_LEN: usize = 100;
+
+/// Previously entered commands, recalled with Up/Down like a shell.
+#[derive(Debug, Default)]
+pub struct CommandHistory {
+    entries: Vec<String>,
+    /// Position while browsing; `entries.len()` means "not browsing".
+    cursor: usize,
+    /// What was typed before browsing started, restored by stepping past the
+    /// newest entry.
+    draft: String,
+}
+
+impl CommandHistory {
+    /// Records a submitted command. Blank lines and repeats of the previous
+    /// command aren't stored. Browsing starts again from the newest entry.
+    pub fn push(&mut self, command: &str) {
+        let command = command.trim();
+        if !command.is_empty() && self.entries.last().map(String::as_str) != Some(command) {
+            self.entries.push(command.to_string());
+            if self.entries.len() > HISTORY_LEN {
+                self.entries.remove(0);
+            }
+        }
+        self.cursor = self.entries.len();
+        self.draft.clear();
+    }
+
+    /// Steps back to an older command. `current` is the Input box text, kept
+    /// as the draft when browsing starts.
+    pub fn prev(&mut self, current: &str) -> Option<&str> {
+        if self.cursor == 0 {
+            return None;
+        }
+        if self.cursor == self.entries.len() {
+            self.draft = current.to_string();
+        }
+        self.cursor -= 1;
+        self.entries.get(self.cursor).map(String::as_str)
+    }
+
+    /// Steps forward to a newer command, or back to the draft after the
+    /// newest one.
+    pub fn next(&mut self) -> Option<&str> {
+        if self.cursor >= self.entries.len() {
+            return None;
+        }
+        self.cursor += 1;
+        Some(
+            self.entries
+                .get(self.cursor)
+                .map(String::as_str)
+                .unwrap_or(&self.draft),
+        )
+    }
+}
@@ -0,0 +1,86 @@
+use std::time::{Duration, Instant};
+
+use crate::deadline;
+use crate::money::format_money;
+use crate::state::GameState;
+
+/// Price of skipping one second of recovery.
+pub const RECOVERY_COST_PER_SEC: u64 = 2;
+
+pub fn is_hospitalized(state: &GameState, now: Instant) -> bool {
+    deadline::remaining(state.hospital_until, now).is_some()
+}
+
+pub fn admit(state: &mut GameState, now: Instant, stay: Duration, injury: impl Into<String>) {
+    let injury = injury.into();
+    state.log_news(format!("Hospitalized: {}", injury));
+    state.hospital_until = Some(now + stay);
+    state.hospital_stay_secs = stay.as_secs();
+    state.injury = Some(injury);
+    state.player.health = 0;
+}
+
+/// Discharges the player once the timer has run out, clearing the injury and
+/// restoring full health.
+/// A timer with nothing left on it counts as fully recovered.
+pub fn discharge_if_recovered(state: &mut GameState, now: Instant) -> bool {
+    if state.hospital_until.is_none() && state.injury.is_none() {
+        return false;
+    }
+    if is_hospitalized(state, now) {
+        return false;
+    }
+    state.hospital_until = None;
+    state.injury = None;
+    state.player.health = state.player.max_health;
+    true
+}
+
+/// What it would cost right now to walk out of the hospital.
+pub fn recovery_cost(state: &GameState, now: Instant) -> u64 {
+    deadline::remaining(state.hospital_until, now)
+        .map(|left| left.as_secs_f64().ceil() as u64 * RECOVERY_COST_PER_SEC)
+        .unwrap_or(0)
+}
+
+/// Pays for instant recovery. Returns the amount paid, or the cost the
+/// player couldn't cover.
+pub fn pay_for_recovery(state: &mut GameState, now: Instant) -> Result<u64, u64> {
+    let cost = recovery_cost(state, now);
+    if cost > state.player.money {
+        return Err(cost);
+    }
+    state.player.money -= cost;
+    state.hospital_until = None;
+    state.injury = None;
+    state.player.health = state.player.max_health;
+    Ok(cost)
+}
+
+/// Seconds recovered and the full stay, while hospitalized.
+pub fn stay_progress(state: &GameState, now: Instant) -> Option<(u64, u64)> {
+    let left = deadline::remaining(state.hospital_until, now)?;
+    let total = state.hospital_stay_secs;
+    Some((total.saturating_sub(left.as_secs()), total))
+}
+
+pub fn status_text(state: &GameState, now: Instant) -> String {
+    match deadline::remaining(state.hospital_until, now) {
+        Some(left) => format!(
+            "Recovering: {}\nCause: {}",
+            deadline::format_remaining(left),
+            state.injury.as_deref().unwrap_or("unknown")
+        ),
+        None => "You are fully recovered.".to_string(),
+    }
+}
+
+pub fn recovery_text(state: &GameState, now: Instant) -> String {
+    if !is_hospitalized(state, now) {
+        return "Rest up between crimes to stay out of here.".to_string();
+    }
+    format!(
+        "Press r to pay {} for instant recovery.\nThe price drops as you heal.",
+        format_money(recovery_cost(state, now))
+    )
+}
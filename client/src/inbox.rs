@@ -0,0 +1,112 @@
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Text},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Message {
+    pub from: String,
+    pub subject: String,
+    pub body: String,
+    pub read: bool,
+}
+
+impl Message {
+    pub fn new(from: &str, subject: &str, body: &str) -> Self {
+        Self {
+            from: from.to_string(),
+            subject: subject.to_string(),
+            body: body.to_string(),
+            read: false,
+        }
+    }
+}
+
+/// The player's mail, oldest first.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Inbox {
+    pub messages: Vec<Message>,
+}
+
+impl Default for Inbox {
+    /// Every new game starts with a letter from the mayor.
+    fn default() -> Self {
+        Self {
+            messages: vec![Message::new(
+                "The Mayor",
+                "Welcome to Rusty",
+                "Welcome to town! Stop by the Gym to get stronger, find work at \
+                 the Job centre, and pick up supplies in the City. Keep your \
+                 nose clean, or the Jail will make room for you.\n\n\
+                 Press ? at any time for help.",
+            )],
+        }
+    }
+}
+
+impl Inbox {
+    pub fn unread(&self) -> usize {
+        self.messages.iter().filter(|message| !message.read).count()
+    }
+
+    /// The unread count and the newest unread subjects, for the Dashboard.
+    pub fn summary_text(&self) -> String {
+        let unread = self.unread();
+        if unread == 0 {
+            return "No unread messages.".to_string();
+        }
+        let mut lines = vec![format!("{} unread", unread)];
+        lines.extend(
+            self.messages
+                .iter()
+                .rev()
+                .filter(|message| !message.read)
+                .take(3)
+                .map(|message| format!("  {}: {}", message.from, message.subject)),
+        );
+        lines.join("\n")
+    }
+
+    pub fn mark_read(&mut self, index: usize) {
+        if let Some(message) = self.messages.get_mut(index) {
+            message.read = true;
+        }
+    }
+}
+
+/// One line per message with `selected` marked; unread ones are bold.
+pub fn message_list_text(inbox: &Inbox, selected: usize) -> Text<'static> {
+    if inbox.messages.is_empty() {
+        return Text::raw("No messages.");
+    }
+    inbox
+        .messages
+        .iter()
+        .enumerate()
+        .map(|(i, message)| {
+            let marker = if i == selected { ">" } else { " " };
+            let style = if message.read {
+                Style::default()
+            } else {
+                Style::default().add_modifier(Modifier::BOLD)
+            };
+            Line::styled(
+                format!("{} {:<12} {}", marker, message.from, message.subject),
+                style,
+            )
+        })
+        .collect::<Vec<_>>()
+        .into()
+}
+
+pub fn message_text(inbox: &Inbox, selected: usize) -> String {
+    match inbox.messages.get(selected) {
+        Some(message) => format!(
+            "From: {}\nSubject: {}\n\n{}",
+            message.from, message.subject, message.body
+        ),
+        None => "Nothing to read.".to_string(),
+    }
+}
@@ -0,0 +1,315 @@
+use std::fmt;
+
+use ratatui::style::Style;
+use serde::{Deserialize, Serialize};
+
+use crate::money::format_money;
+use crate::player::PlayerStats;
+use crate::theme::Theme;
+
+/// How hard an item is to come by. Rarer items cost more and are shown in
+/// their own color, with the name spelled out too for terminals that can't
+/// show the colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Rarity {
+    Common,
+    Uncommon,
+    Rare,
+    Epic,
+}
+
+impl Rarity {
+    pub fn name(self) -> &'static str {
+        match self {
+            Rarity::Common => "Common",
+            Rarity::Uncommon => "Uncommon",
+            Rarity::Rare => "Rare",
+            Rarity::Epic => "Epic",
+        }
+    }
+
+    pub fn style(self, theme: &Theme) -> Style {
+        match self {
+            Rarity::Common => theme.common,
+            Rarity::Uncommon => theme.uncommon,
+            Rarity::Rare => theme.rare,
+            Rarity::Epic => theme.epic,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Item {
+    EnergyDrink,
+    Candy,
+    FirstAidKit,
+    Newspaper,
+    LuckyCharm,
+}
+
+impl Item {
+    pub fn name(self) -> &'static str {
+        match self {
+            Item::EnergyDrink => "Energy Drink",
+            Item::Candy => "Candy",
+            Item::FirstAidKit => "First Aid Kit",
+            Item::Newspaper => "Old Newspaper",
+            Item::LuckyCharm => "Lucky Charm",
+        }
+    }
+
+    pub fn rarity(self) -> Rarity {
+        match self {
+            Item::Candy | Item::Newspaper => Rarity::Common,
+            Item::EnergyDrink => Rarity::Uncommon,
+            Item::FirstAidKit => Rarity::Rare,
+            Item::LuckyCharm => Rarity::Epic,
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Item::EnergyDrink => "Restores 25 energy.",
+            Item::Candy => "A sugary treat. +10 happiness.",
+            Item::FirstAidKit => "Patches you up. Restores 10 energy and 20 happiness.",
+            Item::Newspaper => "Yesterday's news. Not much use.",
+            Item::LuckyCharm => "Fills your energy and +50 happiness.",
+        }
+    }
+
+    /// What the item would sell for, about half the shop price.
+    pub fn resale_value(self) -> u64 {
+        match self {
+            Item::EnergyDrink => 20,
+            Item::Candy => 2,
+            Item::FirstAidKit => 60,
+            Item::Newspaper => 0,
+            Item::LuckyCharm => 300,
+        }
+    }
+
+    /// Applies the item's effect to the player.
+    pub fn apply(self, stats: &mut PlayerStats) {
+        match self {
+            Item::EnergyDrink => stats.restore_energy(25),
+            Item::Candy => stats.happiness += 10,
+            Item::FirstAidKit => {
+                stats.restore_energy(10);
+                stats.happiness += 20;
+            }
+            Item::Newspaper => {}
+            Item::LuckyCharm => {
+                stats.restore_energy(stats.max_energy);
+                stats.happiness += 50;
+            }
+        }
+    }
+}
+
+/// How many of a stack to use or sell in one go.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Quantity {
+    Count(u32),
+    /// The whole stack, however big it is.
+    All,
+}
+
+impl Default for Quantity {
+    fn default() -> Self {
+        Quantity::Count(1)
+    }
+}
+
+impl Quantity {
+    /// `all`, or a count of at least one.
+    pub fn parse(text: &str) -> Option<Quantity> {
+        if text.eq_ignore_ascii_case("all") {
+            return Some(Quantity::All);
+        }
+        match text.parse() {
+            Ok(n @ 1..) => Some(Quantity::Count(n)),
+            _ => None,
+        }
+    }
+
+    /// How many this takes from a stack of `available`: never more than
+    /// there are.
+    pub fn of(self, available: u32) -> u32 {
+        match self {
+            Quantity::Count(n) => n.min(available),
+            Quantity::All => available,
+        }
+    }
+
+    /// One more (`up`) or one fewer, for the Items page's + and - keys. It
+    /// stays between one and the whole stack of `available`.
+    pub fn step(self, up: bool, available: u32) -> Quantity {
+        let n = self.of(available);
+        let n = if up {
+            n.saturating_add(1)
+        } else {
+            n.saturating_sub(1)
+        };
+        Quantity::Count(n.clamp(1, available.max(1)))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InventoryError {
+    NoSuchItem,
+    /// Nobody pays anything for this.
+    Worthless(Item),
+}
+
+impl fmt::Display for InventoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InventoryError::NoSuchItem => write!(f, "No such item"),
+            InventoryError::Worthless(item) => write!(f, "Nobody will buy {}", item.name()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemStack {
+    pub item: Item,
+    pub quantity: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Inventory {
+    pub items: Vec<ItemStack>,
+    /// Keep the items rarest first, then by name. Off lists them in the
+    /// order they were picked up.
+    #[serde(default)]
+    pub sort_by_rarity: bool,
+}
+
+impl Default for Inventory {
+    /// A new character starts with a couple of energy drinks.
+    fn default() -> Self {
+        Self {
+            items: vec![ItemStack {
+                item: Item::EnergyDrink,
+                quantity: 2,
+            }],
+            sort_by_rarity: false,
+        }
+    }
+}
+
+impl Inventory {
+    pub fn add(&mut self, item: Item, quantity: u32) {
+        match self.items.iter_mut().find(|stack| stack.item == item) {
+            Some(stack) => stack.quantity += quantity,
+            None => self.items.push(ItemStack { item, quantity }),
+        }
+        if self.sort_by_rarity {
+            self.sort();
+        }
+    }
+
+    /// Switches rarity order on or off. Turning it off leaves the items
+    /// where they are; new ones go on the end again.
+    pub fn toggle_sort(&mut self) {
+        self.sort_by_rarity = !self.sort_by_rarity;
+        if self.sort_by_rarity {
+            self.sort();
+        }
+    }
+
+    fn sort(&mut self) {
+        self.items.sort_by(|a, b| {
+            b.item
+                .rarity()
+                .cmp(&a.item.rarity())
+                .then_with(|| a.item.name().cmp(b.item.name()))
+        });
+    }
+
+    /// Takes `quantity` off the stack at `index` and applies each one to
+    /// `stats`. Returns the item and how many were used.
+    pub fn use_items(
+        &mut self,
+        index: usize,
+        quantity: Quantity,
+        stats: &mut PlayerStats,
+    ) -> Option<(Item, u32)> {
+        let (item, count) = self.take(index, quantity)?;
+        for _ in 0..count {
+            item.apply(stats);
+        }
+        Some((item, count))
+    }
+
+    /// Sells `quantity` from the stack at `index` at its resale value.
+    /// Returns the item, how many went and what they paid. Everything is
+    /// checked before anything changes, so a refused sale leaves the stack
+    /// and the money as they were.
+    pub fn sell_items(
+        &mut self,
+        index: usize,
+        quantity: Quantity,
+        stats: &mut PlayerStats,
+    ) -> Result<(Item, u32, u64), InventoryError> {
+        let stack = self.items.get(index).ok_or(InventoryError::NoSuchItem)?;
+        if stack.item.resale_value() == 0 {
+            return Err(InventoryError::Worthless(stack.item));
+        }
+        let (item, count) = self
+            .take(index, quantity)
+            .ok_or(InventoryError::NoSuchItem)?;
+        let paid = item.resale_value().saturating_mul(u64::from(count));
+        stats.money = stats.money.saturating_add(paid);
+        Ok((item, count, paid))
+    }
+
+    /// Throws away one item from the stack at `index`.
+    pub fn discard_item(&mut self, index: usize) -> Option<Item> {
+        self.take(index, Quantity::Count(1)).map(|(item, _)| item)
+    }
+
+    /// Takes `quantity` off the stack at `index`, dropping the stack once
+    /// it's empty. Returns the item and how many were taken.
+    fn take(&mut self, index: usize, quantity: Quantity) -> Option<(Item, u32)> {
+        let stack = self.items.get_mut(index)?;
+        let count = quantity.of(stack.quantity);
+        stack.quantity -= count;
+        let item = stack.item;
+        self.items.retain(|stack| stack.quantity > 0);
+        Some((item, count))
+    }
+}
+
+/// Right box of the Items page: the highlighted item, or the use/sell
+/// prompt once Enter has been pressed on it, with `quantity` as how many
+/// of the stack it's for.
+pub fn details_text(
+    inventory: &Inventory,
+    selected: usize,
+    prompt: bool,
+    quantity: Quantity,
+) -> String {
+    let Some(stack) = inventory.items.get(selected) else {
+        return "You have no items yet.".to_string();
+    };
+    if prompt {
+        let count = quantity.of(stack.quantity);
+        format!(
+            "{} (x{})\n\nQuantity: {} of {}  (+/-, a: all)\nSells for {}\n\n\
+             u: use   s: sell   d: discard one   c: cancel",
+            stack.item.name(),
+            stack.quantity,
+            count,
+            stack.quantity,
+            format_money(stack.item.resale_value().saturating_mul(u64::from(count)))
+        )
+    } else {
+        format!(
+            "{}\n{}\n{}\n\nPress Enter to use, sell or discard.",
+            stack.item.name(),
+            stack.item.rarity().name(),
+            stack.item.description()
+        )
+    }
+}
@@ -0,0 +1,66 @@
+use std::time::{Duration, Instant};
+
+use rand::Rng;
+
+use crate::deadline;
+use crate::state::GameState;
+
+/// Chance that an escape attempt succeeds.
+pub const ESCAPE_CHANCE: f64 = 0.25;
+/// Added to the sentence when an escape attempt fails.
+pub const ESCAPE_PENALTY: Duration = Duration::from_secs(30);
+
+pub fn is_jailed(state: &GameState, now: Instant) -> bool {
+    deadline::remaining(state.jail_until, now).is_some()
+}
+
+/// Frees the player once their sentence is over. Returns true on release so
+/// the caller knows something changed.
+pub fn release_if_served(state: &mut GameState, now: Instant) -> bool {
+    match state.jail_until {
+        Some(until) if until <= now => {
+            state.jail_until = None;
+            state.log_news("Released from jail after serving the full sentence.");
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Rolls an escape attempt. Success frees the player immediately; failure
+/// extends the sentence by `ESCAPE_PENALTY`. Returns `None` if not jailed.
+pub fn attempt_escape(state: &mut GameState, now: Instant, rng: &mut impl Rng) -> Option<bool> {
+    let until = state.jail_until.filter(|&until| until > now)?;
+
+    if rng.random_bool(ESCAPE_CHANCE) {
+        state.jail_until = None;
+        state.log_news("Escaped from jail!");
+        Some(true)
+    } else {
+        state.jail_until = Some(until + ESCAPE_PENALTY);
+        state.jail_term_secs += ESCAPE_PENALTY.as_secs();
+        Some(false)
+    }
+}
+
+/// Seconds served and the full sentence, while jailed.
+pub fn sentence_progress(state: &GameState, now: Instant) -> Option<(u64, u64)> {
+    let left = deadline::remaining(state.jail_until, now)?;
+    let total = state.jail_term_secs;
+    Some((total.saturating_sub(left.as_secs()), total))
+}
+
+pub fn status_text(state: &GameState, now: Instant) -> String {
+    match deadline::remaining(state.jail_until, now) {
+        Some(left) => format!("Time remaining: {}", deadline::format_remaining(left)),
+        None => "You are not in jail.".to_string(),
+    }
+}
+
+pub fn escape_text() -> String {
+    format!(
+        "Press e to attempt an escape ({:.0}% chance).\nA failed attempt adds {} to your sentence.",
+        ESCAPE_CHANCE * 100.0,
+        deadline::format_remaining(ESCAPE_PENALTY)
+    )
+}
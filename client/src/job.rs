@@ -0,0 +1,219 @@
+use std::fmt;
+use std::time::Duration;
+
+use crate::money::format_money;
+use crate::state::GameState;
+
+/// Real time between salary payouts.
+pub const PAY_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Extra work that can be done on the job for a one-off bonus.
+pub struct JobTask {
+    pub name: &'static str,
+    pub energy_cost: u32,
+    pub bonus: u64,
+}
+
+pub struct Job {
+    pub title: &'static str,
+    pub min_level: u32,
+    pub salary_per_cycle: u64,
+    pub tasks: &'static [JobTask],
+}
+
+/// Every job, cheapest first. The first one is what new players start with.
+pub const JOBS: [Job; 4] = [
+    Job {
+        title: "Dishwasher",
+        min_level: 1,
+        salary_per_cycle: 20,
+        tasks: &[
+            JobTask {
+                name: "Scrub the pots",
+                energy_cost: 5,
+                bonus: 15,
+            },
+            JobTask {
+                name: "Cover a double shift",
+                energy_cost: 15,
+                bonus: 50,
+            },
+        ],
+    },
+    Job {
+        title: "Cashier",
+        min_level: 2,
+        salary_per_cycle: 40,
+        tasks: &[
+            JobTask {
+                name: "Restock the shelves",
+                energy_cost: 5,
+                bonus: 25,
+            },
+            JobTask {
+                name: "Count the till",
+                energy_cost: 10,
+                bonus: 60,
+            },
+        ],
+    },
+    Job {
+        title: "Mechanic",
+        min_level: 4,
+        salary_per_cycle: 90,
+        tasks: &[
+            JobTask {
+                name: "Change the oil",
+                energy_cost: 10,
+                bonus: 80,
+            },
+            JobTask {
+                name: "Rebuild an engine",
+                energy_cost: 25,
+                bonus: 250,
+            },
+        ],
+    },
+    Job {
+        title: "Manager",
+        min_level: 6,
+        salary_per_cycle: 180,
+        tasks: &[
+            JobTask {
+                name: "Run the morning meeting",
+                energy_cost: 10,
+                bonus: 150,
+            },
+            JobTask {
+                name: "Close a big contract",
+                energy_cost: 30,
+                bonus: 600,
+            },
+        ],
+    },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobError {
+    UnknownJob,
+    LevelTooLow { required: u32 },
+    AlreadyEmployed,
+    NoSuchTask,
+    NotEnoughEnergy,
+}
+
+impl fmt::Display for JobError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JobError::UnknownJob => write!(f, "No such job"),
+            JobError::LevelTooLow { required } => write!(f, "Requires level {}", required),
+            JobError::AlreadyEmployed => write!(f, "You already work there"),
+            JobError::NoSuchTask => write!(f, "No such task"),
+            JobError::NotEnoughEnergy => write!(f, "Not enough energy"),
+        }
+    }
+}
+
+/// The player's current job. Falls back to the starting job if a save names
+/// one that no longer exists.
+pub fn current(state: &GameState) -> &'static Job {
+    JOBS.get(state.job).unwrap_or(&JOBS[0])
+}
+
+/// Case-insensitive lookup by title, returning the job's index in `JOBS`.
+pub fn find(title: &str) -> Option<usize> {
+    JOBS.iter()
+        .position(|job| job.title.eq_ignore_ascii_case(title))
+}
+
+/// Switches to the job at `index` if the player is qualified.
+pub fn apply(state: &mut GameState, index: usize) -> Result<&'static Job, JobError> {
+    let job = JOBS.get(index).ok_or(JobError::UnknownJob)?;
+    if state.job == index {
+        return Err(JobError::AlreadyEmployed);
+    }
+    if state.player.level < job.min_level {
+        return Err(JobError::LevelTooLow {
+            required: job.min_level,
+        });
+    }
+
+    state.job = index;
+    state.job_task_selected = 0;
+    state.log_news(format!("Hired as a {}.", job.title));
+    Ok(job)
+}
+
+/// Spends the task's energy and pays its bonus.
+pub fn complete_task(state: &mut GameState, index: usize) -> Result<u64, JobError> {
+    let task = current(state)
+        .tasks
+        .get(index)
+        .ok_or(JobError::NoSuchTask)?;
+    if !state.player.spend_energy(task.energy_cost) {
+        return Err(JobError::NotEnoughEnergy);
+    }
+    state.player.money += task.bonus;
+    Ok(task.bonus)
+}
+
+/// Pays `cycles` salaries at once; the scheduler fires one per
+/// `PAY_INTERVAL`.
+pub fn pay_salary(state: &mut GameState, cycles: u32) {
+    let job = current(state);
+    let pay = job.salary_per_cycle * u64::from(cycles);
+    state.player.money += pay;
+    state.log_news(format!(
+        "Paid {} salary as a {}.",
+        format_money(pay),
+        job.title
+    ));
+}
+
+pub fn job_summary_text(state: &GameState) -> String {
+    let job = current(state);
+    let mut lines = vec![
+        format!("Title: {}", job.title),
+        format!(
+            "Salary: {} every {}s",
+            format_money(job.salary_per_cycle),
+            PAY_INTERVAL.as_secs()
+        ),
+        String::new(),
+        "Openings (apply <title>):".to_string(),
+    ];
+    lines.extend(JOBS.iter().map(|other| {
+        format!(
+            "  {:<12} {:<7} level {}",
+            other.title,
+            format_money(other.salary_per_cycle),
+            other.min_level
+        )
+    }));
+    lines.join("\n")
+}
+
+pub fn task_list_text(state: &GameState) -> String {
+    let mut lines: Vec<String> = current(state)
+        .tasks
+        .iter()
+        .enumerate()
+        .map(|(i, task)| {
+            let marker = if i == state.job_task_selected {
+                ">"
+            } else {
+                " "
+            };
+            format!(
+                "{} {:<24} +{:<7} {}E",
+                marker,
+                task.name,
+                format_money(task.bonus),
+                task.energy_cost
+            )
+        })
+        .collect();
+    lines.push(String::new());
+    lines.push("Press w to work on the selected task.".to_string());
+    lines.join("\n")
+}
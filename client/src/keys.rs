@@ -0,0 +1,380 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crossterm::event::KeyCode;
+
+/// Where character keys go: the Input box, or menu and page shortcuts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Mode {
+    #[default]
+    Navigate,
+    Input,
+    /// Like `Navigate`, but the arrow keys move through the open page's list
+    /// instead of the menu.
+    List,
+}
+
+impl Mode {
+    pub fn toggled(self) -> Mode {
+        match self {
+            Mode::Navigate => Mode::Input,
+            Mode::Input | Mode::List => Mode::Navigate,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Mode::Navigate => "NAVIGATE",
+            Mode::Input => "INPUT",
+            Mode::List => "LIST",
+        }
+    }
+}
+
+/// What a key press asks for, once the focus has been taken into account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyAction {
+    /// Add a character to the Input box at the cursor.
+    Type(char),
+    Backspace,
+    /// Remove the character under the cursor.
+    Delete,
+    CursorLeft,
+    CursorRight,
+    CursorHome,
+    CursorEnd,
+    /// Run the typed command.
+    Submit,
+    /// Tab-complete the typed command.
+    Complete,
+    HistoryPrev,
+    HistoryNext,
+    Focus(Mode),
+    Help,
+    /// Open the menu filter.
+    Filter,
+    Save,
+    Quit,
+    /// Scroll the page content a screenful up (`true`) or down.
+    Scroll(bool),
+    /// Move the page's list selection by this many rows.
+    ListMove(isize),
+    /// Move the page's list selection a screenful up (`true`) or down.
+    ListPage(bool),
+    NextPage,
+    PrevPage,
+    FirstPage,
+    LastPage,
+    /// A digit of a quick jump to a page number.
+    Digit(usize),
+    /// Go back to the page visited before this one.
+    Back,
+    /// Undo a `Back`.
+    Forward,
+    /// Anything else is a shortcut for whichever page is open.
+    Page(KeyCode),
+}
+
+/// An action whose keys can be changed in `keys.toml`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Binding {
+    MoveUp,
+    MoveDown,
+    Quit,
+    Save,
+    Help,
+    FocusInput,
+}
+
+impl Binding {
+    pub const ALL: [Binding; 6] = [
+        Binding::MoveUp,
+        Binding::MoveDown,
+        Binding::Quit,
+        Binding::Save,
+        Binding::Help,
+        Binding::FocusInput,
+    ];
+
+    /// The action's name in `keys.toml`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Binding::MoveUp => "move_up",
+            Binding::MoveDown => "move_down",
+            Binding::Quit => "quit",
+            Binding::Save => "save",
+            Binding::Help => "help",
+            Binding::FocusInput => "focus_input",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Binding> {
+        Binding::ALL
+            .into_iter()
+            .find(|binding| binding.name() == name)
+    }
+
+    fn defaults(self) -> Vec<KeyCode> {
+        match self {
+            Binding::MoveUp => vec![KeyCode::Char('k'), KeyCode::Up],
+            Binding::MoveDown => vec![KeyCode::Char('j'), KeyCode::Down],
+            Binding::Quit => vec![KeyCode::Esc],
+            Binding::Save => vec![KeyCode::F(5)],
+            Binding::Help => vec![KeyCode::Char('?'), KeyCode::F(1)],
+            Binding::FocusInput => vec![KeyCode::Char(':'), KeyCode::Char('i')],
+        }
+    }
+
+    fn action(self) -> KeyAction {
+        match self {
+            Binding::MoveUp => KeyAction::PrevPage,
+            Binding::MoveDown => KeyAction::NextPage,
+            Binding::Quit => KeyAction::Quit,
+            Binding::Save => KeyAction::Save,
+            Binding::Help => KeyAction::Help,
+            Binding::FocusInput => KeyAction::Focus(Mode::Input),
+        }
+    }
+}
+
+/// Keys with a fixed meaning that can't be bound to anything else.
+fn is_reserved(key: KeyCode) -> bool {
+    matches!(
+        key,
+        KeyCode::Tab
+            | KeyCode::Enter
+            | KeyCode::Backspace
+            | KeyCode::Left
+            | KeyCode::Right
+            | KeyCode::PageUp
+            | KeyCode::PageDown
+            | KeyCode::Char('0'..='9' | '/' | 'g' | 'G' | '[' | ']' | 'p' | 'u')
+    )
+}
+
+/// Which keys trigger each `Binding`. Every action always has at least one
+/// key, and no key triggers two actions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Keymap {
+    keys: HashMap<Binding, Vec<KeyCode>>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self {
+            keys: Binding::ALL
+                .into_iter()
+                .map(|binding| (binding, binding.defaults()))
+                .collect(),
+        }
+    }
+}
+
+impl Keymap {
+    /// The action `key` is bound to, if any.
+    pub fn binding(&self, key: KeyCode) -> Option<Binding> {
+        Binding::ALL
+            .into_iter()
+            .find(|binding| self.keys[binding].contains(&key))
+    }
+
+    /// Reads the keymap at `path`. A missing file means the defaults; a bad
+    /// line or a clash leaves the defaults for the actions involved and adds
+    /// a warning to the list returned alongside.
+    pub fn load(path: &Path) -> (Keymap, Vec<String>) {
+        match fs::read_to_string(path) {
+            Ok(text) => Keymap::parse(&text),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => (Keymap::default(), Vec::new()),
+            Err(err) => (
+                Keymap::default(),
+                vec![format!("Couldn't read {}: {}", path.display(), err)],
+            ),
+        }
+    }
+
+    /// Parses the `action = "key"` or `action = ["key", "key"]` lines of a
+    /// keymap file. That flat corner of TOML is all the file needs, so it's
+    /// read here rather than pulling in a TOML parser.
+    pub fn parse(text: &str) -> (Keymap, Vec<String>) {
+        let mut keymap = Keymap::default();
+        let mut warnings = Vec::new();
+        let mut from_file = Vec::new();
+
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let warn = |message: String| format!("keys.toml line {}: {}", number + 1, message);
+            let Some((name, value)) = line.split_once('=') else {
+                warnings.push(warn("expected action = \"key\"".to_string()));
+                continue;
+            };
+            let name = name.trim();
+            let Some(binding) = Binding::parse(name) else {
+                warnings.push(warn(format!("unknown action '{}'", name)));
+                continue;
+            };
+            match parse_keys(value.trim()) {
+                Ok(keys) => {
+                    keymap.keys.insert(binding, keys);
+                    from_file.push(binding);
+                }
+                Err(message) => warnings.push(warn(message)),
+            }
+        }
+
+        // A key claimed by two actions sends whichever came from the file
+        // back to its defaults, which can free or cause other clashes, so
+        // repeat until nothing changes.
+        while let Some(binding) = from_file
+            .iter()
+            .copied()
+            .find(|&binding| keymap.clashes(binding))
+        {
+            warnings.push(format!(
+                "keys.toml: {} shares a key with another action; using the default",
+                binding.name()
+            ));
+            keymap.keys.insert(binding, binding.defaults());
+            from_file.retain(|&b| b != binding);
+        }
+
+        (keymap, warnings)
+    }
+
+    fn clashes(&self, binding: Binding) -> bool {
+        self.keys[&binding].iter().any(|key| {
+            Binding::ALL
+                .into_iter()
+                .any(|other| other != binding && self.keys[&other].contains(key))
+        })
+    }
+}
+
+/// `"k"` or `["k", "up"]`.
+fn parse_keys(value: &str) -> Result<Vec<KeyCode>, String> {
+    let list = value
+        .strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'));
+    let items: Vec<&str> = match list {
+        Some(list) => list
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .collect(),
+        None => vec![value],
+    };
+    if items.is_empty() {
+        return Err("no keys given".to_string());
+    }
+    items
+        .into_iter()
+        .map(|item| {
+            let name = item
+                .strip_prefix('"')
+                .and_then(|rest| rest.strip_suffix('"'))
+                .ok_or_else(|| format!("expected a quoted key name, got {}", item))?;
+            let key = parse_key(name).ok_or_else(|| format!("unknown key '{}'", name))?;
+            if is_reserved(key) {
+                return Err(format!("'{}' is reserved", name));
+            }
+            Ok(key)
+        })
+        .collect()
+}
+
+/// A key by name: a single character, `f1`-`f12`, or one of the named keys.
+fn parse_key(name: &str) -> Option<KeyCode> {
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(KeyCode::Char(c));
+    }
+    let key = match name.to_lowercase().as_str() {
+        "esc" | "escape" => KeyCode::Esc,
+        "enter" => KeyCode::Enter,
+        "tab" => KeyCode::Tab,
+        "backspace" => KeyCode::Backspace,
+        "space" => KeyCode::Char(' '),
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        "insert" => KeyCode::Insert,
+        "delete" => KeyCode::Delete,
+        other => {
+            let n: u8 = other.strip_prefix('f')?.parse().ok()?;
+            if !(1..=12).contains(&n) {
+                return None;
+            }
+            KeyCode::F(n)
+        }
+    };
+    Some(key)
+}
+
+/// The one place keys are routed by focus. While the Input box has focus
+/// every character is text; otherwise characters are menu and page
+/// shortcuts, so letters like `j` and `k` never end up in the buffer by
+/// accident. A focused list takes the movement keys for itself, and Esc
+/// hands focus back to the menu. `input` is what's typed so far, which
+/// decides whether Tab completes or hands focus back.
+pub fn handle_key(key: KeyCode, mode: Mode, input: &str, keymap: &Keymap) -> Option<KeyAction> {
+    if let Some(binding) = keymap.binding(key) {
+        // Help and Save on keys that can't be typed work while typing too.
+        let global =
+            matches!(binding, Binding::Help | Binding::Save) && !matches!(key, KeyCode::Char(_));
+        match (mode, binding) {
+            (Mode::List, Binding::MoveUp) => return Some(KeyAction::ListMove(-1)),
+            (Mode::List, Binding::MoveDown) => return Some(KeyAction::ListMove(1)),
+            (Mode::List, Binding::Quit) => return Some(KeyAction::Focus(Mode::Navigate)),
+            (Mode::Navigate | Mode::List, _) => return Some(binding.action()),
+            _ if global => return Some(binding.action()),
+            _ => {}
+        }
+    }
+    let action = match (mode, key) {
+        (Mode::List, KeyCode::Up) => KeyAction::ListMove(-1),
+        (Mode::List, KeyCode::Down) => KeyAction::ListMove(1),
+        (Mode::List, KeyCode::PageUp) => KeyAction::ListPage(true),
+        (Mode::List, KeyCode::PageDown) => KeyAction::ListPage(false),
+        (Mode::List, KeyCode::Esc) => KeyAction::Focus(Mode::Navigate),
+        (_, KeyCode::PageUp) => KeyAction::Scroll(true),
+        (_, KeyCode::PageDown) => KeyAction::Scroll(false),
+
+        (Mode::Input, KeyCode::Char(c)) => KeyAction::Type(c),
+        (Mode::Input, KeyCode::Backspace) => KeyAction::Backspace,
+        (Mode::Input, KeyCode::Delete) => KeyAction::Delete,
+        (Mode::Input, KeyCode::Left) => KeyAction::CursorLeft,
+        (Mode::Input, KeyCode::Right) => KeyAction::CursorRight,
+        (Mode::Input, KeyCode::Home) => KeyAction::CursorHome,
+        (Mode::Input, KeyCode::End) => KeyAction::CursorEnd,
+        (Mode::Input, KeyCode::Enter) => KeyAction::Submit,
+        (Mode::Input, KeyCode::Tab) if !input.trim().is_empty() => KeyAction::Complete,
+        (Mode::Input, KeyCode::Up) => KeyAction::HistoryPrev,
+        (Mode::Input, KeyCode::Down) => KeyAction::HistoryNext,
+        (Mode::Input, KeyCode::Esc) => KeyAction::Focus(Mode::Navigate),
+
+        (_, KeyCode::Tab) => KeyAction::Focus(mode.toggled()),
+        (Mode::Navigate, KeyCode::Char('/')) => KeyAction::Filter,
+        (Mode::Navigate, KeyCode::Char(c @ '0'..='9')) => {
+            KeyAction::Digit(c as usize - '0' as usize)
+        }
+        (Mode::Navigate, KeyCode::Char('g')) => KeyAction::FirstPage,
+        (Mode::Navigate, KeyCode::Char('G')) => KeyAction::LastPage,
+        (Mode::Navigate | Mode::List, KeyCode::Char('[')) => KeyAction::Back,
+        (Mode::Navigate | Mode::List, KeyCode::Char(']')) => KeyAction::Forward,
+        (
+            Mode::Navigate | Mode::List,
+            KeyCode::Char(_) | KeyCode::Enter | KeyCode::Left | KeyCode::Right,
+        ) => KeyAction::Page(key),
+        _ => return None,
+    };
+    Some(action)
+}
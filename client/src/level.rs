@@ -0,0 +1,32 @@
+use crate::player::PlayerStats;
+use crate::state::GameState;
+
+/// Total experience needed to reach `level`. Level 1 is where everyone
+/// starts; after that each level costs 100 more than the one before, so
+/// level 2 is at 100, level 3 at 300 and level 4 at 600.
+pub fn xp_for_level(level: u32) -> u64 {
+    let steps = u64::from(level.saturating_sub(1));
+    50 * steps * (steps + 1)
+}
+
+/// Adds `amount` experience, levelling up as many times as it pays for.
+/// Each new level refills energy and makes the news. Returns true if the
+/// player levelled up.
+pub fn award_xp(state: &mut GameState, amount: u64) -> bool {
+    state.player.experience += amount;
+    let mut levelled = false;
+    while state.player.experience >= xp_for_level(state.player.level + 1) {
+        state.player.level += 1;
+        state.player.energy = state.player.max_energy;
+        state.log_news(format!("Reached level {}!", state.player.level));
+        levelled = true;
+    }
+    levelled
+}
+
+/// How far the player is through the current level, as (earned, needed).
+pub fn progress(player: &PlayerStats) -> (u64, u64) {
+    let start = xp_for_level(player.level);
+    let next = xp_for_level(player.level + 1);
+    (player.experience.saturating_sub(start), next - start)
+}
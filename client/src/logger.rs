@@ -0,0 +1,97 @@
+//! Debug log written to `~/.rusty/debug.log`, off unless `RUSTY_LOG` is set.
+//! It only ever touches its file, never the terminal the TUI is drawn on,
+//! and a log that can't be opened or written just goes quiet.
+
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::sync::{Mutex, OnceLock};
+
+use crate::news;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl Level {
+    fn name(self) -> &'static str {
+        match self {
+            Level::Debug => "DEBUG",
+            Level::Info => "INFO",
+            Level::Warn => "WARN",
+            Level::Error => "ERROR",
+        }
+    }
+
+    /// `RUSTY_LOG`'s value: the quietest level to record.
+    fn parse(value: &str) -> Option<Level> {
+        match value.to_lowercase().as_str() {
+            "debug" => Some(Level::Debug),
+            "info" => Some(Level::Info),
+            "warn" => Some(Level::Warn),
+            "error" => Some(Level::Error),
+            _ => None,
+        }
+    }
+}
+
+struct Logger {
+    min_level: Level,
+    file: Mutex<File>,
+}
+
+static LOGGER: OnceLock<Option<Logger>> = OnceLock::new();
+
+/// Opens the log at `path` for appending if `RUSTY_LOG` names a level.
+/// Called once at startup; later calls change nothing.
+pub fn init(path: &Path) {
+    LOGGER.get_or_init(|| {
+        let min_level = Level::parse(&std::env::var("RUSTY_LOG").ok()?)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).ok()?;
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .ok()?;
+        Some(Logger {
+            min_level,
+            file: Mutex::new(file),
+        })
+    });
+}
+
+/// Appends `timestamp LEVEL message` if logging is on at `level`.
+pub fn log(level: Level, message: impl fmt::Display) {
+    let Some(Some(logger)) = LOGGER.get() else {
+        return;
+    };
+    if level < logger.min_level {
+        return;
+    }
+    if let Ok(mut file) = logger.file.lock() {
+        let _ = writeln!(file, "{} {:<5} {}", news::unix_now(), level.name(), message);
+    }
+}
+
+pub fn debug(message: impl fmt::Display) {
+    log(Level::Debug, message);
+}
+
+pub fn info(message: impl fmt::Display) {
+    log(Level::Info, message);
+}
+
+pub fn warn(message: impl fmt::Display) {
+    log(Level::Warn, message);
+}
+
+pub fn error(message: impl fmt::Display) {
+    log(Level::Error, message);
+}
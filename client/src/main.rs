@@ -1,276 +1,1512 @@
+mod achievement;
+mod action;
+mod app;
+mod args;
+mod bank;
+mod bell;
+mod blackjack;
+mod calendar;
+mod casino;
+mod clock;
+mod color;
+mod command;
+mod completion;
+mod cooldown;
+mod crime;
+mod daily;
+mod deadline;
+mod decay;
+mod demo;
+mod difficulty;
+mod drops;
+mod education;
+mod error;
+mod faction;
+mod fight;
+mod flash;
+mod forums;
+mod gym;
+mod hall_of_fame;
+mod help;
+mod history;
+mod hospital;
+mod inbox;
+mod inventory;
+mod jail;
+mod job;
+mod keys;
+mod level;
+mod logger;
+mod market;
+mod mission;
+mod money;
+mod net_worth;
+mod news;
+mod notifications;
+mod pages;
+mod palette;
+mod player;
+mod property;
+mod referral;
+mod repeat;
+mod roulette;
+mod rules;
+mod save;
+mod scheduler;
+mod settings;
+mod shop;
+mod slots;
+mod state;
+mod theme;
+mod toast;
+mod transition;
+mod tutorial;
+mod ui;
+mod undo;
+mod visits;
+
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
+    cursor,
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, KeyModifiers,
+        MouseButton, MouseEventKind,
+    },
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
+use rand::Rng;
 use ratatui::{
-    Terminal,
-    backend::CrosstermBackend,
-    layout::{Constraint, Direction, Layout},
-    style::{Color, Modifier, Style},
-    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    Terminal, backend::CrosstermBackend, layout::Position, style::Style, widgets::ListState,
+};
+use std::{
+    cell::Cell,
+    io::{self, IsTerminal},
+    mem, panic,
+    path::{Path, PathBuf},
+    process,
+    time::{Duration, Instant},
 };
-use std::collections::HashSet;
-use std::{io, time::Duration};
 
-fn get_page_info(page: &str) -> (&'static str, &'static str, &'static str) {
-    match page {
-        "Home" => (
-            "Welcome to your home screen. Here you'll find your basic stats and property info.",
-            "Stats overview",
-            "Current property info",
-        ),
-        "Items" => (
-            "This is your inventory. All your collected items will be listed here.",
-            "You have no items yet.",
-            "Use or discard items here.",
-        ),
-        "City" => (
-            "Visit shops, explore zones, and interact with the city here.",
-            "City zones overview",
-            "Shops and NPCs",
-        ),
-        "Job" => (
-            "Check your current job, salary, and available tasks.",
-            "Job title and salary",
-            "Current tasks",
-        ),
-        "Gym" => (
-            "Train your stats here. Strength, speed, defense—you name it.",
-            "Stat training panel",
-            "Recent training log",
-        ),
-        "Properties" => (
-            "Buy, sell, or upgrade your properties.",
-            "Owned properties",
-            "Market listings",
-        ),
-        "Education" => (
-            "Enroll in courses to gain skills that unlock new opportunities.",
-            "Current courses",
-            "Completed courses",
-        ),
-        "Crimes" => (
-            "Perform crimes to gain money and experience. Risk vs reward!",
-            "Available crimes",
-            "Crime success history",
-        ),
-        "Missions" => (
-            "Complete missions for rewards and progression.",
-            "Current missions",
-            "Completed missions",
-        ),
-        "Newspaper" => (
-            "Read updates, events, and changes in the game world.",
-            "Today's headlines",
-            "Archived news",
-        ),
-        "Jail" => (
-            "See your jail status and how to escape or wait it out.",
-            "Time remaining",
-            "Escape options",
-        ),
-        "Hospital" => (
-            "Check your injuries and time to recover.",
-            "Injury status",
-            "Recovery tips",
-        ),
-        "Casino" => (
-            "Try your luck with slots, blackjack, and roulette.",
-            "Available games",
-            "Last win history",
-        ),
-        "Forums" => (
-            "Chat with other players or browse announcements.",
-            "Recent threads",
-            "Your replies",
-        ),
-        "Hall of Fame" => (
-            "View top players ranked by wealth, strength, and more.",
-            "Leaderboard",
-            "Your rank",
-        ),
-        "Faction" => (
-            "Manage or join a faction to collaborate with others.",
-            "Faction info",
-            "Member list",
-        ),
-        "Recruit Citizens" => (
-            "Invite new players and earn rewards.",
-            "Referral link",
-            "Recruit rewards",
-        ),
-        "Calendar" => (
-            "Track daily and weekly events.",
-            "Today's events",
-            "Upcoming events",
-        ),
-        "Rules" => (
-            "Review game rules and avoid punishment.",
-            "Most broken rules",
-            "Reporting system",
-        ),
-        _ => ("This page is under construction.", "Left Box", "Right Box"),
-    }
+use action::{Action, ConfirmDialog};
+use app::{App, Focus};
+use bell::{Bell, Cues};
+use casino::CasinoGame;
+use command::CommandResult;
+use demo::Demo;
+use difficulty::Difficulty;
+use inventory::Quantity;
+use keys::{Binding, KeyAction, Keymap, Mode};
+use money::format_money;
+use pages::PageContext;
+use palette::{Palette, Pick};
+use repeat::Grind;
+use roulette::Bet;
+use save::Autosave;
+use scheduler::{Scheduler, TickEvent};
+use slots::{Choice, SlotPicker};
+use state::GameState;
+use toast::{ToastLevel, Toasts};
+use tutorial::Tutorial;
+
+/// Lines the content moves per mouse wheel notch.
+const WHEEL_SCROLL_LINES: u16 = 3;
+
+/// How long the menu waits for the second digit of a two-digit jump.
+const JUMP_TIMEOUT: Duration = Duration::from_millis(800);
+
+/// Live timers (jail, hospital, energy) repaint at least this often even when
+/// nothing else changed.
+const REDRAW_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Keeps the Items page cursor on a real stack after the inventory shrinks.
+fn clamp_item_selection(game: &mut GameState) {
+    game.item_selected = game
+        .item_selected
+        .min(game.inventory.items.len().saturating_sub(1));
 }
 
-fn main() -> Result<(), io::Error> {
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
+/// Runs `action`, or parks it in `confirm` to wait for a yes if it's
+/// destructive or a big spend.
+fn perform(
+    action: Action,
+    game: &mut GameState,
+    confirm: &mut Option<ConfirmDialog>,
+    now: Instant,
+    rng: &mut impl Rng,
+) -> error::Result<CommandResult> {
+    match ConfirmDialog::for_action(action, game) {
+        Some(dialog) => {
+            *confirm = Some(dialog);
+            Ok(CommandResult::Empty)
+        }
+        None => run_action(action, game, now, rng),
+    }
+}
 
-    let raw_menu_items = vec![
-        "Home",
-        "Items",
-        "City",
-        "Job",
-        "Gym",
-        "Properties",
-        "Education",
-        "Crimes",
-        "Missions",
-        "Newspaper",
-        "Jail",
-        "Hospital",
-        "Casino",
-        "Forums",
-        "Hall of Fame",
-        "Faction",
-        "Recruit Citizens",
-        "Calendar",
-        "Rules",
-    ];
+fn run_action(
+    action: Action,
+    game: &mut GameState,
+    now: Instant,
+    rng: &mut impl Rng,
+) -> error::Result<CommandResult> {
+    let result = command::run_action(game, action, now, rng);
+    clamp_item_selection(game);
+    result
+}
 
-    let unread: HashSet<&str> = ["Newspaper", "Crimes", "Messages"].into_iter().collect();
-    let important: HashSet<&str> = ["Hospital", "Jail", "Crimes"].into_iter().collect();
+/// Runs a Gym or Crimes action for a press of its key. With hold to repeat
+/// on, a held key's repeats run quietly and one toast sums them up once the
+/// key is let go or an action fails.
+fn grind(
+    app: &mut App,
+    grind: Grind,
+    kind: KeyEventKind,
+    now: Instant,
+    act: impl FnOnce(&mut GameState) -> error::Result<CommandResult>,
+) -> error::Result<CommandResult> {
+    if !app.game.settings.hold_to_repeat {
+        return act(&mut app.game);
+    }
+    let repeat = kind == KeyEventKind::Repeat;
+    if !app.repeater.press(grind, repeat, &app.game.player, now) {
+        return Ok(CommandResult::Empty);
+    }
+    let result = act(&mut app.game);
+    if matches!(result, Err(_) | Ok(CommandResult::Setback(_))) {
+        if let Some(summary) = app.repeater.halt(&app.game.player) {
+            app.toasts
+                .push(summary, ToastLevel::Info, app.game.clock.elapsed());
+        }
+        return result;
+    }
+    if app.repeater.is_repeating() {
+        return Ok(CommandResult::Empty);
+    }
+    result
+}
 
-    let menu_items: Vec<(&str, Color)> = raw_menu_items
-        .iter()
-        .map(|label| {
-            let color = if important.contains(label) {
-                Color::Red
-            } else if unread.contains(label) {
-                Color::Green
-            } else {
-                Color::Gray
-            };
-            (*label, color)
-        })
-        .collect();
+/// Saves the game, telling `autosave` so it doesn't write the same state
+/// again.
+fn save_now(game: &GameState, autosave: &mut Autosave, path: &Path) -> error::Result<()> {
+    let result = save::save_game(game, path);
+    match &result {
+        Ok(()) => {
+            autosave.mark_saved(game, Instant::now());
+            logger::info("Saved");
+        }
+        Err(err) => logger::error(format!("Save failed: {}", err)),
+    }
+    result
+}
 
-    let mut selected = 0;
-    let mut state = ListState::default();
-    state.select(Some(selected));
+/// Puts the terminal back the way the shell expects it. Shared by the normal
+/// exit path and the panic hook.
+fn restore_terminal() -> io::Result<()> {
+    disable_raw_mode()?;
+    execute!(
+        io::stdout(),
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        cursor::Show
+    )
+}
 
-    let mut input = String::new();
+/// Restores the terminal before the default hook prints the panic message,
+/// so the message and backtrace land on a usable screen.
+fn install_panic_hook() {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        let _ = restore_terminal();
+        logger::error(format!("Panicked: {}", info));
+        default_hook(info);
+    }));
+}
 
+/// Shows the slot picker until the player opens a save or quits. Returns
+/// the save to play, or `None` to quit.
+fn choose_slot<B: ratatui::backend::Backend>(
+    terminal: &mut Terminal<B>,
+    dir: &Path,
+) -> error::Result<Option<PathBuf>> {
+    let mut picker = SlotPicker::new(dir);
+    let theme = GameState::default().theme.theme();
     loop {
-        terminal.draw(|f| {
-            let area = f.area();
+        terminal.draw(|f| ui::render_slot_picker(f, &picker, &theme))?;
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            return Ok(None);
+        }
+        match picker.handle_key(key.code) {
+            Some(Choice::Open(path)) => return Ok(Some(path)),
+            Some(Choice::Quit) => return Ok(None),
+            None => {}
+        }
+    }
+}
 
-            let chunks = Layout::default()
-                .direction(Direction::Horizontal)
-                .constraints([Constraint::Length(20), Constraint::Min(0)])
-                .split(area);
+fn main() -> error::Result<()> {
+    let args = match args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("rusty: {}\n\n{}", err, args::USAGE);
+            process::exit(2);
+        }
+    };
+    if args.help {
+        println!("{}", args::USAGE);
+        return Ok(());
+    }
+    let save_dir = save::save_dir();
+    let picked = match args::save_path(&args, &save_dir) {
+        Ok(path) => path,
+        Err(err) => {
+            eprintln!("rusty: {}", err);
+            process::exit(1);
+        }
+    };
 
-            // Vertical: Info (5) | Main (flex) | Input (3)
-            let right_chunks = Layout::default()
-                .direction(Direction::Vertical)
-                .constraints([
-                    Constraint::Length(5), // Info box
-                    Constraint::Min(0),    // Content area
-                    Constraint::Length(3), // Input box
-                ])
-                .split(chunks[1]);
+    // Raw mode and the alternate screen only make sense on a terminal; piped
+    // into a file or a CI log they'd just fill it with escape codes.
+    if !io::stdout().is_terminal() {
+        eprintln!(
+            "rusty needs an interactive terminal; stdout isn't one. Run it directly in a terminal."
+        );
+        process::exit(1);
+    }
+    install_panic_hook();
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
 
-            let content_chunks = Layout::default()
-                .direction(Direction::Horizontal)
-                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-                .split(right_chunks[1]);
+    logger::init(&save_dir.join("debug.log"));
+    logger::info("Starting up");
+    logger::info(format!("Terminal colors: {}", color::depth().name()));
+    // The save picked here is the one autosave, F5 and quitting write to.
+    let save_path = match picked {
+        Some(path) => path,
+        None => match choose_slot(&mut terminal, &save_dir)? {
+            Some(path) => path,
+            None => {
+                logger::info("Quitting from the slot picker");
+                restore_terminal()?;
+                return Ok(());
+            }
+        },
+    };
+    let mut warnings = Vec::new();
+    let loaded = match save::load_game(&save_path) {
+        Ok(_) if args.new_game => {
+            logger::info(format!("Starting a new game over {}", save_path.display()));
+            None
+        }
+        Ok(game) => {
+            logger::info(format!("Loaded {}", save_path.display()));
+            Some(game)
+        }
+        Err(err) if err.is_not_found() => {
+            logger::info("No save yet; starting a new game");
+            None
+        }
+        // Starting over would autosave on top of it.
+        Err(err) if err.is_newer_save() && !args.new_game => {
+            let message = format!("Not loading {}: {}", save_path.display(), err);
+            logger::warn(&message);
+            restore_terminal()?;
+            eprintln!(
+                "rusty: {}. It's been left alone; update rusty or pick another slot.",
+                message
+            );
+            return Ok(());
+        }
+        Err(err) => {
+            // Kept out of the way so the new game's autosave can't replace it.
+            let kept = match save::set_aside(&save_path) {
+                Ok(bad) => format!("; it's been kept as {}", bad.display()),
+                Err(_) => String::new(),
+            };
+            warnings.push(format!(
+                "Couldn't load {}, starting a new game: {}{}",
+                save_path.display(),
+                err,
+                kept
+            ));
+            None
+        }
+    };
+    let mut app = App::new(loaded.clone().unwrap_or_default());
+    if loaded.is_none() {
+        app.difficulty_picker = Some(Difficulty::default());
+    }
+    let (keymap, keymap_warnings) = Keymap::load(&save_dir.join("keys.toml"));
+    app.keymap = keymap;
+    warnings.extend(keymap_warnings);
+    for warning in warnings {
+        logger::warn(&warning);
+        app.toasts
+            .push(warning, ToastLevel::Warn, app.game.clock.elapsed());
+    }
+    let mut autosave = Autosave::new(loaded, Instant::now());
+    // Whether the terminal is currently sending mouse events, kept in step
+    // with the mouse capture setting.
+    let mut mouse_captured = false;
+    let mut scheduler = Scheduler::standard(
+        app.game.clock.elapsed(),
+        app.game.difficulty.regen_interval(),
+    );
+    let mut last_tick = Instant::now();
+    let mut rng = rand::rng();
+    let mut bell = Bell::default();
+    let mut cues = Cues::of(&app.game, Instant::now());
 
-            // Render menu
-            let menu: Vec<ListItem> = menu_items
-                .iter()
-                .map(|(label, color)| {
-                    ListItem::new((*label).to_string()).style(Style::default().fg(*color))
-                })
-                .collect();
+    let mut dirty = true;
+    let mut last_draw = Instant::now();
 
-            let list = List::new(menu)
-                .block(Block::default().title("Menu").borders(Borders::ALL))
-                .highlight_style(
-                    Style::default()
-                        .fg(Color::Yellow)
-                        .add_modifier(Modifier::BOLD),
-                )
-                .highlight_symbol("> ");
-            f.render_stateful_widget(list, chunks[0], &mut state);
+    loop {
+        app.sync_menu();
+        money::configure(&app.game.settings);
+        // The demo's page flipping isn't the player's history.
+        if app.demo.is_none() {
+            app.visits.visit(app.current_page());
+        }
+        if app.game.settings.mouse_capture != mouse_captured {
+            mouse_captured = app.game.settings.mouse_capture;
+            if mouse_captured {
+                execute!(terminal.backend_mut(), EnableMouseCapture)?;
+            } else {
+                execute!(terminal.backend_mut(), DisableMouseCapture)?;
+            }
+        }
+        let now = Instant::now();
+        let before = app.game.clock.minutes();
+        // Paused time is simply never added to the clock, and deadlines kept
+        // as real instants are pushed back by it, so nothing is skipped or
+        // caught up on resume.
+        if app.paused {
+            app.game.postpone_deadlines(now.duration_since(last_tick));
+        } else {
+            app.game.clock.advance(now.duration_since(last_tick));
+        }
+        last_tick = now;
+        // Whether anything happened by itself this tick. None of it can be
+        // taken back, so undo mustn't reach past it.
+        let mut happened = calendar::trigger(&mut app.game, before);
+        let mut regen_points = 0;
+        let mut paydays = 0;
+        for event in scheduler.due(app.game.clock.elapsed()) {
+            match event {
+                TickEvent::EnergyRegen => regen_points += 1,
+                TickEvent::Payday => paydays += 1,
+                TickEvent::NewDay => {
+                    // Interest is on the balance the day ended with, so it's
+                    // paid before the new day's income arrives.
+                    bank::pay_interest(&mut app.game);
+                    decay::apply(&mut app.game);
+                    property::collect_daily_income(&mut app.game);
+                    market::fluctuate(&mut app.game, &mut rng);
+                    rules::review(&mut app.game, &mut rng);
+                    happened = true;
+                    if app.game.daily.is_available(app.game.clock.day()) {
+                        app.toasts.push(
+                            "Your daily reward is ready on the Home page",
+                            ToastLevel::Info,
+                            app.game.clock.elapsed(),
+                        );
+                    }
+                }
+            }
+        }
+        if regen_points > 0 {
+            dirty |= app.game.player.regenerate(regen_points);
+        }
+        if paydays > 0 {
+            job::pay_salary(&mut app.game, paydays);
+            hall_of_fame::grow_rivals(&mut app.game.hall_of_fame, paydays, &mut rng);
+            happened = true;
+        }
+        happened |= jail::release_if_served(&mut app.game, now);
+        happened |= hospital::discharge_if_recovered(&mut app.game, now);
+        happened |= education::complete_if_finished(&mut app.game, now);
+        let missions = mission::update(&mut app.game, &mut rng);
+        happened |= missions.changed;
+        for item in missions.found {
+            app.toasts.push(
+                format!("Mission reward: found a {}", item.name()),
+                ToastLevel::Info,
+                app.game.clock.elapsed(),
+            );
+        }
+        for unlocked in achievement::update(&mut app.game) {
+            app.toasts.push(
+                format!("Achievement unlocked: {}", unlocked.name),
+                ToastLevel::Info,
+                app.game.clock.elapsed(),
+            );
+            happened = true;
+        }
+        if happened {
+            app.undo.clear();
+            dirty = true;
+        }
+        // Whatever happened since the last pass, from a key or the clock.
+        // Held keys grind without a beep per action.
+        let latest = Cues::of(&app.game, now);
+        if cues.rings_for(latest) && !app.repeater.is_repeating() && app.demo.is_none() {
+            bell.ring(terminal.backend_mut(), &app.game.settings, now);
+        }
+        cues = latest;
+        match autosave.tick(&app.game, &save_path, now) {
+            Some(Ok(())) => {
+                logger::info("Autosaved");
+                app.toasts
+                    .push("Autosaved", ToastLevel::Info, app.game.clock.elapsed());
+            }
+            Some(Err(err)) => {
+                logger::error(format!("Autosave failed: {}", err));
+                app.toasts.push(
+                    format!("Autosave failed: {}", err),
+                    ToastLevel::Error,
+                    app.game.clock.elapsed(),
+                );
+            }
+            None => {}
+        }
+        dirty |= app.toasts.expire(app.game.clock.elapsed());
+        if let Some(summary) = app.repeater.expire(&app.game.player, now) {
+            app.toasts
+                .push(summary, ToastLevel::Info, app.game.clock.elapsed());
+            dirty = true;
+        }
+        // Keep repainting while the reels animate, a page slides in or the
+        // money flashes.
+        let elapsed = app.game.clock.elapsed();
+        app.money_flash.update(app.game.player.money, elapsed);
+        dirty |= app.money_flash.is_running(elapsed);
+        dirty |= app.game.slots.is_spinning();
+        dirty |= app.transitions.is_running(now);
+        if let Some(won) = app.game.slots.finish_spin(&mut app.game.player, now) {
+            app.toasts.push_result(
+                &command::slots_result(&mut app.game, won),
+                app.game.clock.elapsed(),
+            );
+        }
+        if let Some((first, typed_at)) = app.pending_jump
+            && now.duration_since(typed_at) >= JUMP_TIMEOUT
+        {
+            app.pending_jump = None;
+            app.select(first - 1);
+            dirty = true;
+        }
+        // A page opened some other way (a command, a click) may have no list
+        // to keep focused.
+        if app.mode == Mode::List && !pages::has_list(app.current_page()) {
+            app.mode = Mode::Navigate;
+        }
+        let idle = app.game.settings.demo_idle();
+        if app.demo.is_none()
+            && !app.paused
+            && idle.is_some_and(|idle| now.duration_since(app.last_input) >= idle)
+            && !app.show_help
+            && app.notifications.is_none()
+            && app.difficulty_picker.is_none()
+            && app.palette.is_none()
+            && app.confirm.is_none()
+            && app.filter.is_none()
+            && app.tutorial.is_none()
+        {
+            logger::info("Starting the demo");
+            app.demo = Some(Demo::start(&app.game, app.current_page(), now));
+            dirty = true;
+        }
+        let (current, pages) = (app.selected(), app.menu_items.len());
+        if let Some(demo) = &mut app.demo
+            && let Some(index) = demo.tick(current, pages, now, &mut rng)
+        {
+            app.select(index);
+            dirty = true;
+        }
+        // Pages seen only in the demo don't count as read.
+        let page = if app.demo.is_some() {
+            ""
+        } else {
+            app.current_page()
+        };
+        match page {
+            "Newspaper" => app.game.news.mark_read(),
+            "Messages" => {
+                let index = app.game.message_selected;
+                app.game.inbox.mark_read(index);
+            }
+            "Recruit Citizens" => {
+                referral::ensure_code(&mut app.game, &mut rng);
+            }
+            _ => {}
+        }
 
-            // Dynamic page data
-            let current_page = menu_items[selected].0;
-            let (info_text, left_text, right_text) = get_page_info(current_page);
+        if dirty || now.duration_since(last_draw) >= REDRAW_INTERVAL {
+            dirty = false;
+            last_draw = now;
+            let focus = app.focus();
+            // The demo's copy of the game is drawn in place of the real one.
+            if let Some(demo) = &mut app.demo {
+                mem::swap(&mut app.game, &mut demo.game);
+            }
+            terminal.draw(|f| {
+                if ui::is_too_small(f.area()) {
+                    app.last_layout = None;
+                    ui::render_too_small(f, f.area());
+                    return;
+                }
 
-            // Top Info Box
-            let info_paragraph = Paragraph::new(info_text)
-                .wrap(Wrap { trim: true })
-                .block(Block::default().title("Info").borders(Borders::ALL));
-            f.render_widget(info_paragraph, right_chunks[0]);
+                let layout = ui::layout(f.area());
+                app.last_layout = Some(layout);
+                let theme = app.game.theme.theme();
+                if let Some(query) = &app.filter {
+                    let labels: Vec<(String, Style)> = ui::filter_menu(&app.menu_items, query)
+                        .into_iter()
+                        .map(|i| {
+                            let page = app.menu_items[i];
+                            (
+                                ui::menu_label(page, &app.game),
+                                ui::menu_color_for(page, &app.game, now),
+                            )
+                        })
+                        .collect();
+                    let styled_items: Vec<(&str, Style)> = labels
+                        .iter()
+                        .map(|(label, style)| (label.as_str(), *style))
+                        .collect();
+                    // Enter picks the first match, so that's the one highlighted.
+                    let mut filter_state =
+                        ListState::default().with_selected((!styled_items.is_empty()).then_some(0));
+                    ui::render_menu(
+                        f,
+                        layout.menu,
+                        &format!("Menu /{}", query),
+                        &styled_items,
+                        &mut filter_state,
+                        &theme,
+                        query,
+                    );
+                    if focus == Focus::Menu {
+                        ui::render_focus_ring(f, layout.menu, &theme);
+                    }
+                } else {
+                    let menu_title = match app.pending_jump {
+                        Some((first, _)) => format!("Menu [{}_]", first),
+                        None => "Menu".to_string(),
+                    };
+                    let labels: Vec<String> = app
+                        .menu_items
+                        .iter()
+                        .map(|page| ui::menu_label(page, &app.game))
+                        .collect();
+                    let styled_items: Vec<(&str, Style)> = labels
+                        .iter()
+                        .zip(&app.menu_items)
+                        .map(|(label, page)| {
+                            (label.as_str(), ui::menu_color_for(page, &app.game, now))
+                        })
+                        .collect();
+                    ui::render_menu(
+                        f,
+                        layout.menu,
+                        &menu_title,
+                        &styled_items,
+                        &mut app.menu_state,
+                        &theme,
+                        "",
+                    );
+                    if focus == Focus::Menu {
+                        ui::render_focus_ring(f, layout.menu, &theme);
+                    }
+                    ui::render_trail(f, layout.menu, app.visits.breadcrumb(), &theme);
+                }
 
-            // Two side-by-side boxes
-            let left_box = Paragraph::new(left_text)
-                .block(Block::default().title("Left Box").borders(Borders::ALL));
-            let right_box = Paragraph::new(right_text)
-                .block(Block::default().title("Right Box").borders(Borders::ALL));
-            f.render_widget(left_box, content_chunks[0]);
-            f.render_widget(right_box, content_chunks[1]);
+                let current_page = app.current_page();
+                let scroll = Cell::new(app.scroll_offsets.get(current_page).copied().unwrap_or(0));
+                let list = Cell::new(app.list_states.remove(current_page).unwrap_or_default());
+                let page = pages::build(
+                    current_page,
+                    PageContext {
+                        game: &app.game,
+                        now,
+                        scroll: &scroll,
+                        list: &list,
+                        list_focused: focus == Focus::PageList,
+                    },
+                );
 
-            // Bottom Input Box
-            let input_box = Paragraph::new(input.as_str())
-                .style(
-                    Style::default()
-                        .fg(Color::Yellow)
-                        .add_modifier(Modifier::BOLD),
-                )
-                .block(Block::default().title("Input").borders(Borders::ALL));
-            f.render_widget(input_box, right_chunks[2]);
-        })?;
+                let info_text = pages::info(current_page, &app.game, now);
+                let mode = if app.demo.is_some() {
+                    "DEMO"
+                } else {
+                    app.mode.label()
+                };
+                ui::render_info(
+                    f,
+                    layout.info,
+                    mode,
+                    app.toasts.log().unread(),
+                    info_text,
+                    &app.game.player,
+                    &theme,
+                );
+                let money_flash = app.money_flash.style(app.game.clock.elapsed(), &theme);
+                let next_regen = scheduler
+                    .next(TickEvent::EnergyRegen)
+                    .map(|at| at.saturating_sub(app.game.clock.elapsed()));
+                ui::render_status(
+                    f,
+                    layout.status,
+                    &app.game,
+                    now,
+                    next_regen,
+                    money_flash,
+                    &theme,
+                );
+
+                ui::render_page(page.as_ref(), f, layout.content, &theme);
+                app.transitions.frame(
+                    f.buffer_mut(),
+                    layout.content,
+                    app.selected(),
+                    app.game.settings.page_transitions,
+                    now,
+                );
+                app.scroll_offsets.insert(current_page, scroll.get());
+                app.list_states.insert(current_page, list.take());
+                ui::render_output(f, layout.output, &app.output, &theme);
+                let cursor = (focus == Focus::Input).then(|| app.cursor_column());
+                ui::render_input(f, layout.input, &app.input, cursor, &theme);
+                if app.show_help {
+                    ui::render_help(f, &help::help_text(), &theme);
+                }
+                if let Some(offset) = app.notifications {
+                    let text = notifications::log_text(app.toasts.log());
+                    app.notifications = Some(ui::render_notifications(f, text, offset));
+                }
+                if let Some((choices, current)) = app.completer.choices(&app.input) {
+                    ui::render_completions(f, layout.content, &choices, current, &theme);
+                }
+                ui::render_toasts(
+                    f,
+                    layout.content,
+                    &app.toasts,
+                    app.game.clock.elapsed(),
+                    &theme,
+                );
+                if let Some(tutorial) = &app.tutorial {
+                    ui::render_tutorial(f, &layout, tutorial, &theme);
+                }
+                if let Some(dialog) = &app.confirm {
+                    ui::render_confirm(f, &dialog.prompt, &theme);
+                }
+                if let Some(selected) = app.difficulty_picker {
+                    ui::render_difficulty_picker(f, selected, &theme);
+                }
+                if let Some(palette) = &app.palette {
+                    ui::render_palette(f, palette, &theme);
+                }
+                if app.paused {
+                    ui::render_paused(f, &theme);
+                }
+            })?;
+            if let Some(demo) = &mut app.demo {
+                mem::swap(&mut app.game, &mut demo.game);
+            }
+        }
 
         // Input events
-        if event::poll(Duration::from_millis(100))? {
-            if let Event::Key(key) = event::read()? {
+        let mut timeout = app.game.settings.tick_rate();
+        if app.transitions.is_running(Instant::now()) {
+            timeout = timeout.min(transition::FRAME_INTERVAL);
+        }
+        if event::poll(timeout)? {
+            let event = event::read()?;
+            if matches!(event, Event::Key(_) | Event::Mouse(_)) {
+                app.last_input = Instant::now();
+                // The input that ends the demo does nothing else.
+                if let Some(demo) = app.demo.take() {
+                    logger::info("Leaving the demo");
+                    app.open_page(demo.prior_page);
+                    dirty = true;
+                    continue;
+                }
+            }
+            let key = match event {
+                Event::Key(key) => key,
+                Event::Resize(_, _) => {
+                    // Drop the previous frame so nothing stale survives the resize.
+                    terminal.clear()?;
+                    dirty = true;
+                    continue;
+                }
+                Event::Mouse(_)
+                    if app.paused
+                        || app.show_help
+                        || app.notifications.is_some()
+                        || app.difficulty_picker.is_some()
+                        || app.palette.is_some()
+                        || app.confirm.is_some()
+                        || app.filter.is_some()
+                        || app.tutorial.is_some() =>
+                {
+                    continue;
+                }
+                Event::Mouse(mouse) => {
+                    let Some(layout) = app.last_layout else {
+                        continue;
+                    };
+                    let position = Position::new(mouse.column, mouse.row);
+                    let over_menu = layout.menu.contains(position);
+                    let over_content = layout.content.contains(position);
+                    match mouse.kind {
+                        MouseEventKind::Down(MouseButton::Left) => {
+                            if let Some(index) = ui::menu_index_at(
+                                layout.menu,
+                                &app.menu_state,
+                                app.menu_items.len(),
+                                mouse.column,
+                                mouse.row,
+                            ) {
+                                app.select(index);
+                                dirty = true;
+                            } else if layout.input.contains(position) {
+                                app.mode = Mode::Input;
+                                dirty = true;
+                            }
+                        }
+                        MouseEventKind::ScrollUp if over_menu => {
+                            app.select_by(-1, false);
+                            dirty = true;
+                        }
+                        MouseEventKind::ScrollDown if over_menu => {
+                            app.select_by(1, false);
+                            dirty = true;
+                        }
+                        MouseEventKind::ScrollUp if over_content => {
+                            let offset = app.scroll_offset();
+                            *offset = offset.saturating_sub(WHEEL_SCROLL_LINES);
+                            dirty = true;
+                        }
+                        MouseEventKind::ScrollDown if over_content => {
+                            // Clamped against the content length on the next draw.
+                            let offset = app.scroll_offset();
+                            *offset = offset.saturating_add(WHEEL_SCROLL_LINES);
+                            dirty = true;
+                        }
+                        _ => {}
+                    }
+                    continue;
+                }
+                _ => continue,
+            };
+            dirty = true;
+            if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+                // The way out that always works, even past the prompts.
+                logger::info("Quitting on Ctrl-C without saving");
+                break;
+            }
+            if app.paused {
+                if matches!(key.code, KeyCode::Char('p') | KeyCode::Esc) {
+                    app.paused = false;
+                    logger::info("Resumed");
+                }
+                continue;
+            }
+            let no_overlay = !app.show_help
+                && app.notifications.is_none()
+                && app.difficulty_picker.is_none()
+                && app.tutorial.is_none()
+                && app.confirm.is_none()
+                && app.filter.is_none();
+            if key.code == KeyCode::Char('p')
+                && key.modifiers.contains(KeyModifiers::CONTROL)
+                && no_overlay
+            {
+                app.palette = Some(Palette::default());
+                continue;
+            }
+            // A command picked from the palette, to run as if typed.
+            let mut from_palette = false;
+            if let Some(palette) = &mut app.palette {
+                match palette.handle_key(key.code) {
+                    Some(Pick::Run(line)) => {
+                        app.palette = None;
+                        app.set_input(line);
+                        from_palette = true;
+                    }
+                    Some(Pick::Fill(line)) => {
+                        app.palette = None;
+                        app.set_input(line);
+                        app.mode = Mode::Input;
+                        continue;
+                    }
+                    Some(Pick::Close) => {
+                        app.palette = None;
+                        continue;
+                    }
+                    None => continue,
+                }
+            }
+            if app.show_help {
+                // The overlay swallows every other key so the view underneath
+                // is exactly as it was when help was opened.
+                if key.code == KeyCode::Esc || app.keymap.binding(key.code) == Some(Binding::Help) {
+                    app.show_help = false;
+                }
+                continue;
+            }
+            if let Some(offset) = app.notifications {
+                // Like help, the log swallows every key but its own.
+                let page_lines = app
+                    .last_layout
+                    .map(|layout| layout.content.height.saturating_sub(2).max(1))
+                    .unwrap_or(1);
+                app.notifications = match key.code {
+                    KeyCode::Esc | KeyCode::Char('n') => None,
+                    KeyCode::Up | KeyCode::Char('k') => Some(offset.saturating_sub(1)),
+                    KeyCode::Down | KeyCode::Char('j') => Some(offset.saturating_add(1)),
+                    KeyCode::PageUp => Some(offset.saturating_sub(page_lines)),
+                    // Clamped against the log's length on the next draw.
+                    KeyCode::PageDown => Some(offset.saturating_add(page_lines)),
+                    _ => Some(offset),
+                };
+                app.toasts.mark_read();
+                continue;
+            }
+            if let Some(selected) = app.difficulty_picker {
+                // A new game starts here, before even the tutorial. Esc skips
+                // the choice and plays Normal.
+                let picked = match key.code {
+                    KeyCode::Up | KeyCode::Left | KeyCode::Char('k') => {
+                        app.difficulty_picker = Some(selected.prev());
+                        None
+                    }
+                    KeyCode::Down | KeyCode::Right | KeyCode::Char('j') => {
+                        app.difficulty_picker = Some(selected.next());
+                        None
+                    }
+                    KeyCode::Enter => Some(selected),
+                    KeyCode::Esc => Some(Difficulty::default()),
+                    _ => None,
+                };
+                if let Some(difficulty) = picked {
+                    app.difficulty_picker = None;
+                    difficulty::start(&mut app.game, difficulty);
+                    scheduler =
+                        Scheduler::standard(app.game.clock.elapsed(), difficulty.regen_interval());
+                    logger::info(format!("Playing on {}", difficulty.name()));
+                }
+                continue;
+            }
+            if let Some(mut tutorial) = app.tutorial.take() {
+                // Any key moves the tutorial on; Esc skips the rest of it.
+                let skipped = key.code == KeyCode::Esc;
+                if !skipped && tutorial.advance() {
+                    app.tutorial = Some(tutorial);
+                } else if let Some(bonus) = tutorial::finish(&mut app.game, !skipped) {
+                    app.toasts.push(
+                        format!(
+                            "Tutorial complete! Here's {} to get you started.",
+                            format_money(bonus)
+                        ),
+                        ToastLevel::Info,
+                        app.game.clock.elapsed(),
+                    );
+                }
+                continue;
+            }
+            if let Some(dialog) = app.confirm.take() {
+                match key.code {
+                    KeyCode::Char('y' | 'Y') if dialog.action == Action::Quit => {
+                        match save_now(&app.game, &mut autosave, &save_path) {
+                            Ok(()) => {
+                                logger::info("Quitting");
+                                break;
+                            }
+                            Err(err) => app.toasts.push(
+                                format!(
+                                    "Couldn't save ({}), so still here. Ctrl-C quits without saving.",
+                                    err
+                                ),
+                                ToastLevel::Error,
+                                app.game.clock.elapsed(),
+                            ),
+                        }
+                    }
+                    KeyCode::Char('y' | 'Y') => {
+                        let snapshot = app.game.clone();
+                        let result = run_action(dialog.action, &mut app.game, now, &mut rng);
+                        app.track_undo(snapshot, dialog.action.is_reversible());
+                        if dialog.action == Action::ResetGame {
+                            logger::info("Game reset");
+                            // The new game's clock starts from zero, so old
+                            // toasts would never expire.
+                            app.toasts = Toasts::default();
+                            autosave = Autosave::new(None, Instant::now());
+                            if let Err(err) = save::delete_save(&save_path) {
+                                logger::error(format!("Couldn't delete the save: {}", err));
+                                let text = format!("Couldn't delete the save: {}", err);
+                                app.toasts
+                                    .push(text, ToastLevel::Error, app.game.clock.elapsed());
+                            }
+                            app.select(0);
+                            app.scroll_offsets.clear();
+                            app.difficulty_picker = Some(Difficulty::default());
+                        }
+                        app.toasts.push_result(&result, app.game.clock.elapsed());
+                    }
+                    KeyCode::Char('n' | 'N') | KeyCode::Esc => {
+                        app.toasts
+                            .push("Cancelled.", ToastLevel::Info, app.game.clock.elapsed());
+                    }
+                    // Anything else leaves the question on screen.
+                    _ => app.confirm = Some(dialog),
+                }
+                continue;
+            }
+            if let Some(mut query) = app.filter.take() {
                 match key.code {
-                    KeyCode::Char(c) => input.push(c),
+                    KeyCode::Enter => {
+                        if let Some(&index) = ui::filter_menu(&app.menu_items, &query).first() {
+                            app.select(index);
+                        }
+                    }
+                    KeyCode::Esc => {}
                     KeyCode::Backspace => {
-                        input.pop();
-                    }
-                    KeyCode::Enter => input.clear(),
-                    KeyCode::Esc => break,
-                    KeyCode::Up => {
-                        if selected > 0 {
-                            selected -= 1;
-                            state.select(Some(selected));
+                        query.pop();
+                        app.filter = Some(query);
+                    }
+                    KeyCode::Char(c) => {
+                        query.push(c);
+                        app.filter = Some(query);
+                    }
+                    _ => app.filter = Some(query),
+                }
+                continue;
+            }
+            let current_page = app.current_page();
+            // Outcome of a page action, toasted once the key is handled.
+            let mut result = Ok(CommandResult::Empty);
+            let action = if from_palette {
+                KeyAction::Submit
+            } else if key.modifiers.contains(KeyModifiers::ALT) && key.code == KeyCode::Left {
+                KeyAction::Back
+            } else if key.modifiers.contains(KeyModifiers::ALT) && key.code == KeyCode::Right {
+                KeyAction::Forward
+            } else {
+                match keys::handle_key(key.code, app.mode, &app.input, &app.keymap) {
+                    Some(action) => action,
+                    None => continue,
+                }
+            };
+            let snapshot = app.game.clone();
+            // Whether the key ran something undo can take back, and whether
+            // it was the undo itself.
+            let mut reversible = false;
+            let mut undone = false;
+            match action {
+                KeyAction::Help => app.show_help = true,
+                KeyAction::Focus(focus) => app.mode = focus,
+                // Stray terminal sequences can arrive as control characters.
+                KeyAction::Type(c) if c.is_control() => {}
+                KeyAction::Type(c) => app.insert_char(c),
+                KeyAction::Backspace => app.backspace(),
+                KeyAction::Delete => app.delete(),
+                KeyAction::CursorLeft => app.cursor_left(),
+                KeyAction::CursorRight => app.cursor_right(),
+                KeyAction::CursorHome => app.cursor_home(),
+                KeyAction::CursorEnd => app.cursor_end(),
+                KeyAction::Complete => {
+                    if let Some(line) = app.completer.tab(&app.input, &app.game) {
+                        app.set_input(line);
+                    }
+                }
+                KeyAction::HistoryPrev => {
+                    if let Some(line) = app.history.prev(&app.input) {
+                        let line = line.to_string();
+                        app.set_input(line);
+                    }
+                }
+                KeyAction::HistoryNext => {
+                    if let Some(line) = app.history.next() {
+                        let line = line.to_string();
+                        app.set_input(line);
+                    }
+                }
+                KeyAction::Submit if let Err(err) = command::clean_input(&app.input) => {
+                    let text = format!("{}; input cleared.", err);
+                    app.toasts
+                        .push(text, ToastLevel::Warn, app.game.clock.elapsed());
+                    app.clear_input();
+                }
+                KeyAction::Submit => {
+                    reversible = command::is_reversible(&app.input);
+                    app.output = command::parse_command(&app.input, &mut app.game, now);
+                    logger::debug(format!("Command {:?} -> {:?}", app.input, app.output));
+                    if let Ok(CommandResult::Confirm(action)) = app.output {
+                        app.output =
+                            perform(action, &mut app.game, &mut app.confirm, now, &mut rng);
+                    }
+                    clamp_item_selection(&mut app.game);
+                    app.history.push(&app.input);
+                    match app.output {
+                        Ok(CommandResult::Goto(page)) => {
+                            app.open_page(page);
+                            app.mode = Mode::Navigate;
+                        }
+                        Ok(CommandResult::Tutorial) => {
+                            app.tutorial = Some(Tutorial::default());
+                            app.mode = Mode::Navigate;
+                        }
+                        Ok(CommandResult::Undo) => {
+                            app.output = app.undo_last();
+                            undone = true;
                         }
+                        Ok(CommandResult::Pause) => {
+                            app.paused = true;
+                            app.mode = Mode::Navigate;
+                            logger::info("Paused");
+                        }
+                        Ok(CommandResult::Export(ref path)) => {
+                            app.output = match save::export_game(&app.game, path) {
+                                Ok(()) => Ok(CommandResult::Message(format!(
+                                    "Exported the game to {}.",
+                                    path.display()
+                                ))),
+                                Err(err) => Err(error::RustyError::game(format!(
+                                    "Couldn't export to {}: {}",
+                                    path.display(),
+                                    err
+                                ))),
+                            };
+                            app.toasts
+                                .push_result(&app.output, app.game.clock.elapsed());
+                        }
+                        _ => {}
                     }
-                    KeyCode::Down => {
-                        if selected < menu_items.len() - 1 {
-                            selected += 1;
-                            state.select(Some(selected));
+                    app.clear_input();
+                }
+                KeyAction::Filter => app.filter = Some(String::new()),
+                KeyAction::Digit(digit) => {
+                    let target = match app.pending_jump.take() {
+                        Some((first, _)) => Some(first * 10 + digit),
+                        None if digit == 0 => None,
+                        None if digit * 10 <= app.menu_items.len() => {
+                            app.pending_jump = Some((digit, now));
+                            None
+                        }
+                        None => Some(digit),
+                    };
+                    if let Some(n) = target.filter(|n| (1..=app.menu_items.len()).contains(n)) {
+                        app.select(n - 1);
+                    }
+                }
+                KeyAction::Back => {
+                    if let Some(page) = app.visits.back() {
+                        app.open_page(page);
+                    }
+                }
+                KeyAction::Forward => {
+                    if let Some(page) = app.visits.forward() {
+                        app.open_page(page);
+                    }
+                }
+                KeyAction::NextPage => app.select_next(),
+                KeyAction::PrevPage => app.select_prev(),
+                KeyAction::ListMove(delta) => {
+                    app.move_list(delta, app.game.settings.wrap_navigation);
+                }
+                KeyAction::ListPage(up) => {
+                    let rows = app
+                        .last_layout
+                        .map(|layout| layout.content.height.saturating_sub(2).max(1))
+                        .unwrap_or(1);
+                    let rows = rows as isize;
+                    app.move_list(if up { -rows } else { rows }, false);
+                }
+                KeyAction::FirstPage => {
+                    app.select(0);
+                }
+                KeyAction::LastPage => {
+                    app.select(app.menu_items.len() - 1);
+                }
+                KeyAction::Scroll(up) => {
+                    let page_lines = app
+                        .last_layout
+                        .map(|layout| layout.content.height.saturating_sub(2).max(1))
+                        .unwrap_or(1);
+                    let offset = app.scroll_offsets.entry(current_page).or_insert(0);
+                    *offset = if up {
+                        offset.saturating_sub(page_lines)
+                    } else {
+                        offset.saturating_add(page_lines)
+                    };
+                }
+                KeyAction::Save => {
+                    let now = app.game.clock.elapsed();
+                    match save_now(&app.game, &mut autosave, &save_path) {
+                        Ok(()) => app.toasts.push("Saved", ToastLevel::Info, now),
+                        Err(err) => {
+                            app.toasts
+                                .push(format!("Save failed: {}", err), ToastLevel::Error, now)
                         }
                     }
-                    _ => {}
                 }
+                // A prompt open on the page is closed first; only with
+                // nothing left to close does it ask to quit.
+                KeyAction::Quit if app.game.shop_prompt || app.game.item_prompt => {
+                    app.game.shop_prompt = false;
+                    app.game.item_prompt = false;
+                }
+                KeyAction::Quit => {
+                    app.confirm = ConfirmDialog::for_action(Action::Quit, &app.game);
+                }
+                KeyAction::Page(code) => match code {
+                    // Pause and undo work the same on every page, so they're
+                    // settled before any page gets a look at the key.
+                    KeyCode::Char('u') => {
+                        result = app.undo_last();
+                        undone = true;
+                    }
+                    KeyCode::Char('p') => {
+                        app.paused = true;
+                        logger::info("Paused");
+                    }
+                    KeyCode::Char(_) | KeyCode::Left | KeyCode::Right
+                        if let Some(reason) = app.game.blocked_reason(current_page, now) =>
+                    {
+                        app.toasts
+                            .push(reason, ToastLevel::Warn, app.game.clock.elapsed());
+                    }
+                    KeyCode::Char('y') if current_page == "City" && app.game.shop_prompt => {
+                        app.game.shop_prompt = false;
+                        let action = Action::BuyItem(app.game.shop_selected);
+                        result = perform(action, &mut app.game, &mut app.confirm, now, &mut rng);
+                        reversible = true;
+                    }
+                    KeyCode::Char('n') if current_page == "City" && app.game.shop_prompt => {
+                        app.game.shop_prompt = false
+                    }
+                    KeyCode::Enter if current_page == "City" => {
+                        app.game.shop_prompt = true;
+                    }
+                    KeyCode::Char('t') if current_page == "City" => {
+                        app.game.target_selected =
+                            (app.game.target_selected + 1) % fight::TARGETS.len();
+                    }
+                    KeyCode::Char('a') if current_page == "City" => {
+                        let target = app.game.target_selected;
+                        result = command::attack(&mut app.game, target, now, &mut rng);
+                    }
+                    KeyCode::Left if current_page == "City" => {
+                        app.game.shop_prompt = false;
+                        app.game.shop_selected = app.game.shop_selected.saturating_sub(1);
+                    }
+                    KeyCode::Right if current_page == "City" => {
+                        app.game.shop_prompt = false;
+                        app.game.shop_selected = (app.game.shop_selected + 1)
+                            .min(app.game.shop.entries.len().saturating_sub(1));
+                    }
+                    KeyCode::Char('e') if current_page == "Items" && app.game.item_prompt => {
+                        app.game.item_prompt = false;
+                        let (index, quantity) = (app.game.item_selected, app.game.item_quantity);
+                        result = command::use_items(&mut app.game, index, quantity);
+                        clamp_item_selection(&mut app.game);
+                    }
+                    KeyCode::Char('s') if current_page == "Items" && app.game.item_prompt => {
+                        app.game.item_prompt = false;
+                        let action =
+                            Action::SellItems(app.game.item_selected, app.game.item_quantity);
+                        result = perform(action, &mut app.game, &mut app.confirm, now, &mut rng);
+                    }
+                    KeyCode::Char(c @ ('+' | '-'))
+                        if current_page == "Items" && app.game.item_prompt =>
+                    {
+                        let available = app
+                            .game
+                            .inventory
+                            .items
+                            .get(app.game.item_selected)
+                            .map_or(1, |stack| stack.quantity);
+                        app.game.item_quantity = app.game.item_quantity.step(c == '+', available);
+                    }
+                    KeyCode::Char('a') if current_page == "Items" && app.game.item_prompt => {
+                        app.game.item_quantity = match app.game.item_quantity {
+                            Quantity::All => Quantity::default(),
+                            Quantity::Count(_) => Quantity::All,
+                        };
+                    }
+                    KeyCode::Char('d') if current_page == "Items" && app.game.item_prompt => {
+                        app.game.item_prompt = false;
+                        let action = Action::DiscardItem(app.game.item_selected);
+                        result = perform(action, &mut app.game, &mut app.confirm, now, &mut rng);
+                    }
+                    KeyCode::Char('c') if current_page == "Items" => app.game.item_prompt = false,
+                    KeyCode::Char('s') if current_page == "Items" => {
+                        app.game.inventory.toggle_sort();
+                    }
+                    KeyCode::Enter if current_page == "Items" => {
+                        app.game.item_prompt = !app.game.inventory.items.is_empty();
+                        app.game.item_quantity = Quantity::default();
+                    }
+                    KeyCode::Left if current_page == "Items" => {
+                        app.game.item_prompt = false;
+                        app.game.item_selected = app.game.item_selected.saturating_sub(1);
+                    }
+                    KeyCode::Right if current_page == "Items" => {
+                        app.game.item_prompt = false;
+                        app.game.item_selected += 1;
+                        clamp_item_selection(&mut app.game);
+                    }
+                    KeyCode::Char('r') if current_page == "Hospital" => {
+                        result = command::pay_for_recovery(&mut app.game, now);
+                    }
+                    KeyCode::Char('e') if current_page == "Jail" => {
+                        result = command::escape_jail(&mut app.game, now, &mut rng);
+                    }
+                    KeyCode::Char('t') if current_page == "Gym" => {
+                        let machine = app.game.gym_machine;
+                        result = grind(&mut app, Grind::Train(machine), key.kind, now, |game| {
+                            command::train_on_machine(game, machine)
+                        });
+                    }
+                    KeyCode::Char('r') if current_page == "Settings" => {
+                        result = perform(
+                            Action::ResetGame,
+                            &mut app.game,
+                            &mut app.confirm,
+                            now,
+                            &mut rng,
+                        );
+                    }
+                    KeyCode::Char('+' | '=') if current_page == "Settings" => {
+                        let setting = app.game.setting_selected;
+                        app.game.settings.adjust(setting, true);
+                    }
+                    KeyCode::Char('-') if current_page == "Settings" => {
+                        let setting = app.game.setting_selected;
+                        app.game.settings.adjust(setting, false);
+                    }
+                    KeyCode::Left if current_page == "Settings" => {
+                        app.game.setting_selected = app.game.setting_selected.prev();
+                    }
+                    KeyCode::Right if current_page == "Settings" => {
+                        app.game.setting_selected = app.game.setting_selected.next();
+                    }
+                    KeyCode::Left if current_page == "Messages" => {
+                        app.game.message_selected = app.game.message_selected.saturating_sub(1);
+                    }
+                    KeyCode::Right if current_page == "Messages" => {
+                        let last = app.game.inbox.messages.len().saturating_sub(1);
+                        app.game.message_selected = (app.game.message_selected + 1).min(last);
+                    }
+                    KeyCode::Left if current_page == "Forums" => {
+                        app.game.thread_selected = app.game.thread_selected.saturating_sub(1);
+                        app.scroll_offsets.insert(current_page, 0);
+                    }
+                    KeyCode::Right if current_page == "Forums" => {
+                        let last = app.game.forums.threads.len().saturating_sub(1);
+                        app.game.thread_selected = (app.game.thread_selected + 1).min(last);
+                        app.scroll_offsets.insert(current_page, 0);
+                    }
+                    KeyCode::Left if current_page == "Gym" => {
+                        app.game.gym_machine = app.game.gym_machine.saturating_sub(1)
+                    }
+                    KeyCode::Right if current_page == "Gym" => {
+                        app.game.gym_machine =
+                            (app.game.gym_machine + 1).min(gym::MACHINES.len() - 1)
+                    }
+                    KeyCode::Char('w') if current_page == "Job" => {
+                        let task = app.game.job_task_selected;
+                        result = command::complete_task(&mut app.game, task);
+                    }
+                    KeyCode::Left if current_page == "Job" => {
+                        app.game.job_task_selected = app.game.job_task_selected.saturating_sub(1);
+                    }
+                    KeyCode::Right if current_page == "Job" => {
+                        let last = job::current(&app.game).tasks.len().saturating_sub(1);
+                        app.game.job_task_selected = (app.game.job_task_selected + 1).min(last);
+                    }
+                    KeyCode::Char('b') if current_page == "Properties" => {
+                        let action = Action::BuyProperty(app.game.property_selected);
+                        result = perform(action, &mut app.game, &mut app.confirm, now, &mut rng);
+                        reversible = true;
+                    }
+                    KeyCode::Char('s') if current_page == "Properties" => {
+                        let action = Action::SellProperty(app.game.property_selected);
+                        result = perform(action, &mut app.game, &mut app.confirm, now, &mut rng);
+                    }
+                    KeyCode::Left if current_page == "Properties" => {
+                        app.game.property_selected = app.game.property_selected.saturating_sub(1);
+                    }
+                    KeyCode::Right if current_page == "Properties" => {
+                        let last = app.game.real_estate.properties.len().saturating_sub(1);
+                        app.game.property_selected = (app.game.property_selected + 1).min(last);
+                    }
+                    KeyCode::Left if current_page == "Hall of Fame" => {
+                        app.game.hall_of_fame.metric = app.game.hall_of_fame.metric.prev();
+                    }
+                    KeyCode::Right if current_page == "Hall of Fame" => {
+                        app.game.hall_of_fame.metric = app.game.hall_of_fame.metric.next();
+                    }
+                    KeyCode::Char('e') if current_page == "Education" => {
+                        let index = app.game.course_selected;
+                        result = command::enroll(&mut app.game, index, now);
+                    }
+                    KeyCode::Left if current_page == "Education" => {
+                        app.game.course_selected = app.game.course_selected.saturating_sub(1);
+                    }
+                    KeyCode::Right if current_page == "Education" => {
+                        app.game.course_selected =
+                            (app.game.course_selected + 1).min(education::COURSES.len() - 1);
+                    }
+                    KeyCode::Char('b') if current_page == "Casino" => {
+                        app.game.casino_game = app.game.casino_game.next();
+                    }
+                    KeyCode::Char('s')
+                        if current_page == "Casino"
+                            && app.game.casino_game == CasinoGame::Slots =>
+                    {
+                        let action = Action::SpinSlots;
+                        result = perform(action, &mut app.game, &mut app.confirm, now, &mut rng);
+                    }
+                    KeyCode::Left
+                        if current_page == "Casino"
+                            && app.game.casino_game == CasinoGame::Slots =>
+                    {
+                        app.game.slots.lower_bet()
+                    }
+                    KeyCode::Right
+                        if current_page == "Casino"
+                            && app.game.casino_game == CasinoGame::Slots =>
+                    {
+                        app.game.slots.raise_bet()
+                    }
+                    KeyCode::Char('d')
+                        if current_page == "Casino"
+                            && app.game.casino_game == CasinoGame::Blackjack =>
+                    {
+                        let action = Action::DealBlackjack;
+                        result = perform(action, &mut app.game, &mut app.confirm, now, &mut rng);
+                    }
+                    KeyCode::Char(c @ ('h' | 's'))
+                        if current_page == "Casino"
+                            && app.game.casino_game == CasinoGame::Blackjack =>
+                    {
+                        result = command::play_blackjack(&mut app.game, c, &mut rng);
+                    }
+                    KeyCode::Left
+                        if current_page == "Casino"
+                            && app.game.casino_game == CasinoGame::Blackjack =>
+                    {
+                        app.game.blackjack.lower_bet()
+                    }
+                    KeyCode::Right
+                        if current_page == "Casino"
+                            && app.game.casino_game == CasinoGame::Blackjack =>
+                    {
+                        app.game.blackjack.raise_bet()
+                    }
+                    KeyCode::Char('t')
+                        if current_page == "Casino"
+                            && app.game.casino_game == CasinoGame::Roulette =>
+                    {
+                        app.game.roulette.kind = app.game.roulette.kind.next();
+                    }
+                    KeyCode::Char(c @ ('+' | '=' | '-'))
+                        if current_page == "Casino"
+                            && app.game.casino_game == CasinoGame::Roulette =>
+                    {
+                        app.game.roulette.step_number(c != '-');
+                    }
+                    KeyCode::Char('a')
+                        if current_page == "Casino"
+                            && app.game.casino_game == CasinoGame::Roulette =>
+                    {
+                        let table = &app.game.roulette;
+                        let bet = Bet {
+                            kind: table.kind,
+                            amount: table.bet_amount(),
+                        };
+                        let action = Action::PlaceBet(bet);
+                        result = perform(action, &mut app.game, &mut app.confirm, now, &mut rng);
+                    }
+                    KeyCode::Char('x')
+                        if current_page == "Casino"
+                            && app.game.casino_game == CasinoGame::Roulette =>
+                    {
+                        app.game.roulette.clear_slip();
+                    }
+                    KeyCode::Char('s')
+                        if current_page == "Casino"
+                            && app.game.casino_game == CasinoGame::Roulette =>
+                    {
+                        result = command::spin_roulette(&mut app.game, &mut rng);
+                    }
+                    KeyCode::Left
+                        if current_page == "Casino"
+                            && app.game.casino_game == CasinoGame::Roulette =>
+                    {
+                        app.game.roulette.lower_bet()
+                    }
+                    KeyCode::Right
+                        if current_page == "Casino"
+                            && app.game.casino_game == CasinoGame::Roulette =>
+                    {
+                        app.game.roulette.raise_bet()
+                    }
+                    KeyCode::Char('c') if current_page == "Crimes" => {
+                        let index = app.game.crime_selected;
+                        result = grind(&mut app, Grind::Crime(index), key.kind, now, |game| {
+                            command::commit_crime(game, &crime::CRIMES[index], now, &mut rng)
+                        });
+                    }
+                    KeyCode::Left if current_page == "Crimes" => {
+                        app.game.crime_selected = (app.game.crime_selected + crime::CRIMES.len()
+                            - 1)
+                            % crime::CRIMES.len();
+                    }
+                    KeyCode::Right if current_page == "Crimes" => {
+                        app.game.crime_selected =
+                            (app.game.crime_selected + 1) % crime::CRIMES.len();
+                    }
+                    KeyCode::Left if current_page == "Rules" => app.game.reports.select_rule(false),
+                    KeyCode::Right if current_page == "Rules" => app.game.reports.select_rule(true),
+                    KeyCode::Char('t') if current_page == "Rules" => {
+                        app.game.reports.select_target(true)
+                    }
+                    KeyCode::Char('r') if current_page == "Rules" => {
+                        result = command::file_report(&mut app.game);
+                    }
+                    KeyCode::Char('c') if current_page == "Home" => {
+                        result = command::claim_daily(&mut app.game);
+                    }
+                    KeyCode::Char('c') if current_page == "Recruit Citizens" => {
+                        result = command::claim_rewards(&mut app.game);
+                    }
+                    KeyCode::Char('l') if pages::has_list(current_page) => {
+                        app.mode = Mode::List;
+                    }
+                    KeyCode::Char('n') => {
+                        app.notifications = Some(0);
+                        app.toasts.mark_read();
+                    }
+                    _ => {}
+                },
+            }
+            if !undone {
+                app.track_undo(snapshot, reversible);
             }
+            app.toasts.push_result(&result, app.game.clock.elapsed());
         }
     }
 
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
-    terminal.show_cursor()?;
+    restore_terminal()?;
     Ok(())
 }
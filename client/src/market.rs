@@ -0,0 +1,91 @@
+//! Daily price moves for the City shop and the property listings. Each game
+//! day every price takes a small random step, bounded around its base price
+//! so nothing runs away. The steps come from an RNG seeded by the save's
+//! seed and the day, so a given day always moves prices the same way.
+
+use std::cmp::Ordering;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use crate::state::GameState;
+
+/// Largest daily move, as a percentage of the current price.
+pub const MAX_STEP_PERCENT: i64 = 8;
+/// Prices never drop below this share of the base price...
+pub const FLOOR_PERCENT: u64 = 50;
+/// ...or climb above this one.
+pub const CEILING_PERCENT: u64 = 200;
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Market {
+    /// Picked once per save; zero means not yet.
+    pub seed: u64,
+}
+
+/// A price with its base and yesterday's value, as kept by the shop and the
+/// property listings.
+pub struct Quote<'a> {
+    pub price: &'a mut u64,
+    /// What the price started at; bounds the walk.
+    pub base_price: &'a mut u64,
+    pub previous_price: &'a mut u64,
+}
+
+impl Quote<'_> {
+    /// Moves the price one step, remembering where it was. Saves from before
+    /// prices moved have no base or previous price, so today's stands in.
+    fn step(self, rng: &mut impl Rng) {
+        if *self.base_price == 0 {
+            *self.base_price = *self.price;
+        }
+        *self.previous_price = *self.price;
+        let base = *self.base_price;
+        let change = rng.random_range(-MAX_STEP_PERCENT..=MAX_STEP_PERCENT);
+        let moved = *self.price as i64 + *self.price as i64 * change / 100;
+        let floor = (base * FLOOR_PERCENT / 100).max(1);
+        let ceiling = base * CEILING_PERCENT / 100;
+        *self.price = (moved.max(0) as u64).clamp(floor, ceiling.max(floor));
+    }
+}
+
+/// Moves every shop and property price for the day that just started.
+pub fn fluctuate(state: &mut GameState, rng: &mut impl Rng) {
+    if state.market.seed == 0 {
+        state.market.seed = rng.random_range(1..u64::MAX);
+    }
+    let day = state.clock.day();
+    let mut prices =
+        StdRng::seed_from_u64(state.market.seed ^ day.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+    for entry in &mut state.shop.entries {
+        Quote {
+            price: &mut entry.price,
+            base_price: &mut entry.base_price,
+            previous_price: &mut entry.previous_price,
+        }
+        .step(&mut prices);
+    }
+    for property in &mut state.real_estate.properties {
+        Quote {
+            price: &mut property.price,
+            base_price: &mut property.base_price,
+            previous_price: &mut property.previous_price,
+        }
+        .step(&mut prices);
+    }
+}
+
+/// An arrow and the change since yesterday, e.g. `▲3`, or blank if the
+/// price hasn't moved.
+pub fn trend_text(price: u64, previous: u64) -> String {
+    if previous == 0 {
+        return String::new();
+    }
+    match price.cmp(&previous) {
+        Ordering::Greater => format!("▲{}", price - previous),
+        Ordering::Less => format!("▼{}", previous - price),
+        Ordering::Equal => String::new(),
+    }
+}
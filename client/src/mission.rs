@@ -0,0 +1,269 @@
+use std::collections::BTreeMap;
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::drops::{Drop, DropTable};
+use crate::inventory::Item;
+use crate::level;
+use crate::money::format_money;
+use crate::player::Stat;
+use crate::state::GameState;
+
+/// Something the player has to do for a mission. Steps are checked against
+/// the game state every tick and stay ticked off once met.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MissionStep {
+    CommitCrimes(u32),
+    ReachStat(Stat, u32),
+    HaveMoney(u64),
+}
+
+impl MissionStep {
+    /// Current progress towards the step and the target, for display.
+    fn progress(self, state: &GameState) -> (u64, u64) {
+        match self {
+            MissionStep::CommitCrimes(n) => (state.crimes_committed.into(), n.into()),
+            MissionStep::ReachStat(stat, x) => (state.player.stat(stat).into(), x.into()),
+            MissionStep::HaveMoney(y) => (state.player.money, y),
+        }
+    }
+
+    fn is_met(self, state: &GameState) -> bool {
+        let (current, target) = self.progress(state);
+        current >= target
+    }
+
+    pub fn describe(self) -> String {
+        match self {
+            MissionStep::CommitCrimes(n) => format!("Commit {} crimes", n),
+            MissionStep::ReachStat(stat, x) => format!("Reach {} {}", x, stat.name()),
+            MissionStep::HaveMoney(y) => format!("Have {} on hand", format_money(y)),
+        }
+    }
+}
+
+pub struct Mission {
+    pub name: &'static str,
+    pub steps: &'static [MissionStep],
+    /// Money paid once every step is done.
+    pub reward: u64,
+    pub xp: u64,
+    /// Rolled once when the mission is finished.
+    pub drops: DropTable,
+}
+
+pub const MISSIONS: [Mission; 3] = [
+    Mission {
+        name: "Petty Beginnings",
+        steps: &[MissionStep::CommitCrimes(3), MissionStep::HaveMoney(750)],
+        reward: 200,
+        xp: 50,
+        drops: DropTable {
+            nothing: 50,
+            drops: &[
+                Drop {
+                    item: Item::EnergyDrink,
+                    weight: 40,
+                },
+                Drop {
+                    item: Item::FirstAidKit,
+                    weight: 10,
+                },
+            ],
+        },
+    },
+    Mission {
+        name: "Gym Rat",
+        steps: &[
+            MissionStep::ReachStat(Stat::Strength, 20),
+            MissionStep::ReachStat(Stat::Speed, 20),
+        ],
+        reward: 300,
+        xp: 75,
+        drops: DropTable {
+            nothing: 30,
+            drops: &[
+                Drop {
+                    item: Item::EnergyDrink,
+                    weight: 50,
+                },
+                Drop {
+                    item: Item::FirstAidKit,
+                    weight: 20,
+                },
+            ],
+        },
+    },
+    Mission {
+        name: "Career Criminal",
+        steps: &[
+            MissionStep::CommitCrimes(15),
+            MissionStep::ReachStat(Stat::Dexterity, 25),
+            MissionStep::HaveMoney(5_000),
+        ],
+        reward: 1_500,
+        xp: 200,
+        drops: DropTable {
+            nothing: 20,
+            drops: &[
+                Drop {
+                    item: Item::FirstAidKit,
+                    weight: 55,
+                },
+                Drop {
+                    item: Item::LuckyCharm,
+                    weight: 25,
+                },
+            ],
+        },
+    },
+];
+
+/// Saved mission progress, keyed by mission name so reordering `MISSIONS`
+/// doesn't scramble old saves.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MissionLog {
+    pub steps_done: BTreeMap<String, Vec<bool>>,
+    /// Names of finished missions, in the order they were finished.
+    pub completed: Vec<String>,
+}
+
+impl MissionLog {
+    pub fn is_completed(&self, mission: &Mission) -> bool {
+        self.completed.iter().any(|name| name == mission.name)
+    }
+
+    fn step_done(&self, mission: &Mission, step: usize) -> bool {
+        self.steps_done
+            .get(mission.name)
+            .and_then(|steps| steps.get(step))
+            .copied()
+            .unwrap_or(false)
+    }
+}
+
+/// What `update` did.
+#[derive(Debug, Default)]
+pub struct Update {
+    pub changed: bool,
+    /// Items found on missions finished just now.
+    pub found: Vec<Item>,
+}
+
+/// Ticks off any steps the player has met and pays out finished missions,
+/// rolling each one's drop table with `rng`.
+pub fn update(state: &mut GameState, rng: &mut impl Rng) -> Update {
+    let mut update = Update::default();
+    for mission in &MISSIONS {
+        if state.missions.is_completed(mission) {
+            continue;
+        }
+
+        let met: Vec<bool> = mission
+            .steps
+            .iter()
+            .enumerate()
+            .map(|(i, step)| state.missions.step_done(mission, i) || step.is_met(state))
+            .collect();
+        let done = state
+            .missions
+            .steps_done
+            .entry(mission.name.to_string())
+            .or_default();
+        if *done != met {
+            *done = met;
+            update.changed = true;
+        }
+
+        if done.iter().all(|&step| step) {
+            state.missions.steps_done.remove(mission.name);
+            state.missions.completed.push(mission.name.to_string());
+            state.player.money += mission.reward;
+            state.log_news(format!(
+                "Mission complete: {} (+{}).",
+                mission.name,
+                format_money(mission.reward)
+            ));
+            level::award_xp(state, mission.xp);
+            if let Some(item) = mission.drops.roll(rng) {
+                state.inventory.add(item, 1);
+                state.log_news(format!("{} turned up a {}.", mission.name, item.name()));
+                update.found.push(item);
+            }
+            update.changed = true;
+        }
+    }
+    update
+}
+
+pub fn active_text(state: &GameState) -> String {
+    let mut blocks = Vec::new();
+    for mission in MISSIONS
+        .iter()
+        .filter(|mission| !state.missions.is_completed(mission))
+    {
+        let mut lines = vec![format!(
+            "{} (reward {})",
+            mission.name,
+            format_money(mission.reward)
+        )];
+        lines.extend(mission.steps.iter().enumerate().map(|(i, step)| {
+            let (current, target) = step.progress(state);
+            if state.missions.step_done(mission, i) {
+                format!("  [x] {}", step.describe())
+            } else {
+                format!(
+                    "  [ ] {} ({}/{})",
+                    step.describe(),
+                    current.min(target),
+                    target
+                )
+            }
+        }));
+        blocks.push(lines.join("\n"));
+    }
+
+    if blocks.is_empty() {
+        return "Every mission is done. Check back later.".to_string();
+    }
+    blocks.join("\n\n")
+}
+
+/// One line per unfinished mission with how many of its steps are done, for
+/// the Dashboard.
+pub fn summary_text(state: &GameState) -> String {
+    let lines: Vec<String> = MISSIONS
+        .iter()
+        .filter(|mission| !state.missions.is_completed(mission))
+        .map(|mission| {
+            let done = (0..mission.steps.len())
+                .filter(|&i| state.missions.step_done(mission, i))
+                .count();
+            format!("{} ({}/{})", mission.name, done, mission.steps.len())
+        })
+        .collect();
+    if lines.is_empty() {
+        return "Every mission is done.".to_string();
+    }
+    lines.join("\n")
+}
+
+pub fn completed_text(log: &MissionLog) -> String {
+    if log.completed.is_empty() {
+        return "No missions completed yet.".to_string();
+    }
+    log.completed
+        .iter()
+        .map(|name| {
+            let reward = MISSIONS
+                .iter()
+                .find(|mission| mission.name == name)
+                .map(|mission| format!("+{}", format_money(mission.reward)))
+                .unwrap_or_default();
+            format!("{:<20} {}", name, reward)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,107 @@
+//! How amounts of money are written: `$1,234,567` by default, with the
+//! symbol and the thousands separator taken from the settings. Money is
+//! shown all over, far from anything that has the settings to hand, so the
+//! format lives here and the main loop keeps it in step with them.
+
+use std::sync::RwLock;
+
+use crate::settings::Settings;
+
+/// A currency symbol and thousands separator to write amounts with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MoneyFormat {
+    symbol: String,
+    separator: String,
+}
+
+impl MoneyFormat {
+    pub fn of(settings: &Settings) -> Self {
+        Self {
+            symbol: settings.currency_symbol.clone(),
+            separator: settings.thousands_separator.clone(),
+        }
+    }
+}
+
+static FORMAT: RwLock<Option<MoneyFormat>> = RwLock::new(None);
+
+/// Takes up the symbol and separator from `settings`, if they've changed.
+pub fn configure(settings: &Settings) {
+    let Ok(mut format) = FORMAT.write() else {
+        return;
+    };
+    let unchanged = format.as_ref().is_some_and(|format| {
+        format.symbol == settings.currency_symbol
+            && format.separator == settings.thousands_separator
+    });
+    if !unchanged {
+        *format = Some(MoneyFormat::of(settings));
+    }
+}
+
+/// `amount` with the currency symbol in front and its digits grouped in
+/// threes, such as `$1,234,567`.
+pub fn format_money(amount: u64) -> String {
+    let format = FORMAT.read().ok();
+    match format.as_deref().and_then(Option::as_ref) {
+        Some(format) => format_with(format, amount),
+        None => format!("${}", group_digits(amount, ",")),
+    }
+}
+
+/// `amount` written in `format`, whatever the settings say.
+pub fn format_with(format: &MoneyFormat, amount: u64) -> String {
+    format!(
+        "{}{}",
+        format.symbol,
+        group_digits(amount, &format.separator)
+    )
+}
+
+/// `amount`'s digits with `separator` between each group of three.
+pub fn group_digits(amount: u64, separator: &str) -> String {
+    let digits = amount.to_string();
+    let mut grouped = String::with_capacity(digits.len() * (1 + separator.len()));
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push_str(separator);
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn groups_digits_in_threes() {
+        assert_eq!(group_digits(0, ","), "0");
+        assert_eq!(group_digits(7, ","), "7");
+        assert_eq!(group_digits(999, ","), "999");
+        assert_eq!(group_digits(1000, ","), "1,000");
+        assert_eq!(group_digits(1_000_000, ","), "1,000,000");
+        assert_eq!(group_digits(u64::MAX, ","), "18,446,744,073,709,551,615");
+    }
+
+    #[test]
+    fn groups_with_any_separator() {
+        assert_eq!(group_digits(1_234_567, ""), "1234567");
+        assert_eq!(group_digits(1_234_567, " | "), "1 | 234 | 567");
+    }
+
+    #[test]
+    fn formats_with_the_settings_symbol() {
+        let settings = Settings {
+            currency_symbol: "£".to_string(),
+            thousands_separator: ".".to_string(),
+            ..Settings::default()
+        };
+        let format = MoneyFormat::of(&settings);
+        assert_eq!(format_with(&format, 1_234_567), "£1.234.567");
+        assert_eq!(format_with(&format, 0), "£0");
+        let format = MoneyFormat::of(&Settings::default());
+        assert_eq!(format_with(&format, 1000), "$1,000");
+    }
+}
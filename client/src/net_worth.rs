@@ -0,0 +1,107 @@
+//! What everything the player has is worth, for the Home page and the Hall
+//! of Fame. Assets count at what they'd fetch if sold today.
+
+use crate::money::format_money;
+use crate::state::GameState;
+
+/// Net worth split by where it's held.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Breakdown {
+    pub cash: u64,
+    pub bank: u64,
+    /// Resale value of the properties owned.
+    pub properties: u64,
+    /// Resale value of the items carried.
+    pub items: u64,
+}
+
+impl Breakdown {
+    pub fn of(state: &GameState) -> Self {
+        Self {
+            cash: state.player.money,
+            bank: state.bank.balance,
+            properties: state
+                .real_estate
+                .owned()
+                .map(|property| property.resale_price())
+                .sum(),
+            items: state
+                .inventory
+                .items
+                .iter()
+                .map(|stack| stack.item.resale_value() * u64::from(stack.quantity))
+                .sum(),
+        }
+    }
+
+    pub fn total(&self) -> u64 {
+        // Saturating, since a hand-edited save can hold anything.
+        self.cash
+            .saturating_add(self.bank)
+            .saturating_add(self.properties)
+            .saturating_add(self.items)
+    }
+}
+
+pub fn net_worth(state: &GameState) -> u64 {
+    Breakdown::of(state).total()
+}
+
+pub fn breakdown_text(state: &GameState) -> String {
+    let breakdown = Breakdown::of(state);
+    let rows = [
+        ("Cash", breakdown.cash),
+        ("Bank", breakdown.bank),
+        ("Properties", breakdown.properties),
+        ("Items", breakdown.items),
+    ];
+    let lines: Vec<String> = rows
+        .iter()
+        .map(|(label, value)| format!("  {:<12} {}", label, format_money(*value)))
+        .collect();
+    format!(
+        "Net worth: {}\n{}",
+        format_money(breakdown.total()),
+        lines.join("\n")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::{Inventory, Item};
+
+    #[test]
+    fn counts_everything_at_resale_value() {
+        let mut state = GameState::default();
+        state.player.money = 1_500;
+        state.bank.balance = 10_000;
+        let trailer = &mut state.real_estate.properties[0];
+        trailer.price = 1_000;
+        trailer.owned = true;
+        state.inventory = Inventory {
+            items: Vec::new(),
+            sort_by_rarity: false,
+        };
+        state.inventory.add(Item::FirstAidKit, 3);
+
+        let breakdown = Breakdown::of(&state);
+        assert_eq!(breakdown.cash, 1_500);
+        assert_eq!(breakdown.bank, 10_000);
+        assert_eq!(breakdown.properties, 700);
+        assert_eq!(breakdown.items, 180);
+        assert_eq!(breakdown.total(), 12_380);
+        assert_eq!(net_worth(&state), 12_380);
+    }
+
+    #[test]
+    fn total_saturates() {
+        let breakdown = Breakdown {
+            cash: u64::MAX,
+            bank: 1,
+            properties: 1,
+            items: 1,
+        };
+        assert_eq!(breakdown.total(), u64::MAX);
+    }
+}
@@ -0,0 +1,109 @@
+use std::collections::VecDeque;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+/// Oldest headlines fall off once the log holds this many.
+pub const NEWS_LOG_LEN: usize = 200;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NewsEntry {
+    /// Seconds since the Unix epoch; 0 for entries from saves that predate
+    /// timestamps.
+    pub timestamp: u64,
+    pub text: String,
+}
+
+/// Ring buffer of game events shown on the Newspaper page.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(from = "SavedNews")]
+pub struct NewsLog {
+    entries: VecDeque<NewsEntry>,
+    /// Entries pushed since the Newspaper was last opened.
+    unread: usize,
+}
+
+/// Older saves stored the news as a plain list of headlines.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SavedNews {
+    Current {
+        #[serde(default)]
+        entries: VecDeque<NewsEntry>,
+        #[serde(default)]
+        unread: usize,
+    },
+    Legacy(Vec<String>),
+}
+
+impl From<SavedNews> for NewsLog {
+    fn from(saved: SavedNews) -> Self {
+        match saved {
+            SavedNews::Current { entries, unread } => NewsLog { entries, unread },
+            SavedNews::Legacy(headlines) => {
+                let mut log = NewsLog::default();
+                for text in headlines {
+                    log.push(NewsEntry { timestamp: 0, text });
+                }
+                log.unread = 0;
+                log
+            }
+        }
+    }
+}
+
+impl NewsLog {
+    pub fn push(&mut self, entry: NewsEntry) {
+        self.entries.push_back(entry);
+        if self.entries.len() > NEWS_LOG_LEN {
+            self.entries.pop_front();
+        }
+        self.unread = (self.unread + 1).min(self.entries.len());
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn newest_first(&self) -> impl Iterator<Item = &NewsEntry> {
+        self.entries.iter().rev()
+    }
+
+    pub fn has_unread(&self) -> bool {
+        self.unread > 0
+    }
+
+    pub fn mark_read(&mut self) {
+        self.unread = 0;
+    }
+}
+
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
+
+/// `HH:MM` (UTC) for a headline's timestamp.
+pub fn format_time(timestamp: u64) -> String {
+    if timestamp == 0 {
+        return "--:--".to_string();
+    }
+    let minutes = timestamp / 60;
+    format!("{:02}:{:02}", minutes / 60 % 24, minutes % 60)
+}
+
+pub fn headlines_text(log: &NewsLog) -> String {
+    if log.is_empty() {
+        return "No news yet.".to_string();
+    }
+    log.newest_first()
+        .map(|entry| format!("[{}] {}", format_time(entry.timestamp), entry.text))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
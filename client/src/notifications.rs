@@ -0,0 +1,74 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use crate::clock;
+use crate::toast::ToastLevel;
+
+/// Oldest notifications fall off once the log holds this many.
+pub const NOTIFICATION_LOG_LEN: usize = 100;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notification {
+    /// Game-clock time it was raised at.
+    pub at: Duration,
+    pub level: ToastLevel,
+    pub text: String,
+}
+
+/// Every toast this session, kept after it leaves the screen so the
+/// notifications overlay can show what was missed. Unlike the Newspaper it
+/// holds the app's own messages rather than in-game events, and it isn't
+/// saved.
+#[derive(Debug, Default)]
+pub struct NotificationLog {
+    entries: VecDeque<Notification>,
+    /// Entries pushed since the overlay was last opened.
+    unread: usize,
+}
+
+impl NotificationLog {
+    pub fn push(&mut self, entry: Notification) {
+        self.entries.push_back(entry);
+        if self.entries.len() > NOTIFICATION_LOG_LEN {
+            self.entries.pop_front();
+        }
+        self.unread = (self.unread + 1).min(self.entries.len());
+    }
+
+    pub fn newest_first(&self) -> impl Iterator<Item = &Notification> {
+        self.entries.iter().rev()
+    }
+
+    pub fn unread(&self) -> usize {
+        self.unread
+    }
+
+    pub fn mark_read(&mut self) {
+        self.unread = 0;
+    }
+}
+
+/// The overlay body: one line per notification, newest first, stamped with
+/// the game time it came in.
+pub fn log_text(log: &NotificationLog) -> String {
+    let lines: Vec<String> = log
+        .newest_first()
+        .map(|entry| {
+            let level = match entry.level {
+                ToastLevel::Info => "",
+                ToastLevel::Warn => "[warn] ",
+                ToastLevel::Error => "[error] ",
+            };
+            format!(
+                "{}  {}{}",
+                clock::format_time(entry.at.as_secs()),
+                level,
+                entry.text
+            )
+        })
+        .collect();
+    if lines.is_empty() {
+        return "Nothing yet.".to_string();
+    }
+    lines.join("\n")
+}
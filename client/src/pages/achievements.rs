@@ -0,0 +1,25 @@
+use ratatui::{Frame, layout::Rect};
+
+use super::{Page, PageContext};
+use crate::achievement;
+use crate::ui;
+
+pub fn build(ctx: PageContext<'_>) -> Box<dyn Page + '_> {
+    Box::new(AchievementsPage { ctx })
+}
+
+struct AchievementsPage<'a> {
+    ctx: PageContext<'a>,
+}
+
+impl Page for AchievementsPage<'_> {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        ui::render_boxes(
+            frame,
+            area,
+            achievement::list_text(self.ctx.game),
+            achievement::summary_text(self.ctx.game),
+            self.ctx.scroll,
+        );
+    }
+}
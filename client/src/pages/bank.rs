@@ -0,0 +1,25 @@
+use ratatui::{Frame, layout::Rect};
+
+use super::{Page, PageContext};
+use crate::bank;
+use crate::ui;
+
+pub fn build(ctx: PageContext<'_>) -> Box<dyn Page + '_> {
+    Box::new(BankPage { ctx })
+}
+
+struct BankPage<'a> {
+    ctx: PageContext<'a>,
+}
+
+impl Page for BankPage<'_> {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        ui::render_boxes(
+            frame,
+            area,
+            bank::balance_text(self.ctx.game),
+            bank::help_text(),
+            self.ctx.scroll,
+        );
+    }
+}
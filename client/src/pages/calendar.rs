@@ -0,0 +1,32 @@
+use ratatui::{Frame, layout::Rect};
+
+use super::{Page, PageContext};
+use crate::{calendar, clock, ui};
+
+/// How many upcoming events the right box lists.
+const UPCOMING_COUNT: usize = 8;
+
+pub fn build(ctx: PageContext<'_>) -> Box<dyn Page + '_> {
+    Box::new(CalendarPage { ctx })
+}
+
+struct CalendarPage<'a> {
+    ctx: PageContext<'a>,
+}
+
+impl Page for CalendarPage<'_> {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        let now = self.ctx.game.clock.minutes();
+        ui::render_boxes(
+            frame,
+            area,
+            format!(
+                "{}\n\n{}",
+                clock::format_time(now),
+                calendar::today_text(now)
+            ),
+            calendar::upcoming_text(now, UPCOMING_COUNT),
+            self.ctx.scroll,
+        );
+    }
+}
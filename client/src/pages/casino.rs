@@ -0,0 +1,58 @@
+use ratatui::{Frame, layout::Rect};
+
+use super::{Page, PageContext};
+use crate::blackjack;
+use crate::casino::{self, CasinoGame};
+use crate::roulette;
+use crate::ui;
+
+pub fn build(ctx: PageContext<'_>) -> Box<dyn Page + '_> {
+    Box::new(CasinoPage { ctx })
+}
+
+struct CasinoPage<'a> {
+    ctx: PageContext<'a>,
+}
+
+impl Page for CasinoPage<'_> {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        let game = self.ctx.game;
+        let (left, right) = match game.casino_game {
+            CasinoGame::Slots => (
+                casino::slots_text(&game.slots, self.ctx.now),
+                casino::slots_summary_text(&game.slots),
+            ),
+            CasinoGame::Blackjack => (
+                blackjack::table_text(&game.blackjack),
+                blackjack::summary_text(&game.blackjack),
+            ),
+            CasinoGame::Roulette => (
+                roulette::table_text(&game.roulette),
+                roulette::slip_text(&game.roulette),
+            ),
+        };
+        ui::render_boxes(frame, area, left, right, self.ctx.scroll);
+    }
+
+    fn actions(&self) -> &[(&'static str, &'static str)] {
+        match self.ctx.game.casino_game {
+            CasinoGame::Slots => &[("s", "spin"), ("Left/Right", "bet"), ("b", "blackjack")],
+            CasinoGame::Blackjack => &[
+                ("d", "deal"),
+                ("h", "hit"),
+                ("s", "stand"),
+                ("Left/Right", "bet"),
+                ("b", "roulette"),
+            ],
+            CasinoGame::Roulette => &[
+                ("t", "bet type"),
+                ("+/-", "number"),
+                ("a", "place"),
+                ("x", "clear"),
+                ("s", "spin"),
+                ("Left/Right", "amount"),
+                ("b", "slots"),
+            ],
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    widgets::{Block, Borders, List},
+};
+
+use super::{Page, PageContext};
+use crate::{fight, ui};
+
+pub fn build(ctx: PageContext<'_>) -> Box<dyn Page + '_> {
+    Box::new(CityPage { ctx })
+}
+
+struct CityPage<'a> {
+    ctx: PageContext<'a>,
+}
+
+impl Page for CityPage<'_> {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        let (left, right) = ui::split_boxes(area);
+        let game = self.ctx.game;
+        let theme = game.theme.theme();
+
+        let offset = ui::render_scrollable(
+            frame,
+            left,
+            fight::target_list_text(game.target_selected),
+            "Left Box",
+            self.ctx.scroll.get(),
+        );
+        self.ctx.scroll.set(offset);
+
+        let prompt = game
+            .shop
+            .prompt_text(game.shop_selected)
+            .filter(|_| game.shop_prompt);
+        let [list_area, prompt_area] = Layout::vertical([
+            Constraint::Min(0),
+            Constraint::Length(if prompt.is_some() { 3 } else { 0 }),
+        ])
+        .areas(right);
+        let list = List::new(game.shop.listing_items(&theme))
+            .block(Block::default().title("Right Box").borders(Borders::ALL))
+            .highlight_style(theme.highlight)
+            .highlight_symbol("> ");
+        let mut state = self.ctx.list.take();
+        state.select(Some(game.shop_selected));
+        frame.render_stateful_widget(list, list_area, &mut state);
+        self.ctx.list.set(state);
+        if self.ctx.list_focused {
+            ui::render_focus_ring(frame, list_area, &theme);
+        }
+        if let Some(prompt) = prompt {
+            frame.render_widget(ui::content_box(prompt, "Buy"), prompt_area);
+        }
+    }
+
+    fn actions(&self) -> &[(&'static str, &'static str)] {
+        &[
+            ("Enter", "buy"),
+            ("l", "list"),
+            ("Left/Right", "item"),
+            ("t", "target"),
+            ("a", "attack"),
+        ]
+    }
+}
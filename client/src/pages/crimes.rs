@@ -0,0 +1,33 @@
+use ratatui::{Frame, layout::Rect};
+
+use super::{Page, PageContext};
+use crate::crime;
+use crate::ui;
+
+pub fn build(ctx: PageContext<'_>) -> Box<dyn Page + '_> {
+    Box::new(CrimesPage { ctx })
+}
+
+struct CrimesPage<'a> {
+    ctx: PageContext<'a>,
+}
+
+impl Page for CrimesPage<'_> {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        ui::render_boxes(
+            frame,
+            area,
+            crime::crime_list_lines(
+                self.ctx.game,
+                self.ctx.game.clock.elapsed(),
+                self.ctx.game.crime_selected,
+            ),
+            crime::crime_history_text(&self.ctx.game.crime_history),
+            self.ctx.scroll,
+        );
+    }
+
+    fn actions(&self) -> &[(&'static str, &'static str)] {
+        &[("c", "commit"), ("Left/Right", "crime")]
+    }
+}
@@ -0,0 +1,105 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+};
+
+use super::{Page, PageContext};
+use crate::deadline;
+use crate::education;
+use crate::job;
+use crate::mission;
+use crate::money::format_money;
+use crate::net_worth;
+use crate::ui;
+
+pub fn build(ctx: PageContext<'_>) -> Box<dyn Page + '_> {
+    Box::new(DashboardPage { ctx })
+}
+
+struct DashboardPage<'a> {
+    ctx: PageContext<'a>,
+}
+
+impl DashboardPage<'_> {
+    fn vitals_text(&self) -> String {
+        let player = &self.ctx.game.player;
+        format!(
+            "Level {}  {}\nEnergy {}/{}\nHealth {}/{}\nHappiness {}",
+            player.level,
+            format_money(player.money),
+            player.energy,
+            player.max_energy,
+            player.health,
+            player.max_health,
+            player.happiness
+        )
+    }
+
+    /// Jail, hospital and course countdowns, whichever are running.
+    fn timers_text(&self) -> String {
+        let game = self.ctx.game;
+        let now = self.ctx.now;
+        let mut lines = Vec::new();
+        if let Some(left) = deadline::remaining(game.jail_until, now) {
+            lines.push(format!("Jail: {}", deadline::format_remaining(left)));
+        }
+        if let Some(left) = deadline::remaining(game.hospital_until, now) {
+            lines.push(format!("Hospital: {}", deadline::format_remaining(left)));
+        }
+        if let Some(course) = education::active_course(game) {
+            let left = deadline::remaining(game.enrolled_until, now).unwrap_or_default();
+            lines.push(format!(
+                "{}: {}",
+                course.name,
+                deadline::format_remaining(left)
+            ));
+        }
+        if lines.is_empty() {
+            return "Nothing running.".to_string();
+        }
+        lines.join("\n")
+    }
+
+    fn job_text(&self) -> String {
+        let job = job::current(self.ctx.game);
+        format!(
+            "{}\n{} every {}s",
+            job.title,
+            format_money(job.salary_per_cycle),
+            job::PAY_INTERVAL.as_secs()
+        )
+    }
+}
+
+impl Page for DashboardPage<'_> {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        let game = self.ctx.game;
+        let blocks = [
+            ("Vitals", self.vitals_text()),
+            ("Timers", self.timers_text()),
+            ("Job", self.job_text()),
+            ("Wealth", net_worth::breakdown_text(game)),
+            ("Messages", game.inbox.summary_text()),
+            ("Missions", mission::summary_text(game)),
+        ];
+        // Three rows of two side by side, or one column when narrow.
+        let cells: Vec<Rect> = if area.width < ui::STACK_WIDTH {
+            Layout::vertical([Constraint::Ratio(1, 6); 6])
+                .split(area)
+                .to_vec()
+        } else {
+            Layout::vertical([Constraint::Ratio(1, 3); 3])
+                .split(area)
+                .iter()
+                .flat_map(|row| {
+                    Layout::horizontal([Constraint::Ratio(1, 2); 2])
+                        .split(*row)
+                        .to_vec()
+                })
+                .collect()
+        };
+        for ((title, text), cell) in blocks.into_iter().zip(cells) {
+            frame.render_widget(ui::content_box(text, title), cell);
+        }
+    }
+}
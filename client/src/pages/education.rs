@@ -0,0 +1,68 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    widgets::{Block, Borders},
+};
+
+use super::{Page, PageContext};
+use crate::{deadline, education, ui};
+
+pub fn build(ctx: PageContext<'_>) -> Box<dyn Page + '_> {
+    Box::new(EducationPage { ctx })
+}
+
+struct EducationPage<'a> {
+    ctx: PageContext<'a>,
+}
+
+impl Page for EducationPage<'_> {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        let game = self.ctx.game;
+        let (left, right) = ui::split_boxes(area);
+        let [progress_area, list_area] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(0)])
+            .areas(left);
+
+        let (title, (done, total)) = match education::active_course(game) {
+            Some(course) => {
+                let left =
+                    deadline::remaining(game.enrolled_until, self.ctx.now).unwrap_or_default();
+                (
+                    format!(
+                        "{} ({} left)",
+                        course.name,
+                        deadline::format_remaining(left)
+                    ),
+                    education::progress(game, self.ctx.now).unwrap_or((0, 0)),
+                )
+            }
+            None => ("Not enrolled".to_string(), (0, 0)),
+        };
+        let percent = (done * 100).checked_div(total).unwrap_or(0);
+        let gauge = ui::vitals_gauge(&format!("{}%", percent), done, total, &game.theme.theme())
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(gauge, progress_area);
+
+        let offset = self.ctx.scroll.get();
+        let list = ui::render_scrollable(
+            frame,
+            list_area,
+            education::course_list_text(game),
+            "Courses",
+            offset,
+        );
+        let done = ui::render_scrollable(
+            frame,
+            right,
+            education::completed_text(game),
+            "Completed courses",
+            offset,
+        );
+        self.ctx.scroll.set(list.max(done));
+    }
+
+    fn actions(&self) -> &[(&'static str, &'static str)] {
+        &[("e", "enroll"), ("Left/Right", "course")]
+    }
+}
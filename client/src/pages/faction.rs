@@ -0,0 +1,25 @@
+use ratatui::{Frame, layout::Rect};
+
+use super::{Page, PageContext};
+use crate::faction;
+use crate::ui;
+
+pub fn build(ctx: PageContext<'_>) -> Box<dyn Page + '_> {
+    Box::new(FactionPage { ctx })
+}
+
+struct FactionPage<'a> {
+    ctx: PageContext<'a>,
+}
+
+impl Page for FactionPage<'_> {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        ui::render_boxes(
+            frame,
+            area,
+            faction::info_text(self.ctx.game),
+            faction::members_text(self.ctx.game),
+            self.ctx.scroll,
+        );
+    }
+}
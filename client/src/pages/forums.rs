@@ -0,0 +1,29 @@
+use ratatui::{Frame, layout::Rect};
+
+use super::{Page, PageContext};
+use crate::{forums, ui};
+
+pub fn build(ctx: PageContext<'_>) -> Box<dyn Page + '_> {
+    Box::new(ForumsPage { ctx })
+}
+
+struct ForumsPage<'a> {
+    ctx: PageContext<'a>,
+}
+
+impl Page for ForumsPage<'_> {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        let game = self.ctx.game;
+        ui::render_boxes(
+            frame,
+            area,
+            forums::thread_list_text(&game.forums, game.thread_selected),
+            forums::thread_text(&game.forums, game.thread_selected),
+            self.ctx.scroll,
+        );
+    }
+
+    fn actions(&self) -> &[(&'static str, &'static str)] {
+        &[("Left/Right", "thread"), ("reply <text>", "post")]
+    }
+}
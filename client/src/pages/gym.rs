@@ -0,0 +1,30 @@
+use ratatui::{Frame, layout::Rect};
+
+use super::{Page, PageContext};
+use crate::gym;
+use crate::ui;
+
+pub fn build(ctx: PageContext<'_>) -> Box<dyn Page + '_> {
+    Box::new(GymPage { ctx })
+}
+
+struct GymPage<'a> {
+    ctx: PageContext<'a>,
+}
+
+impl Page for GymPage<'_> {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        let game = self.ctx.game;
+        ui::render_boxes(
+            frame,
+            area,
+            gym::training_panel(&game.player, &game.training_log),
+            gym::machines_text(&game.player, game.gym_machine),
+            self.ctx.scroll,
+        );
+    }
+
+    fn actions(&self) -> &[(&'static str, &'static str)] {
+        &[("t", "train"), ("Left/Right", "machine")]
+    }
+}
@@ -0,0 +1,56 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Text},
+    widgets::{Block, Borders, Tabs},
+};
+
+use super::{Page, PageContext};
+use crate::hall_of_fame::{self, Metric};
+use crate::ui;
+
+pub fn build(ctx: PageContext<'_>) -> Box<dyn Page + '_> {
+    Box::new(HallOfFamePage { ctx })
+}
+
+struct HallOfFamePage<'a> {
+    ctx: PageContext<'a>,
+}
+
+impl Page for HallOfFamePage<'_> {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        let game = self.ctx.game;
+        let theme = game.theme.theme();
+        let metric = game.hall_of_fame.metric;
+        let [tabs_area, boxes_area] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(0)])
+            .areas(area);
+
+        let selected = Metric::ALL.iter().position(|&m| m == metric).unwrap_or(0);
+        let tabs = Tabs::new(Metric::ALL.map(Metric::name))
+            .block(Block::default().title("Rank by").borders(Borders::ALL))
+            .highlight_style(theme.highlight)
+            .select(selected);
+        frame.render_widget(tabs, tabs_area);
+
+        let board = hall_of_fame::leaderboard(&hall_of_fame::competitors(game), metric);
+        let rows: Vec<Line> = board
+            .iter()
+            .map(|entry| {
+                let line = Line::from(hall_of_fame::format_entry(entry, metric));
+                if entry.is_player {
+                    line.style(theme.highlight)
+                } else {
+                    line
+                }
+            })
+            .collect();
+        let rank = Text::styled(hall_of_fame::rank_summary(&board, metric), theme.highlight);
+        ui::render_boxes(frame, boxes_area, rows, rank, self.ctx.scroll);
+    }
+
+    fn actions(&self) -> &[(&'static str, &'static str)] {
+        &[("Left/Right", "ranking")]
+    }
+}
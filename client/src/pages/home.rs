@@ -0,0 +1,39 @@
+use ratatui::{Frame, layout::Rect};
+
+use super::{Page, PageContext};
+use crate::daily;
+use crate::decay;
+use crate::net_worth;
+use crate::ui;
+
+pub fn build(ctx: PageContext<'_>) -> Box<dyn Page + '_> {
+    Box::new(HomePage { ctx })
+}
+
+struct HomePage<'a> {
+    ctx: PageContext<'a>,
+}
+
+impl Page for HomePage<'_> {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        let game = self.ctx.game;
+        let mut right = format!(
+            "{}\n\n{}",
+            net_worth::breakdown_text(game),
+            daily::reward_text(game)
+        );
+        if let Some(decay) = decay::recent_text(game) {
+            right = format!("{}\n\n{}", right, decay);
+        }
+        ui::render_boxes(frame, area, game.player.overview(), right, self.ctx.scroll);
+    }
+
+    fn actions(&self) -> &[(&'static str, &'static str)] {
+        let game = self.ctx.game;
+        if game.daily.is_available(game.clock.day()) {
+            &[("c", "claim daily reward")]
+        } else {
+            &[]
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use ratatui::{Frame, layout::Rect};
+
+use super::{Page, PageContext};
+use crate::hospital;
+use crate::ui;
+
+pub fn build(ctx: PageContext<'_>) -> Box<dyn Page + '_> {
+    Box::new(HospitalPage { ctx })
+}
+
+struct HospitalPage<'a> {
+    ctx: PageContext<'a>,
+}
+
+impl Page for HospitalPage<'_> {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        let game = self.ctx.game;
+        let status = hospital::status_text(game, self.ctx.now);
+        let recovery = hospital::recovery_text(game, self.ctx.now);
+        let Some((recovered, total)) = hospital::stay_progress(game, self.ctx.now) else {
+            ui::render_boxes(frame, area, status, recovery, self.ctx.scroll);
+            return;
+        };
+        let gauge = ui::vitals_gauge(
+            &format!("{}s / {}s", recovered, total),
+            recovered,
+            total,
+            &game.theme.theme(),
+        );
+        ui::render_boxes_with_gauge(
+            frame,
+            area,
+            gauge,
+            "Recovery",
+            status,
+            recovery,
+            self.ctx.scroll,
+        );
+    }
+
+    fn actions(&self) -> &[(&'static str, &'static str)] {
+        &[("r", "pay to leave")]
+    }
+}
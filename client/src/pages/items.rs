@@ -0,0 +1,86 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem},
+};
+
+use super::{Page, PageContext};
+use crate::inventory;
+use crate::ui;
+
+pub fn build(ctx: PageContext<'_>) -> Box<dyn Page + '_> {
+    Box::new(ItemsPage { ctx })
+}
+
+struct ItemsPage<'a> {
+    ctx: PageContext<'a>,
+}
+
+impl Page for ItemsPage<'_> {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        let (left, right) = ui::split_boxes(area);
+        let stacks = &self.ctx.game.inventory.items;
+        let theme = self.ctx.game.theme.theme();
+
+        if stacks.is_empty() {
+            frame.render_widget(ui::content_box("You have no items yet.", "Left Box"), left);
+        } else {
+            let items: Vec<ListItem> = stacks
+                .iter()
+                .map(|stack| {
+                    ListItem::new(Line::from(vec![
+                        Span::styled(stack.item.name(), stack.item.rarity().style(&theme)),
+                        Span::raw(format!(" x{}", stack.quantity)),
+                    ]))
+                })
+                .collect();
+            let title = if self.ctx.game.inventory.sort_by_rarity {
+                "Left Box (by rarity)"
+            } else {
+                "Left Box"
+            };
+            let list = List::new(items)
+                .block(Block::default().title(title).borders(Borders::ALL))
+                .highlight_style(theme.highlight)
+                .highlight_symbol("> ");
+            let mut state = self.ctx.list.take();
+            state.select(Some(self.ctx.game.item_selected));
+            frame.render_stateful_widget(list, left, &mut state);
+            self.ctx.list.set(state);
+            if self.ctx.list_focused {
+                ui::render_focus_ring(frame, left, &theme);
+            }
+        }
+
+        let details = inventory::details_text(
+            &self.ctx.game.inventory,
+            self.ctx.game.item_selected,
+            self.ctx.game.item_prompt,
+            self.ctx.game.item_quantity,
+        );
+        let offset =
+            ui::render_scrollable(frame, right, details, "Right Box", self.ctx.scroll.get());
+        self.ctx.scroll.set(offset);
+    }
+
+    fn actions(&self) -> &[(&'static str, &'static str)] {
+        // `s` sells while the prompt is open and sorts otherwise.
+        if self.ctx.game.item_prompt {
+            &[
+                ("e", "use"),
+                ("s", "sell"),
+                ("+/-/a", "quantity"),
+                ("d", "discard"),
+                ("c", "cancel"),
+            ]
+        } else {
+            &[
+                ("Enter", "choose"),
+                ("s", "sort"),
+                ("l", "list"),
+                ("Left/Right", "item"),
+            ]
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use ratatui::{Frame, layout::Rect};
+
+use super::{Page, PageContext};
+use crate::jail;
+use crate::ui;
+
+pub fn build(ctx: PageContext<'_>) -> Box<dyn Page + '_> {
+    Box::new(JailPage { ctx })
+}
+
+struct JailPage<'a> {
+    ctx: PageContext<'a>,
+}
+
+impl Page for JailPage<'_> {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        let game = self.ctx.game;
+        let status = jail::status_text(game, self.ctx.now);
+        let Some((served, total)) = jail::sentence_progress(game, self.ctx.now) else {
+            ui::render_boxes(frame, area, status, jail::escape_text(), self.ctx.scroll);
+            return;
+        };
+        let gauge = ui::vitals_gauge(
+            &format!("{}s / {}s", served, total),
+            served,
+            total,
+            &game.theme.theme(),
+        );
+        ui::render_boxes_with_gauge(
+            frame,
+            area,
+            gauge,
+            "Sentence served",
+            status,
+            jail::escape_text(),
+            self.ctx.scroll,
+        );
+    }
+
+    fn actions(&self) -> &[(&'static str, &'static str)] {
+        &[("e", "escape")]
+    }
+}
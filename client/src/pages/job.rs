@@ -0,0 +1,29 @@
+use ratatui::{Frame, layout::Rect};
+
+use super::{Page, PageContext};
+use crate::job;
+use crate::ui;
+
+pub fn build(ctx: PageContext<'_>) -> Box<dyn Page + '_> {
+    Box::new(JobPage { ctx })
+}
+
+struct JobPage<'a> {
+    ctx: PageContext<'a>,
+}
+
+impl Page for JobPage<'_> {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        ui::render_boxes(
+            frame,
+            area,
+            job::job_summary_text(self.ctx.game),
+            job::task_list_text(self.ctx.game),
+            self.ctx.scroll,
+        );
+    }
+
+    fn actions(&self) -> &[(&'static str, &'static str)] {
+        &[("w", "work"), ("Left/Right", "task")]
+    }
+}
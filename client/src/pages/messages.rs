@@ -0,0 +1,29 @@
+use ratatui::{Frame, layout::Rect};
+
+use super::{Page, PageContext};
+use crate::{inbox, ui};
+
+pub fn build(ctx: PageContext<'_>) -> Box<dyn Page + '_> {
+    Box::new(MessagesPage { ctx })
+}
+
+struct MessagesPage<'a> {
+    ctx: PageContext<'a>,
+}
+
+impl Page for MessagesPage<'_> {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        let game = self.ctx.game;
+        ui::render_boxes(
+            frame,
+            area,
+            inbox::message_list_text(&game.inbox, game.message_selected),
+            inbox::message_text(&game.inbox, game.message_selected),
+            self.ctx.scroll,
+        );
+    }
+
+    fn actions(&self) -> &[(&'static str, &'static str)] {
+        &[("Left/Right", "message")]
+    }
+}
@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use serde::Deserialize;
+
+/// What the menu knows about a page before it's drawn. The table lives in
+/// `pages.json`, so adding or rewording a page doesn't touch any code.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct PageMeta {
+    /// Intro line shown at the top of the Info box.
+    pub info: String,
+    /// Text for the two boxes of a page that has no content of its own yet.
+    pub left: String,
+    pub right: String,
+}
+
+static PAGE_META: LazyLock<HashMap<String, PageMeta>> = LazyLock::new(|| {
+    serde_json::from_str(include_str!("pages.json")).expect("pages.json is valid page metadata")
+});
+
+static UNDER_CONSTRUCTION: LazyLock<PageMeta> = LazyLock::new(|| PageMeta {
+    info: "This page is under construction.".to_string(),
+    left: "Left Box".to_string(),
+    right: "Right Box".to_string(),
+});
+
+/// The metadata for the page called `name`, or an "under construction" entry
+/// for a page that isn't listed.
+pub fn meta(name: &str) -> &'static PageMeta {
+    PAGE_META.get(name).unwrap_or(&UNDER_CONSTRUCTION)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::PAGES;
+
+    #[test]
+    fn every_page_has_metadata() {
+        for page in PAGES {
+            assert!(PAGE_META.contains_key(page), "pages.json has no {}", page);
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use ratatui::{Frame, layout::Rect};
+
+use super::{Page, PageContext};
+use crate::mission;
+use crate::ui;
+
+pub fn build(ctx: PageContext<'_>) -> Box<dyn Page + '_> {
+    Box::new(MissionsPage { ctx })
+}
+
+struct MissionsPage<'a> {
+    ctx: PageContext<'a>,
+}
+
+impl Page for MissionsPage<'_> {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        ui::render_boxes(
+            frame,
+            area,
+            mission::active_text(self.ctx.game),
+            mission::completed_text(&self.ctx.game.missions),
+            self.ctx.scroll,
+        );
+    }
+}
@@ -0,0 +1,134 @@
+//! One module per menu page. Each page knows how to draw its own content, so
+//! adding a page means writing a `Page` impl and listing it in `REGISTRY`.
+//! Intro text lives in `pages.json`; a page listed there but not in
+//! `REGISTRY` is drawn from its metadata alone.
+
+mod achievements;
+mod bank;
+mod calendar;
+mod casino;
+mod city;
+mod crimes;
+mod dashboard;
+mod education;
+mod faction;
+mod forums;
+mod gym;
+mod hall_of_fame;
+mod home;
+mod hospital;
+mod items;
+mod jail;
+mod job;
+mod messages;
+mod meta;
+mod missions;
+mod newspaper;
+mod placeholder;
+mod properties;
+mod recruit;
+mod rules;
+mod settings;
+
+use std::cell::Cell;
+use std::time::Instant;
+
+use ratatui::{Frame, layout::Rect, style::Style, text::Text, widgets::ListState};
+
+use crate::state::GameState;
+use crate::ui;
+
+pub trait Page {
+    /// Draws the page's content boxes into `area`.
+    fn render(&self, frame: &mut Frame, area: Rect);
+
+    /// The page's own keys and what they do, for the legend under its
+    /// content. Pages without any leave it empty and get no legend.
+    fn actions(&self) -> &[(&'static str, &'static str)] {
+        &[]
+    }
+}
+
+/// What a page gets to look at when it's built for a frame.
+#[derive(Clone, Copy)]
+pub struct PageContext<'a> {
+    pub game: &'a GameState,
+    pub now: Instant,
+    /// This page's scroll offset. Rendering clamps it to the content length.
+    pub scroll: &'a Cell<u16>,
+    /// Scroll position of this page's list, if it has one. Pages put their
+    /// selection into it before drawing so the highlighted row stays in
+    /// view.
+    pub list: &'a Cell<ListState>,
+    /// Whether keys are going to that list, which lights up its border.
+    pub list_focused: bool,
+}
+
+type Builder = for<'a> fn(PageContext<'a>) -> Box<dyn Page + 'a>;
+
+const REGISTRY: &[(&str, Builder)] = &[
+    ("Home", home::build),
+    ("Dashboard", dashboard::build),
+    ("Items", items::build),
+    ("Job", job::build),
+    ("City", city::build),
+    ("Bank", bank::build),
+    ("Gym", gym::build),
+    ("Properties", properties::build),
+    ("Education", education::build),
+    ("Crimes", crimes::build),
+    ("Missions", missions::build),
+    ("Newspaper", newspaper::build),
+    ("Messages", messages::build),
+    ("Jail", jail::build),
+    ("Hospital", hospital::build),
+    ("Casino", casino::build),
+    ("Forums", forums::build),
+    ("Hall of Fame", hall_of_fame::build),
+    ("Faction", faction::build),
+    ("Recruit Citizens", recruit::build),
+    ("Calendar", calendar::build),
+    ("Rules", rules::build),
+    ("Achievements", achievements::build),
+    ("Settings", settings::build),
+];
+
+/// Builds the page called `name`. Pages the player is locked out of show why
+/// instead, and unknown names fall back to an "under construction" page.
+pub fn build<'a>(name: &str, ctx: PageContext<'a>) -> Box<dyn Page + 'a> {
+    if let Some(reason) = ctx.game.blocked_reason(name, ctx.now) {
+        let style = ctx.game.theme.theme().error;
+        return Box::new(Blocked { reason, style });
+    }
+
+    if let Some((_, builder)) = REGISTRY.iter().find(|(page, _)| *page == name) {
+        return builder(ctx);
+    }
+    placeholder::build(name, ctx)
+}
+
+/// Whether the page called `name` has a list the arrow keys can be handed
+/// to; see `App::move_list`.
+pub fn has_list(name: &str) -> bool {
+    matches!(name, "Items" | "City")
+}
+
+/// Intro line for the Info box on the page called `name`.
+pub fn info(name: &str, game: &GameState, now: Instant) -> &'static str {
+    if game.blocked_reason(name, now).is_some() {
+        return "You can't do that right now.";
+    }
+    &meta::meta(name).info
+}
+
+struct Blocked {
+    reason: &'static str,
+    style: Style,
+}
+
+impl Page for Blocked {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        let reason = Text::styled(self.reason, self.style);
+        frame.render_widget(ui::content_box(reason, "Blocked"), area);
+    }
+}
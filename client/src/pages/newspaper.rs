@@ -0,0 +1,29 @@
+use ratatui::{Frame, layout::Rect};
+
+use super::{Page, PageContext};
+use crate::{news, ui};
+
+pub fn build(ctx: PageContext<'_>) -> Box<dyn Page + '_> {
+    Box::new(NewspaperPage { ctx })
+}
+
+struct NewspaperPage<'a> {
+    ctx: PageContext<'a>,
+}
+
+impl Page for NewspaperPage<'_> {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        let news = &self.ctx.game.news;
+        ui::render_boxes(
+            frame,
+            area,
+            news::headlines_text(news),
+            format!(
+                "Newest first. Keeping {} of the last {} headlines (times in UTC).",
+                news.len(),
+                news::NEWS_LOG_LEN
+            ),
+            self.ctx.scroll,
+        );
+    }
+}
@@ -0,0 +1,30 @@
+use ratatui::{Frame, layout::Rect};
+
+use super::meta::{self, PageMeta};
+use super::{Page, PageContext};
+use crate::ui;
+
+/// A page with nothing behind it yet but its description.
+struct PlaceholderPage<'a> {
+    meta: &'static PageMeta,
+    ctx: PageContext<'a>,
+}
+
+impl Page for PlaceholderPage<'_> {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        ui::render_boxes(
+            frame,
+            area,
+            self.meta.left.as_str(),
+            self.meta.right.as_str(),
+            self.ctx.scroll,
+        );
+    }
+}
+
+pub fn build<'a>(name: &str, ctx: PageContext<'a>) -> Box<dyn Page + 'a> {
+    Box::new(PlaceholderPage {
+        meta: meta::meta(name),
+        ctx,
+    })
+}
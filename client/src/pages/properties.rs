@@ -0,0 +1,30 @@
+use ratatui::{Frame, layout::Rect};
+
+use super::{Page, PageContext};
+use crate::property;
+use crate::ui;
+
+pub fn build(ctx: PageContext<'_>) -> Box<dyn Page + '_> {
+    Box::new(PropertiesPage { ctx })
+}
+
+struct PropertiesPage<'a> {
+    ctx: PageContext<'a>,
+}
+
+impl Page for PropertiesPage<'_> {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        let game = self.ctx.game;
+        ui::render_boxes(
+            frame,
+            area,
+            property::owned_text(&game.real_estate),
+            property::market_text(&game.real_estate, game.property_selected, game.player.level),
+            self.ctx.scroll,
+        );
+    }
+
+    fn actions(&self) -> &[(&'static str, &'static str)] {
+        &[("b", "buy"), ("s", "sell"), ("Left/Right", "property")]
+    }
+}
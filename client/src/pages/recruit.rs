@@ -0,0 +1,30 @@
+use ratatui::{Frame, layout::Rect};
+
+use super::{Page, PageContext};
+use crate::referral;
+use crate::ui;
+
+pub fn build(ctx: PageContext<'_>) -> Box<dyn Page + '_> {
+    Box::new(RecruitPage { ctx })
+}
+
+struct RecruitPage<'a> {
+    ctx: PageContext<'a>,
+}
+
+impl Page for RecruitPage<'_> {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        let referral = &self.ctx.game.referral;
+        ui::render_boxes(
+            frame,
+            area,
+            referral::code_text(referral),
+            referral::recruits_text(referral),
+            self.ctx.scroll,
+        );
+    }
+
+    fn actions(&self) -> &[(&'static str, &'static str)] {
+        &[("c", "claim rewards")]
+    }
+}
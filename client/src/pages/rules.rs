@@ -0,0 +1,34 @@
+use ratatui::{Frame, layout::Rect};
+
+use super::{Page, PageContext};
+use crate::rules;
+use crate::ui;
+
+pub fn build(ctx: PageContext<'_>) -> Box<dyn Page + '_> {
+    Box::new(RulesPage { ctx })
+}
+
+struct RulesPage<'a> {
+    ctx: PageContext<'a>,
+}
+
+impl Page for RulesPage<'_> {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        let reports = &self.ctx.game.reports;
+        ui::render_boxes(
+            frame,
+            area,
+            rules::rules_text(reports),
+            rules::report_text(reports),
+            self.ctx.scroll,
+        );
+    }
+
+    fn actions(&self) -> &[(&'static str, &'static str)] {
+        &[
+            ("Left/Right", "rule"),
+            ("t", "target"),
+            ("r", "file report"),
+        ]
+    }
+}
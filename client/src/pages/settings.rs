@@ -0,0 +1,38 @@
+use ratatui::{Frame, layout::Rect};
+
+use super::{Page, PageContext};
+use crate::settings;
+use crate::ui;
+
+pub fn build(ctx: PageContext<'_>) -> Box<dyn Page + '_> {
+    Box::new(SettingsPage { ctx })
+}
+
+struct SettingsPage<'a> {
+    ctx: PageContext<'a>,
+}
+
+impl Page for SettingsPage<'_> {
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        let game = self.ctx.game;
+        let selected = game.setting_selected;
+        ui::render_boxes(
+            frame,
+            area,
+            settings::settings_text(&game.settings, selected),
+            format!(
+                "{}\n\nLeft / Right: choose a setting\n+ / -: change it\nr: start a new game",
+                selected.description()
+            ),
+            self.ctx.scroll,
+        );
+    }
+
+    fn actions(&self) -> &[(&'static str, &'static str)] {
+        &[
+            ("Left/Right", "setting"),
+            ("+/-", "change"),
+            ("r", "new game"),
+        ]
+    }
+}
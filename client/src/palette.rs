@@ -0,0 +1,134 @@
+//! The Ctrl-P command palette: every command, plus a `goto` for every page,
+//! searchable by a few letters of its name. It's built from `COMMANDS`, the
+//! same table behind `help` and the help overlay, so it never falls behind.
+
+use std::cmp::Reverse;
+
+use crossterm::event::KeyCode;
+
+use crate::command::COMMANDS;
+use crate::state::PAGES;
+
+/// One thing the palette can run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// As listed, e.g. `deposit <amount|all>`.
+    pub label: String,
+    pub description: String,
+}
+
+impl Entry {
+    /// The command line for this entry up to its first argument the player
+    /// has to fill in, and whether that's the whole command.
+    fn line(&self) -> (String, bool) {
+        let mut words = Vec::new();
+        for word in self.label.split_whitespace() {
+            if word.contains(['<', '[', '|']) {
+                return (format!("{} ", words.join(" ")), false);
+            }
+            words.push(word);
+        }
+        (words.join(" "), true)
+    }
+}
+
+/// Every entry, commands first and then the pages.
+pub fn entries() -> Vec<Entry> {
+    let commands = COMMANDS.iter().map(|(usage, description)| Entry {
+        label: usage.to_string(),
+        description: description.to_string(),
+    });
+    let pages = PAGES.iter().map(|page| Entry {
+        label: format!("goto {}", page),
+        description: format!("Open the {} page", page),
+    });
+    commands.chain(pages).collect()
+}
+
+/// How well `query` matches `text` as a subsequence, ignoring case and the
+/// query's spaces; higher is better. Letters in a row and letters starting
+/// a word score extra, and an early first match beats a late one.
+fn score(query: &str, text: &str) -> Option<usize> {
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+    let mut score: usize = 0;
+    let mut from = 0;
+    let mut first = None;
+    let mut last = None;
+    for c in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let found = from + text[from..].iter().position(|&t| t == c)?;
+        score += 1;
+        if last.is_some_and(|last| found == last + 1) {
+            score += 4;
+        }
+        if found == 0 || text[found - 1] == ' ' {
+            score += 2;
+        }
+        first.get_or_insert(found);
+        last = Some(found);
+        from = found + 1;
+    }
+    Some((score * 100).saturating_sub(first.unwrap_or(0)))
+}
+
+/// What picking an entry does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Pick {
+    /// Run this command line now.
+    Run(String),
+    /// Put this in the Input box for the player to finish.
+    Fill(String),
+    Close,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Palette {
+    pub query: String,
+    /// Index into `matches`.
+    pub selected: usize,
+}
+
+impl Palette {
+    /// The entries matching the query, best first. An empty query lists
+    /// them all in order.
+    pub fn matches(&self) -> Vec<Entry> {
+        let mut scored: Vec<(usize, Entry)> = entries()
+            .into_iter()
+            .filter_map(|entry| {
+                let on_label = score(&self.query, &entry.label).map(|score| score * 2);
+                let on_description = score(&self.query, &entry.description);
+                Some((on_label.max(on_description)?, entry))
+            })
+            .collect();
+        // Stable, so equal scores keep the table's order.
+        scored.sort_by_key(|(score, _)| Reverse(*score));
+        scored.into_iter().map(|(_, entry)| entry).collect()
+    }
+
+    pub fn handle_key(&mut self, code: KeyCode) -> Option<Pick> {
+        match code {
+            KeyCode::Esc => return Some(Pick::Close),
+            KeyCode::Enter => {
+                let entry = self.matches().into_iter().nth(self.selected)?;
+                return Some(match entry.line() {
+                    (line, true) => Pick::Run(line),
+                    (line, false) => Pick::Fill(line),
+                });
+            }
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down => {
+                let last = self.matches().len().saturating_sub(1);
+                self.selected = (self.selected + 1).min(last);
+            }
+            KeyCode::Backspace => {
+                self.query.pop();
+                self.selected = 0;
+            }
+            KeyCode::Char(c) if !c.is_control() => {
+                self.query.push(c);
+                self.selected = 0;
+            }
+            _ => {}
+        }
+        None
+    }
+}
@@ -0,0 +1,159 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::money::format_money;
+
+/// Game-clock time it takes to regenerate a single point of energy.
+pub const ENERGY_REGEN_INTERVAL: Duration = Duration::from_secs(30);
+/// Health that comes back along with each point of energy.
+pub const HEALTH_REGEN_POINTS: u32 = 5;
+
+/// Core character stats shown on the Home page and used by the action pages.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlayerStats {
+    pub name: String,
+    pub strength: u32,
+    pub speed: u32,
+    pub defense: u32,
+    pub dexterity: u32,
+    pub money: u64,
+    pub level: u32,
+    /// Lifetime experience; see `level::xp_for_level`.
+    pub experience: u64,
+    pub energy: u32,
+    pub max_energy: u32,
+    /// Lost in fights; zero means a trip to the hospital.
+    pub health: u32,
+    pub max_health: u32,
+    pub happiness: u32,
+    /// Learned from Education courses.
+    pub skills: Vec<String>,
+}
+
+impl Default for PlayerStats {
+    fn default() -> Self {
+        Self {
+            name: "Player".to_string(),
+            strength: 10,
+            speed: 10,
+            defense: 10,
+            dexterity: 10,
+            money: 500,
+            level: 1,
+            experience: 0,
+            energy: 100,
+            max_energy: 100,
+            health: 100,
+            max_health: 100,
+            happiness: 100,
+            skills: Vec::new(),
+        }
+    }
+}
+
+impl PlayerStats {
+    /// Multi-line summary with labels and values lined up in two columns.
+    pub fn overview(&self) -> String {
+        let rows = [
+            ("Level", self.level.to_string()),
+            ("Experience", self.experience.to_string()),
+            ("Money", format_money(self.money)),
+            ("Energy", format!("{}/{}", self.energy, self.max_energy)),
+            ("Health", format!("{}/{}", self.health, self.max_health)),
+            ("Happiness", self.happiness.to_string()),
+            ("Strength", self.strength.to_string()),
+            ("Speed", self.speed.to_string()),
+            ("Defense", self.defense.to_string()),
+            ("Dexterity", self.dexterity.to_string()),
+        ];
+
+        rows.iter()
+            .map(|(label, value)| format!("{:<10} {:>10}", label, value))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Deducts `cost` energy, or leaves it untouched and returns false if the
+    /// player can't afford it.
+    pub fn spend_energy(&mut self, cost: u32) -> bool {
+        match self.energy.checked_sub(cost) {
+            Some(left) => {
+                self.energy = left;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn stat(&self, stat: Stat) -> u32 {
+        match stat {
+            Stat::Strength => self.strength,
+            Stat::Speed => self.speed,
+            Stat::Defense => self.defense,
+            Stat::Dexterity => self.dexterity,
+        }
+    }
+
+    pub fn stat_mut(&mut self, stat: Stat) -> &mut u32 {
+        match stat {
+            Stat::Strength => &mut self.strength,
+            Stat::Speed => &mut self.speed,
+            Stat::Defense => &mut self.defense,
+            Stat::Dexterity => &mut self.dexterity,
+        }
+    }
+
+    /// Hands out `points` of energy, and `HEALTH_REGEN_POINTS` of health for
+    /// each, once per `ENERGY_REGEN_INTERVAL`. Returns true if anything was
+    /// restored.
+    pub fn regenerate(&mut self, points: u32) -> bool {
+        if self.energy >= self.max_energy && self.health >= self.max_health {
+            return false;
+        }
+        self.restore_energy(points);
+        self.restore_health(points.saturating_mul(HEALTH_REGEN_POINTS));
+        true
+    }
+
+    /// Adds energy without ever going past `max_energy`.
+    pub fn restore_energy(&mut self, amount: u32) {
+        self.energy = self.energy.saturating_add(amount).min(self.max_energy);
+    }
+
+    /// Adds health without ever going past `max_health`.
+    pub fn restore_health(&mut self, amount: u32) {
+        self.health = self.health.saturating_add(amount).min(self.max_health);
+    }
+}
+
+/// A trainable battle stat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum Stat {
+    #[default]
+    Strength,
+    Speed,
+    Defense,
+    Dexterity,
+}
+
+impl Stat {
+    pub const ALL: [Stat; 4] = [Stat::Strength, Stat::Speed, Stat::Defense, Stat::Dexterity];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Stat::Strength => "strength",
+            Stat::Speed => "speed",
+            Stat::Defense => "defense",
+            Stat::Dexterity => "dexterity",
+        }
+    }
+
+    /// Case-insensitive lookup by name, e.g. `"Strength"` or `"speed"`.
+    pub fn parse(name: &str) -> Option<Stat> {
+        Stat::ALL
+            .into_iter()
+            .find(|stat| stat.name().eq_ignore_ascii_case(name))
+    }
+}
@@ -0,0 +1,220 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::market;
+use crate::money::format_money;
+use crate::player::PlayerStats;
+use crate::state::GameState;
+
+/// Share of today's price returned when selling a property back.
+pub const RESALE_PERCENT: u64 = 70;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Property {
+    pub name: String,
+    /// Today's price; see `market`.
+    pub price: u64,
+    #[serde(default)]
+    pub base_price: u64,
+    /// Yesterday's price, for the trend arrow.
+    #[serde(default)]
+    pub previous_price: u64,
+    /// Paid out at the start of every game day while owned.
+    pub daily_income: u64,
+    /// Level the player must reach before buying. Saves from before level
+    /// requirements don't have one, so anything goes there.
+    #[serde(default)]
+    pub min_level: u32,
+    pub owned: bool,
+}
+
+impl Property {
+    fn listing(name: &str, price: u64, daily_income: u64, min_level: u32) -> Property {
+        Property {
+            name: name.to_string(),
+            price,
+            base_price: price,
+            previous_price: price,
+            daily_income,
+            min_level,
+            owned: false,
+        }
+    }
+
+    /// What selling it back pays at today's price.
+    pub fn resale_price(&self) -> u64 {
+        self.price * RESALE_PERCENT / 100
+    }
+}
+
+/// Every property in town, owned or not. Saved whole so ownership survives
+/// a restart.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RealEstate {
+    pub properties: Vec<Property>,
+}
+
+impl Default for RealEstate {
+    fn default() -> Self {
+        Self {
+            properties: vec![
+                Property::listing("Trailer", 400, 5, 1),
+                Property::listing("Studio flat", 1_500, 20, 2),
+                Property::listing("Townhouse", 6_000, 90, 4),
+                Property::listing("Beach villa", 25_000, 400, 6),
+            ],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PropertyError {
+    NoSuchProperty,
+    AlreadyOwned,
+    NotOwned,
+    NotEnoughMoney { price: u64 },
+    LevelTooLow { required: u32 },
+}
+
+impl fmt::Display for PropertyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PropertyError::NoSuchProperty => write!(f, "No such property"),
+            PropertyError::AlreadyOwned => write!(f, "You already own that property"),
+            PropertyError::NotOwned => write!(f, "You don't own that property"),
+            PropertyError::NotEnoughMoney { price } => {
+                write!(f, "Not enough money: that costs {}", format_money(*price))
+            }
+            PropertyError::LevelTooLow { required } => write!(f, "Requires level {}", required),
+        }
+    }
+}
+
+impl RealEstate {
+    /// Buys the property at `index`. Nothing changes if the purchase is
+    /// rejected.
+    pub fn buy(
+        &mut self,
+        index: usize,
+        stats: &mut PlayerStats,
+    ) -> Result<&Property, PropertyError> {
+        let property = self
+            .properties
+            .get_mut(index)
+            .ok_or(PropertyError::NoSuchProperty)?;
+        if property.owned {
+            return Err(PropertyError::AlreadyOwned);
+        }
+        if stats.level < property.min_level {
+            return Err(PropertyError::LevelTooLow {
+                required: property.min_level,
+            });
+        }
+        if stats.money < property.price {
+            return Err(PropertyError::NotEnoughMoney {
+                price: property.price,
+            });
+        }
+
+        stats.money -= property.price;
+        property.owned = true;
+        Ok(property)
+    }
+
+    /// Sells the property at `index` back for `RESALE_PERCENT` of its price.
+    /// Returns what was paid.
+    pub fn sell(&mut self, index: usize, stats: &mut PlayerStats) -> Result<u64, PropertyError> {
+        let property = self
+            .properties
+            .get_mut(index)
+            .ok_or(PropertyError::NoSuchProperty)?;
+        if !property.owned {
+            return Err(PropertyError::NotOwned);
+        }
+
+        property.owned = false;
+        let paid = property.resale_price();
+        stats.money += paid;
+        Ok(paid)
+    }
+
+    pub fn daily_income(&self) -> u64 {
+        self.owned().map(|property| property.daily_income).sum()
+    }
+
+    pub fn owned(&self) -> impl Iterator<Item = &Property> {
+        self.properties.iter().filter(|property| property.owned)
+    }
+}
+
+/// Pays out a day's income from everything owned. Returns true if anything
+/// was paid.
+pub fn collect_daily_income(state: &mut GameState) -> bool {
+    let income = state.real_estate.daily_income();
+    if income == 0 {
+        return false;
+    }
+    state.player.money += income;
+    state.log_news(format!(
+        "Collected {} in property income for day {}.",
+        format_money(income),
+        state.clock.day()
+    ));
+    true
+}
+
+pub fn owned_text(estate: &RealEstate) -> String {
+    let lines: Vec<String> = estate
+        .owned()
+        .map(|property| {
+            format!(
+                "{:<14} +{}/day  (sells for {})",
+                property.name,
+                format_money(property.daily_income),
+                format_money(property.resale_price())
+            )
+        })
+        .collect();
+    if lines.is_empty() {
+        return "You don't own any property yet.".to_string();
+    }
+    format!(
+        "{}\n\nTotal income: {}/day",
+        lines.join("\n"),
+        format_money(estate.daily_income())
+    )
+}
+
+pub fn market_text(estate: &RealEstate, selected: usize, level: u32) -> String {
+    let mut lines: Vec<String> = estate
+        .properties
+        .iter()
+        .enumerate()
+        .map(|(i, property)| {
+            let marker = if i == selected { ">" } else { " " };
+            let status = if property.owned {
+                "owned".to_string()
+            } else if level < property.min_level {
+                format!("level {}", property.min_level)
+            } else {
+                String::new()
+            };
+            format!(
+                "{} {:<14} {:<9} {:<7} +{:<6}/day {}",
+                marker,
+                property.name,
+                format_money(property.price),
+                market::trend_text(property.price, property.previous_price),
+                format_money(property.daily_income),
+                status
+            )
+        })
+        .collect();
+    lines.push(String::new());
+    lines.push(format!(
+        "b: buy  s: sell back for {}% of today's price",
+        RESALE_PERCENT
+    ));
+    lines.join("\n")
+}
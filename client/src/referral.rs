@@ -0,0 +1,153 @@
+use std::fmt;
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::money::format_money;
+use crate::state::GameState;
+
+/// Money paid once for each player who joins with the code.
+pub const RECRUIT_REWARD: u64 = 250;
+/// Characters after the `RUSTY-` prefix. Letters and digits that are easy to
+/// tell apart when read aloud or copied by hand.
+const CODE_LEN: usize = 6;
+const CODE_CHARS: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+
+/// The player's referral code and who has joined with it.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Referral {
+    /// Empty until the Recruit Citizens page is first opened.
+    pub code: String,
+    pub recruits: Vec<String>,
+    /// Recruits whose reward has been paid out.
+    pub rewards_claimed: Vec<String>,
+}
+
+impl Referral {
+    /// Recruits still waiting to be claimed, oldest first.
+    pub fn claimable(&self) -> impl Iterator<Item = &str> {
+        self.recruits
+            .iter()
+            .filter(|name| !self.rewards_claimed.contains(name))
+            .map(String::as_str)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReferralError {
+    /// Nobody can have joined with a code the player hasn't been given.
+    NoCode,
+    EmptyName,
+    AlreadyRecruited(String),
+    NothingToClaim,
+}
+
+impl fmt::Display for ReferralError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReferralError::NoCode => {
+                write!(f, "Open Recruit Citizens to get a referral code first")
+            }
+            ReferralError::EmptyName => write!(f, "Recruits need a name"),
+            ReferralError::AlreadyRecruited(name) => write!(f, "{} already joined", name),
+            ReferralError::NothingToClaim => write!(f, "No rewards to claim"),
+        }
+    }
+}
+
+/// A fresh code such as `RUSTY-7KQ2MD`.
+pub fn generate_code(rng: &mut impl Rng) -> String {
+    let suffix: String = (0..CODE_LEN)
+        .map(|_| char::from(CODE_CHARS[rng.random_range(0..CODE_CHARS.len())]))
+        .collect();
+    format!("RUSTY-{}", suffix)
+}
+
+/// Gives the player a code if they don't have one yet. Returns true if one
+/// was made.
+pub fn ensure_code(state: &mut GameState, rng: &mut impl Rng) -> bool {
+    if !state.referral.code.is_empty() {
+        return false;
+    }
+    state.referral.code = generate_code(rng);
+    true
+}
+
+/// Records `name` as having joined with the player's code. There's no server
+/// to hear about real sign-ups yet, so this is only reached through the
+/// `recruit` debug command.
+pub fn add_recruit(state: &mut GameState, name: &str) -> Result<(), ReferralError> {
+    if state.referral.code.is_empty() {
+        return Err(ReferralError::NoCode);
+    }
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(ReferralError::EmptyName);
+    }
+    let referral = &mut state.referral;
+    if referral
+        .recruits
+        .iter()
+        .any(|recruit| recruit.eq_ignore_ascii_case(name))
+    {
+        return Err(ReferralError::AlreadyRecruited(name.to_string()));
+    }
+    referral.recruits.push(name.to_string());
+    Ok(())
+}
+
+/// Pays `RECRUIT_REWARD` for every recruit not yet claimed for. Each recruit
+/// pays exactly once, however often this is called. Returns how many were
+/// claimed and the total paid.
+pub fn claim_rewards(state: &mut GameState) -> Result<(usize, u64), ReferralError> {
+    let claimable: Vec<String> = state.referral.claimable().map(String::from).collect();
+    if claimable.is_empty() {
+        return Err(ReferralError::NothingToClaim);
+    }
+    let paid = RECRUIT_REWARD * claimable.len() as u64;
+    state.player.money += paid;
+    state
+        .referral
+        .rewards_claimed
+        .extend(claimable.iter().cloned());
+    state.log_news(format!(
+        "Paid {} in referral rewards for {}.",
+        format_money(paid),
+        claimable.join(", ")
+    ));
+    Ok((claimable.len(), paid))
+}
+
+pub fn code_text(referral: &Referral) -> String {
+    format!(
+        "Your referral code\n\n  {}\n\nShare it with friends. You get {} for everyone who joins with it.",
+        referral.code,
+        format_money(RECRUIT_REWARD)
+    )
+}
+
+pub fn recruits_text(referral: &Referral) -> String {
+    if referral.recruits.is_empty() {
+        return "No recruits yet.".to_string();
+    }
+    let rows: Vec<String> = referral
+        .recruits
+        .iter()
+        .map(|name| {
+            let status = if referral.rewards_claimed.contains(name) {
+                "claimed".to_string()
+            } else {
+                format!("{} to claim", format_money(RECRUIT_REWARD))
+            };
+            format!("{:<16} {}", name, status)
+        })
+        .collect();
+    let waiting = referral.claimable().count();
+    format!(
+        "Recruits\n{}\n\n{} reward(s) waiting: {}",
+        rows.join("\n"),
+        waiting,
+        format_money(RECRUIT_REWARD * waiting as u64)
+    )
+}
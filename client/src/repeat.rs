@@ -0,0 +1,139 @@
+//! Hold-to-repeat for grinding: holding the Gym's train key or the Crimes
+//! page's crime key keeps doing it. Terminals send a held key as a stream of
+//! presses (or repeat events, where keyboard enhancement is on), so a press
+//! of the same key soon after the last one counts as held. Repeats are rate
+//! limited, and a run ends when the key goes quiet or an action fails, with
+//! one toast summing up the whole run.
+
+use std::time::{Duration, Instant};
+
+use crate::crime::CRIMES;
+use crate::gym::MACHINES;
+use crate::money::format_money;
+use crate::player::PlayerStats;
+
+/// Fastest a held key repeats its action.
+pub const REPEAT_INTERVAL: Duration = Duration::from_millis(200);
+/// Terminals repeat a held key faster than this, so a longer gap between
+/// presses means it was let go.
+const RELEASE_GAP: Duration = Duration::from_millis(600);
+
+/// An action that can be repeated by holding its key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Grind {
+    /// Train on this Gym machine.
+    Train(usize),
+    /// Commit this crime.
+    Crime(usize),
+}
+
+#[derive(Debug)]
+struct Run {
+    grind: Grind,
+    /// The player as they were before the run's first action.
+    before: PlayerStats,
+    count: u32,
+    last_action: Instant,
+}
+
+#[derive(Debug, Default)]
+pub struct Repeater {
+    run: Option<Run>,
+    /// The last grind key pressed and when.
+    last_press: Option<(Grind, Instant)>,
+    /// A grind whose run was cut short; the rest of that hold is ignored.
+    halted: Option<Grind>,
+}
+
+impl Repeater {
+    /// Called for each press of a grind key, before acting on it; `repeat`
+    /// is whether the terminal flagged it as a key repeat. Returns whether
+    /// to act, which is false while a held key is going faster than
+    /// `REPEAT_INTERVAL` or after its run was cut short.
+    pub fn press(
+        &mut self,
+        grind: Grind,
+        repeat: bool,
+        player: &PlayerStats,
+        now: Instant,
+    ) -> bool {
+        let held = repeat
+            || self
+                .last_press
+                .is_some_and(|(last, at)| last == grind && now.duration_since(at) < RELEASE_GAP);
+        self.last_press = Some((grind, now));
+        if !held {
+            self.halted = None;
+        } else if self.halted == Some(grind) {
+            return false;
+        }
+        match &mut self.run {
+            Some(run) if held && run.grind == grind => {
+                if now.duration_since(run.last_action) < REPEAT_INTERVAL {
+                    return false;
+                }
+                run.count += 1;
+                run.last_action = now;
+            }
+            _ => {
+                self.run = Some(Run {
+                    grind,
+                    before: player.clone(),
+                    count: 1,
+                    last_action: now,
+                });
+            }
+        }
+        true
+    }
+
+    /// Whether the last action was a repeat rather than a fresh press.
+    pub fn is_repeating(&self) -> bool {
+        self.run.as_ref().is_some_and(|run| run.count > 1)
+    }
+
+    /// Ends the run after a failed action, ignoring the key until it's let
+    /// go. Returns the summary if it had repeated.
+    pub fn halt(&mut self, player: &PlayerStats) -> Option<String> {
+        let run = self.run.take()?;
+        self.halted = Some(run.grind);
+        summary(&run, player)
+    }
+
+    /// Ends the run once its key has been let go. Returns the summary if it
+    /// had repeated.
+    pub fn expire(&mut self, player: &PlayerStats, now: Instant) -> Option<String> {
+        let released = self
+            .last_press
+            .is_some_and(|(_, at)| now.duration_since(at) >= RELEASE_GAP);
+        if !released {
+            return None;
+        }
+        summary(&self.run.take()?, player)
+    }
+}
+
+/// What a run did in total, by comparing the player before and after.
+fn summary(run: &Run, player: &PlayerStats) -> Option<String> {
+    if run.count < 2 {
+        return None;
+    }
+    Some(match run.grind {
+        Grind::Train(machine) => {
+            let stat = MACHINES[machine].stat;
+            format!(
+                "Trained {} times on the {}: {} +{}",
+                run.count,
+                MACHINES[machine].name,
+                stat.name(),
+                player.stat(stat).saturating_sub(run.before.stat(stat))
+            )
+        }
+        Grind::Crime(crime) => format!(
+            "{} x{}: +{}",
+            CRIMES[crime].name,
+            run.count,
+            format_money(player.money.saturating_sub(run.before.money))
+        ),
+    })
+}
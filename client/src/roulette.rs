@@ -0,0 +1,304 @@
+use std::fmt;
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::casino::BETS;
+use crate::money::format_money;
+use crate::player::PlayerStats;
+
+/// A single-zero wheel: 0 to 36.
+pub const POCKETS: u8 = 37;
+
+/// The red numbers; every other number but 0 is black.
+const RED: [u8; 18] = [
+    1, 3, 5, 7, 9, 12, 14, 16, 18, 19, 21, 23, 25, 27, 30, 32, 34, 36,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Black,
+    Green,
+}
+
+impl Color {
+    pub fn name(self) -> &'static str {
+        match self {
+            Color::Red => "Red",
+            Color::Black => "Black",
+            Color::Green => "Green",
+        }
+    }
+}
+
+pub fn color_of(number: u8) -> Color {
+    if number == 0 {
+        Color::Green
+    } else if RED.contains(&number) {
+        Color::Red
+    } else {
+        Color::Black
+    }
+}
+
+/// What a chip is riding on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BetKind {
+    Number(u8),
+    #[default]
+    Red,
+    Black,
+    Odd,
+    Even,
+}
+
+impl BetKind {
+    /// The bet type after this one, for the Casino page's `t` key.
+    pub fn next(self) -> BetKind {
+        match self {
+            BetKind::Red => BetKind::Black,
+            BetKind::Black => BetKind::Odd,
+            BetKind::Odd => BetKind::Even,
+            BetKind::Even => BetKind::Number(0),
+            BetKind::Number(_) => BetKind::Red,
+        }
+    }
+
+    /// Parses `red`, `black`, `odd`, `even` or a number from 0 to 36.
+    pub fn parse(text: &str) -> Option<BetKind> {
+        match text.to_lowercase().as_str() {
+            "red" => Some(BetKind::Red),
+            "black" => Some(BetKind::Black),
+            "odd" => Some(BetKind::Odd),
+            "even" => Some(BetKind::Even),
+            number => number
+                .parse()
+                .ok()
+                .filter(|&n| n < POCKETS)
+                .map(BetKind::Number),
+        }
+    }
+
+    pub fn wins(self, number: u8) -> bool {
+        match self {
+            BetKind::Number(n) => n == number,
+            BetKind::Red => color_of(number) == Color::Red,
+            BetKind::Black => color_of(number) == Color::Black,
+            // Zero is neither odd nor even as far as the house is concerned.
+            BetKind::Odd => number != 0 && number % 2 == 1,
+            BetKind::Even => number != 0 && number.is_multiple_of(2),
+        }
+    }
+
+    /// Winnings per unit staked, on top of the stake: 35 to 1 on a single
+    /// number, even money on everything else.
+    pub fn odds(self) -> u64 {
+        match self {
+            BetKind::Number(_) => 35,
+            _ => 1,
+        }
+    }
+}
+
+impl fmt::Display for BetKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BetKind::Number(n) => write!(f, "Number {}", n),
+            BetKind::Red => write!(f, "Red"),
+            BetKind::Black => write!(f, "Black"),
+            BetKind::Odd => write!(f, "Odd"),
+            BetKind::Even => write!(f, "Even"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bet {
+    pub kind: BetKind,
+    pub amount: u64,
+}
+
+impl Bet {
+    /// What comes back to the player if the ball lands on `number`, stake
+    /// included.
+    pub fn payout(self, number: u8) -> u64 {
+        if self.kind.wins(number) {
+            self.amount * (self.kind.odds() + 1)
+        } else {
+            0
+        }
+    }
+}
+
+/// How a spin went across every bet on the slip.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpinResult {
+    pub number: u8,
+    pub staked: u64,
+    pub returned: u64,
+}
+
+impl SpinResult {
+    pub fn net(&self) -> i64 {
+        self.returned as i64 - self.staked as i64
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RouletteError {
+    /// The slip plus the new bet would come to more than the player has.
+    NotEnoughMoney {
+        total: u64,
+    },
+    EmptySlip,
+}
+
+impl fmt::Display for RouletteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RouletteError::NotEnoughMoney { total } => {
+                write!(f, "You can't cover {} in bets", format_money(*total))
+            }
+            RouletteError::EmptySlip => write!(f, "Place a bet first"),
+        }
+    }
+}
+
+/// Table state. Like blackjack, only the chip size and the running total are
+/// saved; bets on the slip haven't been paid for yet, so nothing is lost if
+/// they're dropped on quit.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Roulette {
+    /// Index into `casino::BETS`.
+    pub bet: usize,
+    pub net: i64,
+    /// What the page's `a` key will bet on.
+    #[serde(skip)]
+    pub kind: BetKind,
+    #[serde(skip)]
+    slip: Vec<Bet>,
+    #[serde(skip)]
+    last: Option<SpinResult>,
+}
+
+impl Roulette {
+    pub fn bet_amount(&self) -> u64 {
+        BETS[self.bet.min(BETS.len() - 1)]
+    }
+
+    pub fn raise_bet(&mut self) {
+        self.bet = (self.bet + 1).min(BETS.len() - 1);
+    }
+
+    pub fn lower_bet(&mut self) {
+        self.bet = self.bet.saturating_sub(1);
+    }
+
+    /// Moves the number being bet on up or down the wheel, wrapping past 36.
+    pub fn step_number(&mut self, up: bool) {
+        if let BetKind::Number(n) = self.kind {
+            let step = if up { 1 } else { POCKETS - 1 };
+            self.kind = BetKind::Number((n + step) % POCKETS);
+        }
+    }
+
+    pub fn staked(&self) -> u64 {
+        self.slip.iter().map(|bet| bet.amount).sum()
+    }
+
+    /// Adds a bet to the slip. Nothing is paid until the spin, but the slip
+    /// can never total more than the player could pay for.
+    pub fn place(&mut self, bet: Bet, stats: &PlayerStats) -> Result<(), RouletteError> {
+        let total = self.staked() + bet.amount;
+        if total > stats.money {
+            return Err(RouletteError::NotEnoughMoney { total });
+        }
+        self.slip.push(bet);
+        Ok(())
+    }
+
+    pub fn clear_slip(&mut self) {
+        self.slip.clear();
+    }
+
+    /// Takes every stake on the slip, spins and pays out the winners.
+    pub fn spin(
+        &mut self,
+        stats: &mut PlayerStats,
+        rng: &mut impl Rng,
+    ) -> Result<SpinResult, RouletteError> {
+        if self.slip.is_empty() {
+            return Err(RouletteError::EmptySlip);
+        }
+        let staked = self.staked();
+        // Money may have been spent elsewhere since the bets went down.
+        if staked > stats.money {
+            return Err(RouletteError::NotEnoughMoney { total: staked });
+        }
+
+        let number = rng.random_range(0..POCKETS);
+        let returned = self.slip.drain(..).map(|bet| bet.payout(number)).sum();
+        stats.money = stats.money - staked + returned;
+        let result = SpinResult {
+            number,
+            staked,
+            returned,
+        };
+        self.net += result.net();
+        self.last = Some(result);
+        Ok(result)
+    }
+}
+
+fn signed_money(amount: i64) -> String {
+    if amount < 0 {
+        format!("-{}", format_money(amount.unsigned_abs()))
+    } else {
+        format!("+{}", format_money(amount.unsigned_abs()))
+    }
+}
+
+pub fn table_text(table: &Roulette) -> String {
+    let wheel = match table.last {
+        Some(spin) => format!("[ {:>2} {} ]", spin.number, color_of(spin.number).name()),
+        None => "[ -- ]".to_string(),
+    };
+    format!(
+        "Roulette\n\n{}\n\nNext bet: {} for {}\n\n\
+         t: bet type  + / -: number\np: place  x: clear  s: spin\n\
+         Left/Right: change amount\nb: back to slots",
+        wheel,
+        table.kind,
+        format_money(table.bet_amount())
+    )
+}
+
+pub fn slip_text(table: &Roulette) -> String {
+    let mut lines = vec!["Bet slip".to_string()];
+    if table.slip.is_empty() {
+        lines.push("  (empty)".to_string());
+    }
+    lines.extend(table.slip.iter().map(|bet| {
+        format!(
+            "  {:<10} {:<7} pays {}:1",
+            bet.kind.to_string(),
+            format_money(bet.amount),
+            bet.kind.odds()
+        )
+    }));
+    lines.push(format!("Total: {}", format_money(table.staked())));
+    lines.push(String::new());
+    lines.push(match table.last {
+        Some(spin) => format!(
+            "Last spin: staked {}, paid {} ({})",
+            format_money(spin.staked),
+            format_money(spin.returned),
+            signed_money(spin.net())
+        ),
+        None => "No spins yet".to_string(),
+    });
+    lines.push(format!("Win/loss: {}", signed_money(table.net)));
+    lines.join("\n")
+}
@@ -0,0 +1,309 @@
+use std::cmp::Reverse;
+use std::fmt;
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::fight::TARGETS;
+use crate::state::GameState;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Minor,
+    Major,
+    Severe,
+}
+
+impl Severity {
+    pub fn label(self) -> &'static str {
+        match self {
+            Severity::Minor => "minor",
+            Severity::Major => "major",
+            Severity::Severe => "severe",
+        }
+    }
+
+    /// Chance in percent that a report under a rule this serious is upheld.
+    /// Moderators look harder at the worse accusations.
+    fn uphold_chance(self) -> u32 {
+        match self {
+            Severity::Minor => 40,
+            Severity::Major => 60,
+            Severity::Severe => 80,
+        }
+    }
+}
+
+pub struct Rule {
+    pub id: &'static str,
+    pub text: &'static str,
+    pub severity: Severity,
+}
+
+pub const RULES: &[Rule] = &[
+    Rule {
+        id: "R1",
+        text: "Be civil in messages and the forums.",
+        severity: Severity::Minor,
+    },
+    Rule {
+        id: "R2",
+        text: "No spamming or advertising other games.",
+        severity: Severity::Minor,
+    },
+    Rule {
+        id: "R3",
+        text: "One account per player.",
+        severity: Severity::Major,
+    },
+    Rule {
+        id: "R4",
+        text: "Don't trade money for real-world goods.",
+        severity: Severity::Major,
+    },
+    Rule {
+        id: "R5",
+        text: "Don't impersonate staff.",
+        severity: Severity::Major,
+    },
+    Rule {
+        id: "R6",
+        text: "No scripts, bots or macros.",
+        severity: Severity::Severe,
+    },
+    Rule {
+        id: "R7",
+        text: "Report bugs instead of exploiting them.",
+        severity: Severity::Severe,
+    },
+    Rule {
+        id: "R8",
+        text: "Never ask for another player's password.",
+        severity: Severity::Severe,
+    },
+];
+
+pub fn find_rule(id: &str) -> Option<&'static Rule> {
+    RULES.iter().find(|rule| rule.id.eq_ignore_ascii_case(id))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ReportStatus {
+    #[default]
+    Pending,
+    Upheld,
+    Dismissed,
+}
+
+impl ReportStatus {
+    pub fn label(self) -> &'static str {
+        match self {
+            ReportStatus::Pending => "pending",
+            ReportStatus::Upheld => "upheld",
+            ReportStatus::Dismissed => "dismissed",
+        }
+    }
+}
+
+/// A report filed against one of the City's NPCs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+    pub target: String,
+    /// `Rule::id` of the rule broken.
+    pub rule: String,
+    /// Game day it was filed on; it's reviewed on a later one.
+    pub day: u64,
+    pub status: ReportStatus,
+}
+
+/// Reports the player has filed, and the report form on the Rules page.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Reports {
+    pub filed: Vec<Report>,
+    /// Index into `RULES` of the rule the form will report.
+    #[serde(skip)]
+    pub rule_selected: usize,
+    /// Index into `fight::TARGETS` of who the form will report.
+    #[serde(skip)]
+    pub target_selected: usize,
+}
+
+impl Reports {
+    pub fn select_rule(&mut self, forward: bool) {
+        self.rule_selected = step(self.rule_selected, RULES.len(), forward);
+    }
+
+    pub fn select_target(&mut self, forward: bool) {
+        self.target_selected = step(self.target_selected, TARGETS.len(), forward);
+    }
+
+    /// How many upheld reports each rule has, most broken first. Rules
+    /// nobody has been caught breaking are left out.
+    pub fn most_broken(&self) -> Vec<(&'static Rule, usize)> {
+        let mut tally: Vec<(&'static Rule, usize)> = RULES
+            .iter()
+            .map(|rule| {
+                let count = self
+                    .filed
+                    .iter()
+                    .filter(|report| {
+                        report.status == ReportStatus::Upheld && report.rule == rule.id
+                    })
+                    .count();
+                (rule, count)
+            })
+            .filter(|&(_, count)| count > 0)
+            .collect();
+        // A stable sort keeps rules with the same count in rule-book order.
+        tally.sort_by_key(|&(_, count)| Reverse(count));
+        tally
+    }
+}
+
+fn step(index: usize, len: usize, forward: bool) -> usize {
+    if forward {
+        (index + 1) % len
+    } else {
+        (index + len - 1) % len
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReportError {
+    UnknownRule(String),
+    UnknownTarget(String),
+    /// The same NPC already has a pending report under the same rule.
+    AlreadyReported,
+}
+
+impl fmt::Display for ReportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReportError::UnknownRule(id) => write!(f, "No rule '{}'", id),
+            ReportError::UnknownTarget(name) => write!(f, "Nobody called '{}'", name),
+            ReportError::AlreadyReported => write!(f, "That report is already being looked at"),
+        }
+    }
+}
+
+/// Files a report against the NPC called `target` for breaking `rule_id`.
+pub fn file(state: &mut GameState, target: &str, rule_id: &str) -> Result<(), ReportError> {
+    let rule = find_rule(rule_id).ok_or_else(|| ReportError::UnknownRule(rule_id.to_string()))?;
+    let npc = TARGETS
+        .iter()
+        .find(|npc| npc.name.eq_ignore_ascii_case(target))
+        .ok_or_else(|| ReportError::UnknownTarget(target.to_string()))?;
+    let duplicate = state.reports.filed.iter().any(|report| {
+        report.status == ReportStatus::Pending
+            && report.target == npc.name
+            && report.rule == rule.id
+    });
+    if duplicate {
+        return Err(ReportError::AlreadyReported);
+    }
+    let day = state.clock.day();
+    state.reports.filed.push(Report {
+        target: npc.name.to_string(),
+        rule: rule.id.to_string(),
+        day,
+        status: ReportStatus::Pending,
+    });
+    Ok(())
+}
+
+/// Files the report the Rules page's form is filled in with.
+pub fn file_selected(state: &mut GameState) -> Result<(), ReportError> {
+    let rule = RULES[state.reports.rule_selected].id;
+    let target = TARGETS[state.reports.target_selected].name;
+    file(state, target, rule)
+}
+
+/// Decides every report filed before today, upholding each with a chance
+/// that rises with the rule's severity. Called on a new day; returns how many
+/// were decided.
+pub fn review(state: &mut GameState, rng: &mut impl Rng) -> usize {
+    let today = state.clock.day();
+    let mut decided = Vec::new();
+    for report in &mut state.reports.filed {
+        if report.status != ReportStatus::Pending || report.day >= today {
+            continue;
+        }
+        let chance = find_rule(&report.rule).map_or(0, |rule| rule.severity.uphold_chance());
+        report.status = if rng.random_range(0..100) < chance {
+            ReportStatus::Upheld
+        } else {
+            ReportStatus::Dismissed
+        };
+        decided.push(format!(
+            "Your report on the {} ({}) was {}.",
+            report.target.to_lowercase(),
+            report.rule,
+            report.status.label()
+        ));
+    }
+    let count = decided.len();
+    for line in decided {
+        state.log_news(line);
+    }
+    count
+}
+
+pub fn rules_text(reports: &Reports) -> String {
+    let rows: Vec<String> = RULES
+        .iter()
+        .enumerate()
+        .map(|(i, rule)| {
+            let marker = if i == reports.rule_selected { ">" } else { " " };
+            format!(
+                "{} {} [{}] {}",
+                marker,
+                rule.id,
+                rule.severity.label(),
+                rule.text
+            )
+        })
+        .collect();
+    let tally = reports.most_broken();
+    let tally_text = if tally.is_empty() {
+        "  No upheld reports yet.".to_string()
+    } else {
+        tally
+            .iter()
+            .map(|(rule, count)| format!("  {} x{}  {}", rule.id, count, rule.text))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    format!(
+        "Rules\n{}\n\nMost broken rules\n{}",
+        rows.join("\n"),
+        tally_text
+    )
+}
+
+pub fn report_text(reports: &Reports) -> String {
+    let rule = &RULES[reports.rule_selected];
+    let target = TARGETS[reports.target_selected].name;
+    let form = format!(
+        "Report form\n  Against: {}\n  Rule:    {} {}",
+        target, rule.id, rule.text
+    );
+    if reports.filed.is_empty() {
+        return format!("{}\n\nNo reports filed.", form);
+    }
+    let rows: Vec<String> = reports
+        .filed
+        .iter()
+        .rev()
+        .map(|report| {
+            format!(
+                "  Day {:<3} {:<14} {}  {}",
+                report.day,
+                report.target,
+                report.rule,
+                report.status.label()
+            )
+        })
+        .collect();
+    format!("{}\n\nYour reports\n{}", form, rows.join("\n"))
+}
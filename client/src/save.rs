@@ -0,0 +1,344 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Instant, SystemTime};
+
+use serde_json::Value;
+
+use crate::error::{Result, RustyError};
+use crate::logger;
+use crate::state::GameState;
+
+/// Slots are numbered from 1 up to this.
+pub const MAX_SLOTS: u32 = 9;
+/// Written into every save as `version`. Bump it when a change to
+/// `GameState` could stop older saves from loading as they are; those go
+/// through `migrate`. Saves from before there was a version are version 1.
+pub const SAVE_VERSION: u64 = 2;
+
+/// `~/.rusty`, or `./.rusty` when `HOME` isn't set. Saves, the key config
+/// and the debug log all live here.
+pub fn save_dir() -> PathBuf {
+    let home = std::env::var_os("HOME")
+        .map(PathBuf::from)
+        .unwrap_or_default();
+    home.join(".rusty")
+}
+
+/// `save<slot>.json` in `dir`.
+pub fn slot_path(dir: &Path, slot: u32) -> PathBuf {
+    dir.join(format!("save{}.json", slot))
+}
+
+/// What the slot picker shows for one save.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlotInfo {
+    pub slot: u32,
+    /// Character level, or `None` if the save couldn't be read.
+    pub level: Option<u32>,
+    /// When the file was last written.
+    pub last_played: Option<SystemTime>,
+}
+
+/// The saves in `dir`, lowest slot first. A save from before slots existed
+/// is moved into slot 1 first, as long as that slot is free.
+pub fn list_slots(dir: &Path) -> Vec<SlotInfo> {
+    let legacy = dir.join("save.json");
+    let first = slot_path(dir, 1);
+    if legacy.exists() && !first.exists() {
+        let _ = fs::rename(&legacy, &first);
+    }
+    (1..=MAX_SLOTS)
+        .filter_map(|slot| {
+            let path = slot_path(dir, slot);
+            let meta = fs::metadata(&path).ok()?;
+            Some(SlotInfo {
+                slot,
+                level: load_game(&path).ok().map(|state| state.player.level),
+                last_played: meta.modified().ok(),
+            })
+        })
+        .collect()
+}
+
+/// The lowest slot with no save in it, or `None` when they're all taken.
+pub fn free_slot(slots: &[SlotInfo]) -> Option<u32> {
+    (1..=MAX_SLOTS).find(|slot| slots.iter().all(|info| info.slot != *slot))
+}
+
+/// Writes the state to a sibling temp file first and renames it over the
+/// real save, so a crash mid-write never leaves a truncated save behind.
+pub fn save_game(state: &GameState, path: &Path) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let json = to_json(state)?;
+    let tmp = path.with_extension("json.tmp");
+    {
+        let mut file = fs::File::create(&tmp)?;
+        file.write_all(json.as_bytes())?;
+        file.sync_all()?;
+    }
+    fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+/// Writes the state as pretty JSON to `path`, for sharing in bug reports.
+/// It's a plain copy: the save slots are left alone, and loading it back
+/// means copying it over a slot's file.
+pub fn export_game(state: &GameState, path: &Path) -> Result<()> {
+    let json = to_json(state)?;
+    fs::write(path, json)?;
+    Ok(())
+}
+
+/// Deletes the save at `path`. A save that was never written counts as
+/// deleted.
+pub fn delete_save(path: &Path) -> Result<()> {
+    match fs::remove_file(path) {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err.into()),
+        _ => Ok(()),
+    }
+}
+
+/// `state` as pretty JSON, stamped with `SAVE_VERSION`.
+fn to_json(state: &GameState) -> Result<String> {
+    let mut value = serde_json::to_value(state)?;
+    if let Value::Object(fields) = &mut value {
+        fields.insert("version".to_string(), SAVE_VERSION.into());
+    }
+    Ok(serde_json::to_string_pretty(&value)?)
+}
+
+/// Reads the save at `path`, bringing older versions up to date. A save
+/// from a newer version is refused rather than half read, and a current one
+/// with a field that won't read goes through `migrate` as well rather than
+/// being lost whole.
+pub fn load_game(path: &Path) -> Result<GameState> {
+    let json = fs::read_to_string(path)?;
+    let value: Value = serde_json::from_str(&json)?;
+    let version = value.get("version").and_then(Value::as_u64).unwrap_or(1);
+    if version > SAVE_VERSION {
+        return Err(RustyError::NewerSave(version));
+    }
+    let mut state = if version < SAVE_VERSION {
+        logger::info(format!(
+            "Migrating {} from save version {}",
+            path.display(),
+            version
+        ));
+        migrate(value)?
+    } else {
+        match serde_json::from_value(value.clone()) {
+            Ok(state) => state,
+            Err(err) => {
+                logger::warn(format!("Couldn't read {} as is: {}", path.display(), err));
+                migrate(value)?
+            }
+        }
+    };
+    state.player.energy = state.player.energy.min(state.player.max_energy);
+    state.settings.sanitize_pages();
+    Ok(state)
+}
+
+/// Turns an older save into the current `GameState`. Its fields are laid
+/// over a new game's, and if that doesn't read, each is tried on its own
+/// over a new game so the ones that no longer fit can be left at their
+/// defaults and logged. One changed field costs that field rather than the
+/// whole save.
+pub fn migrate(old: Value) -> Result<GameState> {
+    let Value::Object(old) = old else {
+        return Err(RustyError::Game("the save isn't a JSON object".to_string()));
+    };
+    let Value::Object(defaults) = serde_json::to_value(GameState::default())? else {
+        return Err(RustyError::Game(
+            "a new game isn't a JSON object".to_string(),
+        ));
+    };
+    let mut fields = defaults.clone();
+    fields.extend(old.clone());
+    if let Ok(state) = serde_json::from_value(Value::Object(fields)) {
+        return Ok(state);
+    }
+    let mut fields = defaults.clone();
+    for (name, value) in old {
+        let mut alone = defaults.clone();
+        alone.insert(name.clone(), value.clone());
+        if serde_json::from_value::<GameState>(Value::Object(alone)).is_ok() {
+            fields.insert(name, value);
+        } else {
+            logger::warn(format!(
+                "Couldn't read '{}' from the old save; reset it",
+                name
+            ));
+        }
+    }
+    Ok(serde_json::from_value(Value::Object(fields))?)
+}
+
+/// Moves the unreadable save at `path` to `save<N>.json.bad` beside it, so
+/// the new game started in its place doesn't autosave over it.
+pub fn set_aside(path: &Path) -> Result<PathBuf> {
+    let bad = path.with_extension("json.bad");
+    fs::rename(path, &bad)?;
+    Ok(bad)
+}
+
+/// Saves in the background every `Settings::autosave_interval` of real
+/// time, paused or not, skipping the write when nothing has changed since
+/// the last save.
+#[derive(Debug)]
+pub struct Autosave {
+    /// When the last save was made, manual or automatic.
+    saved_at: Instant,
+    /// The state as last written, or `None` if it has never been saved.
+    saved: Option<GameState>,
+}
+
+impl Autosave {
+    /// `saved` is the state just loaded from disk, if there was one.
+    pub fn new(saved: Option<GameState>, now: Instant) -> Self {
+        Self {
+            saved_at: now,
+            saved,
+        }
+    }
+
+    /// Records a save made outside the autosave, so the next one waits a
+    /// full interval and compares against it.
+    pub fn mark_saved(&mut self, state: &GameState, now: Instant) {
+        self.saved_at = now;
+        self.saved = Some(state.clone());
+    }
+
+    /// Writes `state` to `path` once the interval has passed. Returns the
+    /// result when a write was attempted, and `None` when it wasn't due or
+    /// there was nothing new to save.
+    pub fn tick(&mut self, state: &GameState, path: &Path, now: Instant) -> Option<Result<()>> {
+        if now.saturating_duration_since(self.saved_at) < state.settings.autosave_interval() {
+            return None;
+        }
+        self.saved_at = now;
+        if self.is_saved(state) {
+            return None;
+        }
+        let result = save_game(state, path);
+        if result.is_ok() {
+            self.saved = Some(state.clone());
+        }
+        Some(result)
+    }
+
+    /// Whether `state` matches the last save, not counting the clock, which
+    /// moves on whether or not anything happened.
+    fn is_saved(&self, state: &GameState) -> bool {
+        self.saved.as_ref().is_some_and(|saved| {
+            let state = GameState {
+                clock: saved.clock,
+                ..state.clone()
+            };
+            state == *saved
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::App;
+    use crate::state::PAGES;
+
+    /// A file in the temp dir for one test to write a save to.
+    fn temp_save(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("rusty-{}-{}.json", std::process::id(), name))
+    }
+
+    fn load_json(name: &str, json: &str) -> Result<GameState> {
+        let path = temp_save(name);
+        fs::write(&path, json).unwrap();
+        let loaded = load_game(&path);
+        let _ = fs::remove_file(&path);
+        loaded
+    }
+
+    #[test]
+    fn loads_a_version_1_save() {
+        // No version, no cooldowns, and a crime count that's text.
+        let json = r#"{
+            "player": { "name": "Old Timer", "money": 1234, "level": 4 },
+            "crime_xp": "lots",
+            "crimes_committed": 9,
+            "fights_won": 2
+        }"#;
+        let state = load_json("v1", json).unwrap();
+        assert_eq!(state.player.name, "Old Timer");
+        assert_eq!(state.player.money, 1234);
+        assert_eq!(state.player.level, 4);
+        assert_eq!(state.crime_xp, 0);
+        assert_eq!(state.crimes_committed, 9);
+        assert_eq!(state.fights_won, 2);
+        assert_eq!(state.cooldowns, GameState::default().cooldowns);
+    }
+
+    #[test]
+    fn current_save_with_a_bad_field_keeps_the_rest() {
+        let json = format!(
+            r#"{{ "version": {}, "fights_won": -3, "casino_wins": 5 }}"#,
+            SAVE_VERSION
+        );
+        let state = load_json("bad-field", &json).unwrap();
+        assert_eq!(state.fights_won, 0);
+        assert_eq!(state.casino_wins, 5);
+    }
+
+    #[test]
+    fn a_save_hiding_every_page_still_has_a_menu() {
+        let hidden: Vec<String> = PAGES
+            .iter()
+            .map(|page| format!("\"{}\"", page.to_lowercase()))
+            .chain(["\"Nowhere\"".to_string()])
+            .collect();
+        let json = format!(
+            r#"{{ "version": {}, "settings": {{ "menu_order": ["Bank", "Nowhere", "Bank"], "hidden_pages": [{}] }} }}"#,
+            SAVE_VERSION,
+            hidden.join(", ")
+        );
+        let state = load_json("all-hidden", &json).unwrap();
+        assert!(state.settings.hidden_pages.is_empty());
+        assert_eq!(state.settings.menu_order.len(), PAGES.len());
+        assert_eq!(state.settings.menu_order[0], "Bank");
+        let app = App::new(state);
+        assert_eq!(app.current_page(), "Bank");
+    }
+
+    #[test]
+    fn drops_unknown_hidden_pages() {
+        let json = format!(
+            r#"{{ "version": {}, "settings": {{ "hidden_pages": ["gym", "Nowhere", "Gym"] }} }}"#,
+            SAVE_VERSION
+        );
+        let state = load_json("unknown-hidden", &json).unwrap();
+        assert_eq!(state.settings.hidden_pages, ["Gym"]);
+        assert!(!state.settings.menu_items().contains(&"Gym"));
+    }
+
+    #[test]
+    fn refuses_a_newer_save() {
+        let err = load_json("v3", r#"{ "version": 3 }"#).unwrap_err();
+        assert!(matches!(err, RustyError::NewerSave(3)));
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let mut state = GameState::default();
+        state.player.name = "Round Trip".to_string();
+        state.player.money = 98_765;
+        state.crime_xp = 12;
+        let json = to_json(&state).unwrap();
+        let value: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["version"], SAVE_VERSION);
+        assert_eq!(load_json("round-trip", &json).unwrap(), state);
+    }
+}
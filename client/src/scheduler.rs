@@ -0,0 +1,187 @@
+//! One place for everything that happens on a timer. Systems register here
+//! and the main loop drains whatever has come due each tick, so nothing else
+//! keeps its own timestamps. Times are game-clock `Duration`s passed in by
+//! the caller, which keeps the scheduler free of any real clock.
+
+use std::time::Duration;
+
+use crate::clock::MINUTES_PER_DAY;
+use crate::job::PAY_INTERVAL;
+
+/// A game minute, in game-clock time.
+const GAME_MINUTE: Duration = Duration::from_secs(1);
+
+/// Something the main loop does when its time comes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TickEvent {
+    /// A point of energy, and some health, comes back.
+    EnergyRegen,
+    /// The job pays a salary.
+    Payday,
+    /// Midnight: interest, decay, property income and the like.
+    NewDay,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+    event: TickEvent,
+    interval: Duration,
+    /// For daily events, how far past midnight they fire.
+    time_of_day: Option<Duration>,
+    next: Duration,
+}
+
+impl Entry {
+    /// When this fires first after `now`, starting afresh.
+    fn first_after(&self, now: Duration) -> Duration {
+        match self.time_of_day {
+            Some(at) => {
+                let day = self.interval.as_secs();
+                let midnight = Duration::from_secs(now.as_secs() / day * day);
+                if midnight + at > now {
+                    midnight + at
+                } else {
+                    midnight + self.interval + at
+                }
+            }
+            None => now + self.interval,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Scheduler {
+    entries: Vec<Entry>,
+    /// The time of the last `due` call.
+    now: Duration,
+}
+
+impl Scheduler {
+    pub fn new(now: Duration) -> Self {
+        Self {
+            entries: Vec::new(),
+            now,
+        }
+    }
+
+    /// The game's timed systems, counting from `now`, with energy coming
+    /// back once per `regen_interval`.
+    pub fn standard(now: Duration, regen_interval: Duration) -> Self {
+        let mut scheduler = Self::new(now);
+        scheduler.every(regen_interval, TickEvent::EnergyRegen);
+        scheduler.every(PAY_INTERVAL, TickEvent::Payday);
+        scheduler.daily(0, TickEvent::NewDay);
+        scheduler
+    }
+
+    /// Fires `event` once per `interval`, the first time one interval from
+    /// now.
+    pub fn every(&mut self, interval: Duration, event: TickEvent) {
+        self.add(event, interval, None);
+    }
+
+    /// Fires `event` every game day at `minute` past midnight.
+    pub fn daily(&mut self, minute: u64, event: TickEvent) {
+        let day = GAME_MINUTE * MINUTES_PER_DAY as u32;
+        let at = GAME_MINUTE * (minute % MINUTES_PER_DAY) as u32;
+        self.add(event, day, Some(at));
+    }
+
+    fn add(&mut self, event: TickEvent, interval: Duration, time_of_day: Option<Duration>) {
+        let mut entry = Entry {
+            event,
+            interval,
+            time_of_day,
+            next: Duration::ZERO,
+        };
+        entry.next = entry.first_after(self.now);
+        self.entries.push(entry);
+    }
+
+    /// When `event` next fires, or `None` if it isn't scheduled.
+    pub fn next(&self, event: TickEvent) -> Option<Duration> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.event == event)
+            .map(|entry| entry.next)
+            .min()
+    }
+
+    /// Every event due by `now`, oldest first. An event that came due more
+    /// than once since the last call is listed once per time. If the clock
+    /// went backwards, as it does when the game is reset, everything is
+    /// rescheduled from `now` instead.
+    pub fn due(&mut self, now: Duration) -> Vec<TickEvent> {
+        if now < self.now {
+            for entry in &mut self.entries {
+                entry.next = entry.first_after(now);
+            }
+        }
+        self.now = now;
+        let mut due = Vec::new();
+        for entry in &mut self.entries {
+            while entry.next <= now {
+                due.push((entry.next, entry.event));
+                entry.next += entry.interval;
+            }
+        }
+        due.sort_by_key(|(at, _)| *at);
+        due.into_iter().map(|(_, event)| event).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::GameClock;
+
+    use TickEvent::{EnergyRegen, NewDay, Payday};
+
+    const REGEN: Duration = Duration::from_secs(30);
+
+    #[test]
+    fn drains_each_time_an_event_came_due_in_order() {
+        let mut clock = GameClock::default();
+        let mut scheduler = Scheduler::standard(clock.elapsed(), REGEN);
+        clock.advance(Duration::from_secs(20));
+        assert!(scheduler.due(clock.elapsed()).is_empty());
+        clock.advance(Duration::from_secs(70));
+        assert_eq!(
+            scheduler.due(clock.elapsed()),
+            [EnergyRegen, EnergyRegen, Payday, EnergyRegen]
+        );
+        assert!(scheduler.due(clock.elapsed()).is_empty());
+        assert_eq!(scheduler.next(Payday), Some(Duration::from_secs(120)));
+    }
+
+    #[test]
+    fn new_day_comes_at_midnight() {
+        let mut clock = GameClock::default();
+        clock.advance(Duration::from_secs(1400));
+        let mut scheduler = Scheduler::standard(clock.elapsed(), REGEN);
+        clock.advance(Duration::from_secs(60));
+        assert_eq!(
+            scheduler.due(clock.elapsed()),
+            [EnergyRegen, NewDay, EnergyRegen, Payday]
+        );
+        assert_eq!(scheduler.next(NewDay), Some(Duration::from_secs(2880)));
+    }
+
+    #[test]
+    fn reschedules_when_the_clock_goes_backwards() {
+        let mut clock = GameClock::default();
+        let mut scheduler = Scheduler::standard(clock.elapsed(), REGEN);
+        clock.advance(Duration::from_secs(1000));
+        assert!(!scheduler.due(clock.elapsed()).is_empty());
+
+        // A reset starts the clock over.
+        let mut clock = GameClock::default();
+        clock.advance(Duration::from_secs(100));
+        assert!(scheduler.due(clock.elapsed()).is_empty());
+        assert_eq!(scheduler.next(EnergyRegen), Some(Duration::from_secs(130)));
+        assert_eq!(scheduler.next(Payday), Some(Duration::from_secs(160)));
+        assert_eq!(scheduler.next(NewDay), Some(Duration::from_secs(1440)));
+        clock.advance(Duration::from_secs(30));
+        assert_eq!(scheduler.due(clock.elapsed()), [EnergyRegen]);
+    }
+}
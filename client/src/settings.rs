@@ -0,0 +1,634 @@
+use std::ops::RangeInclusive;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::money;
+use crate::state::PAGES;
+
+/// Milliseconds the loop may wait for input before redrawing. Zero would
+/// spin the CPU, and much past a second makes the clock look stuck.
+pub const TICK_RATE_RANGE: RangeInclusive<u64> = 10..=1000;
+/// Seconds between autosaves.
+pub const AUTOSAVE_RANGE: RangeInclusive<u64> = 30..=3600;
+/// Reversible actions that can be taken back in a row. Zero turns undo off.
+pub const UNDO_DEPTH_RANGE: RangeInclusive<u64> = 0..=10;
+/// Daily stat decay in tenths of a percent: 0.1% to 5%.
+pub const DECAY_RATE_RANGE: RangeInclusive<u64> = 1..=50;
+/// Seconds without input before the demo starts, when it's on.
+pub const DEMO_IDLE_RANGE: RangeInclusive<u64> = 30..=600;
+/// Share of the player's cash, in percent, a single buy or bet can spend
+/// before it's asked about. 100 never asks.
+pub const CONFIRM_SPEND_RANGE: RangeInclusive<u64> = 10..=100;
+/// How far one press of `+` or `-` on the Settings page moves each value.
+const TICK_RATE_STEP: u64 = 10;
+const AUTOSAVE_STEP: u64 = 30;
+const UNDO_DEPTH_STEP: u64 = 1;
+const DECAY_RATE_STEP: u64 = 1;
+const DEMO_IDLE_STEP: u64 = 30;
+const CONFIRM_SPEND_STEP: u64 = 10;
+/// Thousands separators `+` and `-` step through on the Settings page, by
+/// the name `set separator` knows each by.
+const SEPARATORS: [(&str, &str); 5] = [
+    ("comma", ","),
+    ("dot", "."),
+    ("space", " "),
+    ("apostrophe", "'"),
+    ("none", ""),
+];
+/// Longest currency symbol `set currency` takes, in characters.
+const SYMBOL_MAX_LEN: usize = 3;
+
+/// User preferences, saved along with the game.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Up on the first menu item jumps to the last, and Down on the last
+    /// back to the first. Off clamps at the ends instead.
+    pub wrap_navigation: bool,
+    /// See `TICK_RATE_RANGE`.
+    pub tick_rate_ms: u64,
+    /// See `AUTOSAVE_RANGE`.
+    pub autosave_secs: u64,
+    /// Whether the app takes the mouse. Off hands it back to the terminal,
+    /// so text can be selected and copied.
+    pub mouse_capture: bool,
+    /// See `UNDO_DEPTH_RANGE`.
+    pub undo_depth: u64,
+    /// Stats left untrained for a day lose a little. Off by default.
+    pub stat_decay: bool,
+    /// See `DECAY_RATE_RANGE`.
+    pub decay_rate_permille: u64,
+    /// Slide the content across when switching pages. Off switches at once.
+    pub page_transitions: bool,
+    /// Holding the Gym's train key or the crime key keeps going; see
+    /// `repeat`. Off by default, since it makes grinding easy.
+    pub hold_to_repeat: bool,
+    /// Seconds without input before the demo starts; see `demo`. Zero, the
+    /// default, turns it off, and anything else is kept in
+    /// `DEMO_IDLE_RANGE`.
+    pub demo_idle_secs: u64,
+    /// See `CONFIRM_SPEND_RANGE`.
+    pub confirm_spend_percent: u64,
+    /// Ring the terminal bell for level-ups, jail and achievements; see
+    /// `bell`. Off by default.
+    pub bell: bool,
+    /// Keeps the bell silent without turning it off, for when it'd bother
+    /// someone.
+    pub quiet: bool,
+    /// Written before every amount of money; see `money`.
+    pub currency_symbol: String,
+    /// Written between each group of three digits in amounts of money.
+    pub thousands_separator: String,
+    /// Every page in the order the menu lists them, hidden ones included so
+    /// they come back where they were.
+    pub menu_order: Vec<String>,
+    /// Pages left out of the menu. `goto` still opens them.
+    pub hidden_pages: Vec<String>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            wrap_navigation: true,
+            tick_rate_ms: 100,
+            autosave_secs: 120,
+            mouse_capture: true,
+            undo_depth: 1,
+            stat_decay: false,
+            decay_rate_permille: 10,
+            page_transitions: true,
+            hold_to_repeat: false,
+            demo_idle_secs: 0,
+            confirm_spend_percent: 50,
+            bell: false,
+            quiet: false,
+            currency_symbol: "$".to_string(),
+            thousands_separator: ",".to_string(),
+            menu_order: PAGES.iter().map(|page| page.to_string()).collect(),
+            hidden_pages: Vec::new(),
+        }
+    }
+}
+
+/// One row on the Settings page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Setting {
+    #[default]
+    WrapNavigation,
+    TickRate,
+    Autosave,
+    MouseCapture,
+    UndoDepth,
+    StatDecay,
+    DecayRate,
+    PageTransitions,
+    HoldToRepeat,
+    DemoIdle,
+    ConfirmSpend,
+    Bell,
+    Quiet,
+    MoneyFormat,
+}
+
+impl Setting {
+    pub const ALL: [Setting; 14] = [
+        Setting::WrapNavigation,
+        Setting::TickRate,
+        Setting::Autosave,
+        Setting::MouseCapture,
+        Setting::UndoDepth,
+        Setting::StatDecay,
+        Setting::DecayRate,
+        Setting::PageTransitions,
+        Setting::HoldToRepeat,
+        Setting::DemoIdle,
+        Setting::ConfirmSpend,
+        Setting::Bell,
+        Setting::Quiet,
+        Setting::MoneyFormat,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Setting::WrapNavigation => "Menu wrapping",
+            Setting::TickRate => "Tick rate",
+            Setting::Autosave => "Autosave every",
+            Setting::MouseCapture => "Mouse capture",
+            Setting::UndoDepth => "Undo steps",
+            Setting::StatDecay => "Stat decay",
+            Setting::DecayRate => "Decay rate",
+            Setting::PageTransitions => "Page transitions",
+            Setting::HoldToRepeat => "Hold to repeat",
+            Setting::DemoIdle => "Demo after",
+            Setting::ConfirmSpend => "Confirm spends",
+            Setting::Bell => "Bell",
+            Setting::Quiet => "Quiet",
+            Setting::MoneyFormat => "Money format",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Setting::WrapNavigation => {
+                "Moving past the last menu item goes back to the first, and the other way round."
+            }
+            Setting::TickRate => {
+                "How often the screen updates while no keys are pressed. Lower is smoother; higher uses less CPU."
+            }
+            Setting::Autosave => "How often the game saves itself in the background.",
+            Setting::MouseCapture => {
+                "Click and scroll in the app. Turn off to select text with the mouse instead."
+            }
+            Setting::UndoDepth => {
+                "How many buys, sells and discards in a row u can take back. 0 turns undo off."
+            }
+            Setting::StatDecay => {
+                "Stats you don't train during a day drop a little when it ends. For a harder game."
+            }
+            Setting::DecayRate => {
+                "How much of each untrained stat is lost per day, when decay is on."
+            }
+            Setting::PageTransitions => {
+                "Slide the content across when switching pages. Turn off for instant switches."
+            }
+            Setting::HoldToRepeat => {
+                "Hold the train or crime key to keep going until you run out of energy."
+            }
+            Setting::DemoIdle => {
+                "Left alone this long, the game plays itself until a key is pressed. Your game isn't touched."
+            }
+            Setting::ConfirmSpend => {
+                "Buys and bets bigger than this share of your cash ask first. 100% never asks."
+            }
+            Setting::Bell => "Beep when you level up, land in jail or unlock an achievement.",
+            Setting::Quiet => "Silence the bell for now without turning it off.",
+            Setting::MoneyFormat => {
+                "The thousands separator in amounts of money. Change the symbol with set currency."
+            }
+        }
+    }
+
+    /// The row after this one, wrapping around.
+    pub fn next(self) -> Setting {
+        let index = Setting::ALL.iter().position(|&s| s == self).unwrap_or(0);
+        Setting::ALL[(index + 1) % Setting::ALL.len()]
+    }
+
+    pub fn prev(self) -> Setting {
+        let index = Setting::ALL.iter().position(|&s| s == self).unwrap_or(0);
+        Setting::ALL[(index + Setting::ALL.len() - 1) % Setting::ALL.len()]
+    }
+}
+
+impl Settings {
+    /// How long the loop waits for input, kept in range even if the save
+    /// file was edited by hand.
+    pub fn tick_rate(&self) -> Duration {
+        Duration::from_millis(clamp(self.tick_rate_ms, &TICK_RATE_RANGE))
+    }
+
+    pub fn autosave_interval(&self) -> Duration {
+        Duration::from_secs(clamp(self.autosave_secs, &AUTOSAVE_RANGE))
+    }
+
+    pub fn undo_depth(&self) -> usize {
+        clamp(self.undo_depth, &UNDO_DEPTH_RANGE) as usize
+    }
+
+    /// How long the game sits idle before the demo starts, or `None` when
+    /// it's off.
+    pub fn demo_idle(&self) -> Option<Duration> {
+        (self.demo_idle_secs > 0)
+            .then(|| Duration::from_secs(clamp(self.demo_idle_secs, &DEMO_IDLE_RANGE)))
+    }
+
+    /// See `CONFIRM_SPEND_RANGE`.
+    pub fn confirm_spend_percent(&self) -> u64 {
+        clamp(self.confirm_spend_percent, &CONFIRM_SPEND_RANGE)
+    }
+
+    /// Tenths of a percent of each untrained stat lost per day.
+    pub fn decay_rate(&self) -> u64 {
+        clamp(self.decay_rate_permille, &DECAY_RATE_RANGE)
+    }
+
+    /// The current value of `setting`, as shown on the Settings page.
+    pub fn value_text(&self, setting: Setting) -> String {
+        match setting {
+            Setting::WrapNavigation => switch_name(self.wrap_navigation).to_string(),
+            Setting::TickRate => format!("{} ms", self.tick_rate().as_millis()),
+            Setting::Autosave => format!("{} s", self.autosave_interval().as_secs()),
+            Setting::MouseCapture => switch_name(self.mouse_capture).to_string(),
+            Setting::UndoDepth => self.undo_depth().to_string(),
+            Setting::StatDecay => switch_name(self.stat_decay).to_string(),
+            Setting::DecayRate => {
+                let rate = self.decay_rate();
+                format!("{}.{}% a day", rate / 10, rate % 10)
+            }
+            Setting::PageTransitions => switch_name(self.page_transitions).to_string(),
+            Setting::HoldToRepeat => switch_name(self.hold_to_repeat).to_string(),
+            Setting::DemoIdle => match self.demo_idle() {
+                Some(idle) => format!("{} s", idle.as_secs()),
+                None => "off".to_string(),
+            },
+            Setting::ConfirmSpend => format!("over {}% of cash", self.confirm_spend_percent()),
+            Setting::Bell => switch_name(self.bell).to_string(),
+            Setting::Quiet => switch_name(self.quiet).to_string(),
+            Setting::MoneyFormat => format!(
+                "{}{}",
+                self.currency_symbol,
+                money::group_digits(1_234_567, &self.thousands_separator)
+            ),
+        }
+    }
+
+    /// Moves `setting` up (`up`) or down a step, stopping at the ends of its
+    /// range; switches just flip.
+    pub fn adjust(&mut self, setting: Setting, up: bool) {
+        let step = |value: u64, step: u64, range: &RangeInclusive<u64>| {
+            let value = if up {
+                value.saturating_add(step)
+            } else {
+                value.saturating_sub(step)
+            };
+            clamp(value, range)
+        };
+        match setting {
+            Setting::WrapNavigation => self.wrap_navigation = !self.wrap_navigation,
+            Setting::TickRate => {
+                self.tick_rate_ms = step(self.tick_rate_ms, TICK_RATE_STEP, &TICK_RATE_RANGE)
+            }
+            Setting::Autosave => {
+                self.autosave_secs = step(self.autosave_secs, AUTOSAVE_STEP, &AUTOSAVE_RANGE)
+            }
+            Setting::MouseCapture => self.mouse_capture = !self.mouse_capture,
+            Setting::UndoDepth => {
+                self.undo_depth = step(self.undo_depth, UNDO_DEPTH_STEP, &UNDO_DEPTH_RANGE)
+            }
+            Setting::StatDecay => self.stat_decay = !self.stat_decay,
+            Setting::DecayRate => {
+                self.decay_rate_permille =
+                    step(self.decay_rate_permille, DECAY_RATE_STEP, &DECAY_RATE_RANGE)
+            }
+            Setting::PageTransitions => self.page_transitions = !self.page_transitions,
+            Setting::HoldToRepeat => self.hold_to_repeat = !self.hold_to_repeat,
+            // Off sits just below the bottom of the range.
+            Setting::DemoIdle => {
+                self.demo_idle_secs = match self.demo_idle() {
+                    None if up => *DEMO_IDLE_RANGE.start(),
+                    None => 0,
+                    Some(idle) if !up && idle.as_secs() <= *DEMO_IDLE_RANGE.start() => 0,
+                    Some(idle) => step(idle.as_secs(), DEMO_IDLE_STEP, &DEMO_IDLE_RANGE),
+                }
+            }
+            Setting::ConfirmSpend => {
+                self.confirm_spend_percent = step(
+                    self.confirm_spend_percent,
+                    CONFIRM_SPEND_STEP,
+                    &CONFIRM_SPEND_RANGE,
+                )
+            }
+            Setting::Bell => self.bell = !self.bell,
+            Setting::Quiet => self.quiet = !self.quiet,
+            Setting::MoneyFormat => {
+                let index = SEPARATORS
+                    .iter()
+                    .position(|(_, separator)| *separator == self.thousands_separator)
+                    .unwrap_or(0);
+                let index = if up {
+                    (index + 1) % SEPARATORS.len()
+                } else {
+                    (index + SEPARATORS.len() - 1) % SEPARATORS.len()
+                };
+                self.thousands_separator = SEPARATORS[index].1.to_string();
+            }
+        }
+    }
+
+    /// Sets the option called `name` from its text form, for the `set`
+    /// command. Returns a description of the new value.
+    pub fn set(&mut self, name: &str, value: &str) -> Result<String, String> {
+        match name {
+            "wrap" => {
+                self.wrap_navigation = parse_switch(value)?;
+                Ok(format!(
+                    "Menu wrapping {}",
+                    switch_name(self.wrap_navigation)
+                ))
+            }
+            "tick" => {
+                self.tick_rate_ms = parse_in_range(value, &TICK_RATE_RANGE, "Tick rate", "ms")?;
+                Ok(format!("Tick rate {} ms", self.tick_rate_ms))
+            }
+            "autosave" => {
+                self.autosave_secs = parse_in_range(value, &AUTOSAVE_RANGE, "Autosave", "s")?;
+                Ok(format!("Autosaving every {} s", self.autosave_secs))
+            }
+            "mouse" => {
+                self.mouse_capture = parse_switch(value)?;
+                Ok(format!("Mouse capture {}", switch_name(self.mouse_capture)))
+            }
+            "undo" => {
+                self.undo_depth = parse_in_range(value, &UNDO_DEPTH_RANGE, "Undo", "steps")?;
+                Ok(format!("Keeping {} undo steps", self.undo_depth))
+            }
+            "decay" => {
+                self.stat_decay = parse_switch(value)?;
+                Ok(format!("Stat decay {}", switch_name(self.stat_decay)))
+            }
+            "decay-rate" => {
+                self.decay_rate_permille = parse_in_range(
+                    value,
+                    &DECAY_RATE_RANGE,
+                    "Decay rate",
+                    "tenths of a percent",
+                )?;
+                Ok(format!(
+                    "Decay rate {}",
+                    self.value_text(Setting::DecayRate)
+                ))
+            }
+            "transitions" => {
+                self.page_transitions = parse_switch(value)?;
+                Ok(format!(
+                    "Page transitions {}",
+                    switch_name(self.page_transitions)
+                ))
+            }
+            "repeat" => {
+                self.hold_to_repeat = parse_switch(value)?;
+                Ok(format!(
+                    "Hold to repeat {}",
+                    switch_name(self.hold_to_repeat)
+                ))
+            }
+            "demo" => {
+                self.demo_idle_secs = match value.to_lowercase().as_str() {
+                    "off" | "0" => 0,
+                    _ => parse_in_range(value, &DEMO_IDLE_RANGE, "Demo idle time", "s")?,
+                };
+                Ok(match self.demo_idle() {
+                    Some(idle) => format!("Demo after {} s idle", idle.as_secs()),
+                    None => "Demo off".to_string(),
+                })
+            }
+            "spend" => {
+                self.confirm_spend_percent =
+                    parse_in_range(value, &CONFIRM_SPEND_RANGE, "Confirm threshold", "%")?;
+                Ok(format!(
+                    "Confirming spends {}",
+                    self.value_text(Setting::ConfirmSpend)
+                ))
+            }
+            "bell" => {
+                self.bell = parse_switch(value)?;
+                Ok(format!("Bell {}", switch_name(self.bell)))
+            }
+            "quiet" => {
+                self.quiet = parse_switch(value)?;
+                Ok(format!("Quiet {}", switch_name(self.quiet)))
+            }
+            "currency" => {
+                let length = value.chars().count();
+                if length == 0 || length > SYMBOL_MAX_LEN {
+                    return Err(format!(
+                        "Currency symbol must be 1-{} characters, got '{}'",
+                        SYMBOL_MAX_LEN, value
+                    ));
+                }
+                self.currency_symbol = value.to_string();
+                Ok(format!(
+                    "Money now reads {}",
+                    self.value_text(Setting::MoneyFormat)
+                ))
+            }
+            "separator" => {
+                let Some((_, separator)) = SEPARATORS
+                    .iter()
+                    .find(|(name, _)| name.eq_ignore_ascii_case(value))
+                else {
+                    let names: Vec<&str> = SEPARATORS.iter().map(|(name, _)| *name).collect();
+                    return Err(format!(
+                        "Separator must be one of {}, got '{}'",
+                        names.join(", "),
+                        value
+                    ));
+                };
+                self.thousands_separator = separator.to_string();
+                Ok(format!(
+                    "Money now reads {}",
+                    self.value_text(Setting::MoneyFormat)
+                ))
+            }
+            _ => Err(format!("Unknown setting '{}'", name)),
+        }
+    }
+}
+
+impl Settings {
+    /// Every page in menu order, hidden ones included. Pages the saved order
+    /// doesn't mention, such as ones added since the save was written, go at
+    /// the end.
+    fn page_order(&self) -> Vec<&'static str> {
+        let mut order: Vec<&'static str> = Vec::with_capacity(PAGES.len());
+        for page in self.menu_order.iter().filter_map(|name| page_named(name)) {
+            if !order.contains(&page) {
+                order.push(page);
+            }
+        }
+        for page in PAGES {
+            if !order.contains(&page) {
+                order.push(page);
+            }
+        }
+        order
+    }
+
+    /// Tidies a loaded menu: unknown page names are dropped, missing pages
+    /// put back at the end, and if every page was hidden they're all shown
+    /// again, since an empty menu leaves nothing to open.
+    pub fn sanitize_pages(&mut self) {
+        self.menu_order = self
+            .page_order()
+            .into_iter()
+            .map(|page| page.to_string())
+            .collect();
+        let mut hidden: Vec<String> = Vec::new();
+        for page in self.hidden_pages.iter().filter_map(|name| page_named(name)) {
+            if !hidden.iter().any(|known| known == page) {
+                hidden.push(page.to_string());
+            }
+        }
+        if hidden.len() >= PAGES.len() {
+            hidden.clear();
+        }
+        self.hidden_pages = hidden;
+    }
+
+    /// The pages the menu lists, in order.
+    pub fn menu_items(&self) -> Vec<&'static str> {
+        self.page_order()
+            .into_iter()
+            .filter(|page| !self.is_hidden(page))
+            .collect()
+    }
+
+    pub fn is_hidden(&self, page: &str) -> bool {
+        self.hidden_pages.iter().any(|hidden| hidden == page)
+    }
+
+    /// Swaps `name` with the visible page above (`up`) or below it, for the
+    /// `menu up` and `menu down` commands.
+    pub fn move_page(&mut self, name: &str, up: bool) -> Result<String, String> {
+        let page = known_page(name)?;
+        let items = self.menu_items();
+        let Some(index) = items.iter().position(|item| *item == page) else {
+            return Err(format!("{} is hidden; show it first", page));
+        };
+        let neighbour = if up {
+            index.checked_sub(1)
+        } else {
+            Some(index + 1).filter(|&i| i < items.len())
+        };
+        let Some(neighbour) = neighbour.map(|i| items[i]) else {
+            let end = if up { "top" } else { "bottom" };
+            return Err(format!("{} is already at the {}", page, end));
+        };
+        let mut order = self.page_order();
+        let a = order.iter().position(|item| *item == page);
+        let b = order.iter().position(|item| *item == neighbour);
+        if let (Some(a), Some(b)) = (a, b) {
+            order.swap(a, b);
+        }
+        self.menu_order = order.iter().map(|page| page.to_string()).collect();
+        let direction = if up { "up" } else { "down" };
+        Ok(format!("Moved {} {}", page, direction))
+    }
+
+    /// Takes `name` out of the menu, as long as something is left in it.
+    pub fn hide_page(&mut self, name: &str) -> Result<String, String> {
+        let page = known_page(name)?;
+        if self.is_hidden(page) {
+            return Err(format!("{} is already hidden", page));
+        }
+        if self.menu_items().len() <= 1 {
+            return Err("The menu needs at least one page".to_string());
+        }
+        self.hidden_pages.push(page.to_string());
+        Ok(format!("Hid {}; goto still opens it", page))
+    }
+
+    pub fn show_page(&mut self, name: &str) -> Result<String, String> {
+        let page = known_page(name)?;
+        if !self.is_hidden(page) {
+            return Err(format!("{} isn't hidden", page));
+        }
+        self.hidden_pages.retain(|hidden| hidden != page);
+        Ok(format!("{} is back in the menu", page))
+    }
+}
+
+/// The page called `name`, ignoring case.
+fn page_named(name: &str) -> Option<&'static str> {
+    PAGES
+        .iter()
+        .find(|page| page.eq_ignore_ascii_case(name))
+        .copied()
+}
+
+fn known_page(name: &str) -> Result<&'static str, String> {
+    page_named(name).ok_or_else(|| format!("Unknown page '{}'", name))
+}
+
+fn clamp(value: u64, range: &RangeInclusive<u64>) -> u64 {
+    value.clamp(*range.start(), *range.end())
+}
+
+fn parse_in_range(
+    value: &str,
+    range: &RangeInclusive<u64>,
+    name: &str,
+    unit: &str,
+) -> Result<u64, String> {
+    match value.parse() {
+        Ok(n) if range.contains(&n) => Ok(n),
+        _ => Err(format!(
+            "{} must be {}-{} {}, got '{}'",
+            name,
+            range.start(),
+            range.end(),
+            unit,
+            value
+        )),
+    }
+}
+
+fn parse_switch(value: &str) -> Result<bool, String> {
+    match value.to_lowercase().as_str() {
+        "on" | "true" | "yes" => Ok(true),
+        "off" | "false" | "no" => Ok(false),
+        _ => Err(format!("Expected on or off, got '{}'", value)),
+    }
+}
+
+fn switch_name(on: bool) -> &'static str {
+    if on { "on" } else { "off" }
+}
+
+/// The Settings page's list, with `selected` marked.
+pub fn settings_text(settings: &Settings, selected: Setting) -> String {
+    Setting::ALL
+        .iter()
+        .map(|&setting| {
+            let marker = if setting == selected { ">" } else { " " };
+            format!(
+                "{} {:<16} {}",
+                marker,
+                setting.label(),
+                settings.value_text(setting)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
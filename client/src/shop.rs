@@ -0,0 +1,159 @@
+use std::fmt;
+
+use ratatui::text::{Line, Span};
+use ratatui::widgets::ListItem;
+use serde::{Deserialize, Serialize};
+
+use crate::inventory::{Inventory, Item};
+use crate::market;
+use crate::money::format_money;
+use crate::player::PlayerStats;
+use crate::theme::Theme;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShopEntry {
+    pub item: Item,
+    /// Today's price; see `market`.
+    pub price: u64,
+    #[serde(default)]
+    pub base_price: u64,
+    /// Yesterday's price, for the trend arrow.
+    #[serde(default)]
+    pub previous_price: u64,
+    /// Units left to sell; `None` never sells out.
+    pub stock: Option<u32>,
+}
+
+/// The City shop. Stock levels are part of the save, so sold-out items stay
+/// sold out.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Shop {
+    pub entries: Vec<ShopEntry>,
+}
+
+impl Default for Shop {
+    fn default() -> Self {
+        Self {
+            entries: vec![
+                ShopEntry {
+                    item: Item::EnergyDrink,
+                    price: 40,
+                    base_price: 40,
+                    previous_price: 40,
+                    stock: None,
+                },
+                ShopEntry {
+                    item: Item::Candy,
+                    price: 5,
+                    base_price: 5,
+                    previous_price: 5,
+                    stock: None,
+                },
+                ShopEntry {
+                    item: Item::FirstAidKit,
+                    price: 120,
+                    base_price: 120,
+                    previous_price: 120,
+                    stock: Some(5),
+                },
+                ShopEntry {
+                    item: Item::Newspaper,
+                    price: 1,
+                    base_price: 1,
+                    previous_price: 1,
+                    stock: Some(1),
+                },
+                ShopEntry {
+                    item: Item::LuckyCharm,
+                    price: 600,
+                    base_price: 600,
+                    previous_price: 600,
+                    stock: Some(1),
+                },
+            ],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuyError {
+    NoSuchEntry,
+    SoldOut,
+    NotEnoughMoney { price: u64 },
+}
+
+impl fmt::Display for BuyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuyError::NoSuchEntry => write!(f, "The shop doesn't sell that"),
+            BuyError::SoldOut => write!(f, "That item is sold out"),
+            BuyError::NotEnoughMoney { price } => {
+                write!(f, "Not enough money: that costs {}", format_money(*price))
+            }
+        }
+    }
+}
+
+impl Shop {
+    /// Buys one unit of the entry at `index`, paying from `stats` and adding
+    /// it to `inventory`. Nothing changes if the purchase is rejected.
+    pub fn buy(
+        &mut self,
+        index: usize,
+        stats: &mut PlayerStats,
+        inventory: &mut Inventory,
+    ) -> Result<Item, BuyError> {
+        let entry = self.entries.get_mut(index).ok_or(BuyError::NoSuchEntry)?;
+        if entry.stock == Some(0) {
+            return Err(BuyError::SoldOut);
+        }
+        if stats.money < entry.price {
+            return Err(BuyError::NotEnoughMoney { price: entry.price });
+        }
+
+        stats.money -= entry.price;
+        if let Some(stock) = &mut entry.stock {
+            *stock -= 1;
+        }
+        inventory.add(entry.item, 1);
+        Ok(entry.item)
+    }
+
+    /// One row per entry, item names colored by rarity.
+    pub fn listing_items(&self, theme: &Theme) -> Vec<ListItem<'static>> {
+        self.entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                let stock = match entry.stock {
+                    Some(0) => "SOLD OUT".to_string(),
+                    Some(n) => format!("{} left", n),
+                    None => String::new(),
+                };
+                ListItem::new(Line::from(vec![
+                    Span::raw(format!("{}. ", i + 1)),
+                    Span::styled(
+                        format!("{:<14}", entry.item.name()),
+                        entry.item.rarity().style(theme),
+                    ),
+                    Span::raw(format!(
+                        " {:<7} {:<6} {}",
+                        format_money(entry.price),
+                        market::trend_text(entry.price, entry.previous_price),
+                        stock
+                    )),
+                ]))
+            })
+            .collect()
+    }
+
+    /// The buy prompt for the entry at `selected`.
+    pub fn prompt_text(&self, selected: usize) -> Option<String> {
+        let entry = self.entries.get(selected)?;
+        Some(format!(
+            "Buy {} for {}? y: yes   n: no",
+            entry.item.name(),
+            format_money(entry.price)
+        ))
+    }
+}
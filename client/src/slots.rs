@@ -0,0 +1,127 @@
+//! The save slot picker shown at startup: one save per character, opened,
+//! started or deleted from a list before the game itself comes up.
+
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crossterm::event::KeyCode;
+
+use crate::save::{self, SlotInfo};
+
+/// What the player picked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Choice {
+    /// Play the save at this path, starting a new game if there isn't one.
+    Open(PathBuf),
+    Quit,
+}
+
+#[derive(Debug)]
+pub struct SlotPicker {
+    dir: PathBuf,
+    pub slots: Vec<SlotInfo>,
+    /// Row under the cursor. One past the last slot is the "New game" row,
+    /// while there's a free slot for it.
+    pub selected: usize,
+    /// Slot waiting on a yes before it's deleted.
+    pub deleting: Option<u32>,
+    /// Outcome of the last delete, shown under the list.
+    pub message: Option<String>,
+}
+
+impl SlotPicker {
+    /// Lists the saves in `dir`, starting on the one played most recently.
+    pub fn new(dir: &Path) -> Self {
+        let slots = save::list_slots(dir);
+        let selected = slots
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, info)| info.last_played)
+            .map_or(0, |(index, _)| index);
+        Self {
+            dir: dir.to_path_buf(),
+            slots,
+            selected,
+            deleting: None,
+            message: None,
+        }
+    }
+
+    /// Number of rows, counting "New game" when there's room for one.
+    pub fn rows(&self) -> usize {
+        self.slots.len() + usize::from(save::free_slot(&self.slots).is_some())
+    }
+
+    /// The picker's rows as text, slots first.
+    pub fn labels(&self, now: SystemTime) -> Vec<String> {
+        let mut labels: Vec<String> = self.slots.iter().map(|info| label(info, now)).collect();
+        if let Some(slot) = save::free_slot(&self.slots) {
+            labels.push(format!("New game (slot {})", slot));
+        }
+        labels
+    }
+
+    pub fn handle_key(&mut self, code: KeyCode) -> Option<Choice> {
+        if let Some(slot) = self.deleting.take() {
+            if let KeyCode::Char('y' | 'Y') = code {
+                self.delete(slot);
+            }
+            return None;
+        }
+        match code {
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down => self.selected = (self.selected + 1).min(self.rows().saturating_sub(1)),
+            KeyCode::Enter => {
+                let slot = match self.slots.get(self.selected) {
+                    Some(info) => Some(info.slot),
+                    None => save::free_slot(&self.slots),
+                };
+                return slot.map(|slot| Choice::Open(save::slot_path(&self.dir, slot)));
+            }
+            KeyCode::Char('n') => {
+                return save::free_slot(&self.slots)
+                    .map(|slot| Choice::Open(save::slot_path(&self.dir, slot)));
+            }
+            KeyCode::Char('d') => {
+                self.deleting = self.slots.get(self.selected).map(|info| info.slot);
+            }
+            KeyCode::Char('q') | KeyCode::Esc => return Some(Choice::Quit),
+            _ => {}
+        }
+        None
+    }
+
+    /// Deletes one slot's save and re-reads the list; the other slots'
+    /// files are never touched.
+    fn delete(&mut self, slot: u32) {
+        self.message = Some(match save::delete_save(&save::slot_path(&self.dir, slot)) {
+            Ok(()) => format!("Deleted slot {}.", slot),
+            Err(err) => format!("Couldn't delete slot {}: {}", slot, err),
+        });
+        self.slots = save::list_slots(&self.dir);
+        self.selected = self.selected.min(self.rows().saturating_sub(1));
+    }
+}
+
+fn label(info: &SlotInfo, now: SystemTime) -> String {
+    let level = match info.level {
+        Some(level) => format!("Level {}", level),
+        None => "Unreadable".to_string(),
+    };
+    let played = match info.last_played {
+        Some(time) => played_text(time, now),
+        None => "never".to_string(),
+    };
+    format!("Slot {}  {}  played {}", info.slot, level, played)
+}
+
+/// How long ago `time` was, in the largest whole unit.
+fn played_text(time: SystemTime, now: SystemTime) -> String {
+    let secs = now.duration_since(time).unwrap_or_default().as_secs();
+    match secs {
+        0..60 => "just now".to_string(),
+        60..3_600 => format!("{}m ago", secs / 60),
+        3_600..86_400 => format!("{}h ago", secs / 3_600),
+        _ => format!("{}d ago", secs / 86_400),
+    }
+}
@@ -0,0 +1,208 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::achievement::Achievements;
+use crate::bank::Bank;
+use crate::blackjack::Blackjack;
+use crate::casino::{CasinoGame, Slots};
+use crate::clock::GameClock;
+use crate::cooldown::Cooldowns;
+use crate::crime::CrimeRecord;
+use crate::daily::DailyReward;
+use crate::decay::DecayLog;
+use crate::difficulty::Difficulty;
+use crate::faction::LocalFactions;
+use crate::forums::Forums;
+use crate::gym::TrainingEntry;
+use crate::hall_of_fame::HallOfFame;
+use crate::inbox::Inbox;
+use crate::inventory::{Inventory, Quantity};
+use crate::market::Market;
+use crate::mission::MissionLog;
+use crate::news::{self, NewsEntry, NewsLog};
+use crate::player::PlayerStats;
+use crate::property::RealEstate;
+use crate::referral::Referral;
+use crate::roulette::Roulette;
+use crate::rules::Reports;
+use crate::settings::{Setting, Settings};
+use crate::shop::Shop;
+use crate::theme::ThemeName;
+use crate::{hospital, jail};
+
+/// Every page reachable from the menu, in menu order.
+pub const PAGES: [&str; 24] = [
+    "Home",
+    "Dashboard",
+    "Items",
+    "City",
+    "Bank",
+    "Job",
+    "Gym",
+    "Properties",
+    "Education",
+    "Crimes",
+    "Missions",
+    "Newspaper",
+    "Messages",
+    "Jail",
+    "Hospital",
+    "Casino",
+    "Forums",
+    "Hall of Fame",
+    "Faction",
+    "Recruit Citizens",
+    "Calendar",
+    "Rules",
+    "Achievements",
+    "Settings",
+];
+
+/// Everything that belongs to a single playthrough and is written to the save file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameState {
+    pub player: PlayerStats,
+    pub inventory: Inventory,
+    pub shop: Shop,
+    pub market: Market,
+    pub bank: Bank,
+    /// Highlighted entry in the City shop.
+    #[serde(skip)]
+    pub shop_selected: usize,
+    /// Whether the City shop is asking to confirm a purchase.
+    #[serde(skip)]
+    pub shop_prompt: bool,
+    /// Highlighted stack on the Items page.
+    #[serde(skip)]
+    pub item_selected: usize,
+    /// Whether the use/sell prompt is open for the highlighted stack.
+    #[serde(skip)]
+    pub item_prompt: bool,
+    /// How many of the highlighted stack the prompt uses or sells.
+    #[serde(skip)]
+    pub item_quantity: Quantity,
+    /// Index into `gym::MACHINES` of the machine the Gym's train key uses.
+    #[serde(skip)]
+    pub gym_machine: usize,
+    /// Most recent training sessions, newest first.
+    pub training_log: VecDeque<TrainingEntry>,
+    /// When each stat was last trained, for the optional stat decay.
+    pub decay: DecayLog,
+    /// Index into `crime::CRIMES` of the crime the Crimes page's key commits.
+    #[serde(skip)]
+    pub crime_selected: usize,
+    /// Successful crimes committed so far.
+    pub crime_xp: u32,
+    /// Most recent crime outcomes, newest first.
+    pub crime_history: VecDeque<CrimeRecord>,
+    /// Every attempt, successful or not.
+    pub crimes_committed: u32,
+    /// Crimes that can't be tried again yet, by name.
+    pub cooldowns: Cooldowns,
+    /// Index into `fight::TARGETS` of the City's attack target.
+    #[serde(skip)]
+    pub target_selected: usize,
+    pub fights_won: u32,
+    pub missions: MissionLog,
+    pub achievements: Achievements,
+    /// Casino rounds that paid out more than was staked.
+    pub casino_wins: u32,
+    /// Index into `job::JOBS`.
+    pub job: usize,
+    #[serde(skip)]
+    pub job_task_selected: usize,
+    /// Index into `education::COURSES` of the course being taken.
+    pub course: Option<usize>,
+    #[serde(with = "crate::deadline")]
+    pub enrolled_until: Option<Instant>,
+    #[serde(skip)]
+    pub course_selected: usize,
+    pub completed_courses: Vec<String>,
+    pub real_estate: RealEstate,
+    #[serde(skip)]
+    pub property_selected: usize,
+    pub hall_of_fame: HallOfFame,
+    pub factions: LocalFactions,
+    /// Name of the faction the player belongs to.
+    pub faction: Option<String>,
+    pub referral: Referral,
+    pub daily: DailyReward,
+    /// Reports filed from the Rules page.
+    pub reports: Reports,
+    pub casino_game: CasinoGame,
+    pub slots: Slots,
+    pub blackjack: Blackjack,
+    pub roulette: Roulette,
+    #[serde(with = "crate::deadline")]
+    pub jail_until: Option<Instant>,
+    /// Full length of the current sentence, penalties included.
+    pub jail_term_secs: u64,
+    #[serde(with = "crate::deadline")]
+    pub hospital_until: Option<Instant>,
+    /// Full length of the current hospital stay.
+    pub hospital_stay_secs: u64,
+    /// What put the player in the hospital.
+    pub injury: Option<String>,
+    pub news: NewsLog,
+    pub inbox: Inbox,
+    /// Highlighted message on the Messages page.
+    #[serde(skip)]
+    pub message_selected: usize,
+    pub forums: Forums,
+    /// Highlighted thread on the Forums page; replies go to it.
+    #[serde(skip)]
+    pub thread_selected: usize,
+    pub theme: ThemeName,
+    pub settings: Settings,
+    /// Highlighted row on the Settings page.
+    #[serde(skip)]
+    pub setting_selected: Setting,
+    pub clock: GameClock,
+    /// Whether the first-launch tutorial has been finished or skipped.
+    pub tutorial_done: bool,
+    pub difficulty: Difficulty,
+}
+
+/// Pages whose actions are off limits while the player is locked up.
+pub const ACTION_PAGES: [&str; 2] = ["Gym", "Crimes"];
+
+impl GameState {
+    /// Pushes every real-time deadline back by `by`, so jail, hospital and
+    /// course countdowns stand still while the game is paused.
+    pub fn postpone_deadlines(&mut self, by: Duration) {
+        for deadline in [
+            &mut self.enrolled_until,
+            &mut self.jail_until,
+            &mut self.hospital_until,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            *deadline += by;
+        }
+    }
+
+    pub fn log_news(&mut self, text: impl Into<String>) {
+        self.news.push(NewsEntry {
+            timestamp: news::unix_now(),
+            text: text.into(),
+        });
+    }
+
+    /// Why `page` can't be used right now, if anything is stopping it.
+    pub fn blocked_reason(&self, page: &str, now: Instant) -> Option<&'static str> {
+        if !ACTION_PAGES.contains(&page) {
+            return None;
+        }
+        if jail::is_jailed(self, now) {
+            return Some("You are in jail");
+        }
+        if hospital::is_hospitalized(self, now) {
+            return Some("You are in the hospital");
+        }
+        None
+    }
+}
@@ -0,0 +1,191 @@
+use ratatui::style::{Color, Modifier, Style};
+use serde::{Deserialize, Serialize};
+
+use crate::color::{self, ColorDepth};
+
+/// Named styles every widget pulls from instead of hardcoding colors. Styles
+/// rather than bare colors so presets can lean on bold/reverse where color
+/// support is poor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theme {
+    pub menu_normal: Style,
+    pub menu_unread: Style,
+    pub menu_important: Style,
+    /// The selected menu row and other "you are here" markers.
+    pub highlight: Style,
+    pub input: Style,
+    pub info: Style,
+    pub warn: Style,
+    pub error: Style,
+    /// The money field just after money comes in or goes out.
+    pub gain: Style,
+    pub loss: Style,
+    /// Item names, by rarity. Rare and Epic are bold too, so they still
+    /// stand out where colors are scarce.
+    pub common: Style,
+    pub uncommon: Style,
+    pub rare: Style,
+    pub epic: Style,
+}
+
+impl Theme {
+    /// Every style with its colors fitted to `depth`.
+    pub fn fitted(self, depth: ColorDepth) -> Theme {
+        let fit = |style| color::fit_style(style, depth);
+        Theme {
+            menu_normal: fit(self.menu_normal),
+            menu_unread: fit(self.menu_unread),
+            menu_important: fit(self.menu_important),
+            highlight: fit(self.highlight),
+            input: fit(self.input),
+            info: fit(self.info),
+            warn: fit(self.warn),
+            error: fit(self.error),
+            gain: fit(self.gain),
+            loss: fit(self.loss),
+            common: fit(self.common),
+            uncommon: fit(self.uncommon),
+            rare: fit(self.rare),
+            epic: fit(self.epic),
+        }
+    }
+}
+
+/// The built-in presets. Only the name is saved, so tweaking a preset
+/// applies to existing saves too.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ThemeName {
+    /// Soft RGB colors, for terminals that can show them. Elsewhere they're
+    /// fitted to the nearest the terminal has, much like `Dark`.
+    #[default]
+    Rich,
+    Dark,
+    Light,
+    HighContrast,
+}
+
+impl ThemeName {
+    pub const ALL: [ThemeName; 4] = [
+        ThemeName::Rich,
+        ThemeName::Dark,
+        ThemeName::Light,
+        ThemeName::HighContrast,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ThemeName::Rich => "rich",
+            ThemeName::Dark => "dark",
+            ThemeName::Light => "light",
+            ThemeName::HighContrast => "high-contrast",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<ThemeName> {
+        ThemeName::ALL
+            .into_iter()
+            .find(|theme| theme.name().eq_ignore_ascii_case(name))
+    }
+
+    /// The preset as the terminal can show it.
+    pub fn theme(self) -> Theme {
+        self.preset().fitted(color::depth())
+    }
+
+    fn preset(self) -> Theme {
+        let bold = Modifier::BOLD;
+        match self {
+            ThemeName::Rich => Theme {
+                menu_normal: Style::default().fg(Color::Rgb(200, 200, 212)),
+                menu_unread: Style::default().fg(Color::Rgb(120, 220, 140)),
+                menu_important: Style::default().fg(Color::Rgb(240, 100, 100)),
+                highlight: Style::default()
+                    .fg(Color::Rgb(255, 200, 80))
+                    .add_modifier(bold),
+                input: Style::default()
+                    .fg(Color::Rgb(255, 200, 80))
+                    .add_modifier(bold),
+                info: Style::default().fg(Color::Rgb(120, 220, 140)),
+                warn: Style::default().fg(Color::Rgb(240, 190, 90)),
+                error: Style::default().fg(Color::Rgb(240, 90, 90)),
+                gain: Style::default()
+                    .fg(Color::Rgb(110, 230, 130))
+                    .add_modifier(bold),
+                loss: Style::default()
+                    .fg(Color::Rgb(240, 90, 90))
+                    .add_modifier(bold),
+                common: Style::default().fg(Color::Rgb(190, 190, 200)),
+                uncommon: Style::default().fg(Color::Rgb(100, 210, 120)),
+                rare: Style::default()
+                    .fg(Color::Rgb(90, 150, 255))
+                    .add_modifier(bold),
+                epic: Style::default()
+                    .fg(Color::Rgb(190, 110, 255))
+                    .add_modifier(bold),
+            },
+            ThemeName::Dark => Theme {
+                menu_normal: Style::default().fg(Color::Gray),
+                menu_unread: Style::default().fg(Color::Green),
+                menu_important: Style::default().fg(Color::Red),
+                highlight: Style::default().fg(Color::Yellow).add_modifier(bold),
+                input: Style::default().fg(Color::Yellow).add_modifier(bold),
+                info: Style::default().fg(Color::Green),
+                warn: Style::default().fg(Color::Yellow),
+                error: Style::default().fg(Color::Red),
+                gain: Style::default().fg(Color::Green).add_modifier(bold),
+                loss: Style::default().fg(Color::Red).add_modifier(bold),
+                common: Style::default().fg(Color::Gray),
+                uncommon: Style::default().fg(Color::Green),
+                rare: Style::default().fg(Color::Blue).add_modifier(bold),
+                epic: Style::default().fg(Color::Magenta).add_modifier(bold),
+            },
+            ThemeName::Light => Theme {
+                menu_normal: Style::default().fg(Color::Black),
+                menu_unread: Style::default().fg(Color::Green),
+                menu_important: Style::default().fg(Color::Red),
+                highlight: Style::default().fg(Color::Blue).add_modifier(bold),
+                input: Style::default().fg(Color::Blue).add_modifier(bold),
+                info: Style::default().fg(Color::Green),
+                warn: Style::default().fg(Color::Magenta),
+                error: Style::default().fg(Color::Red).add_modifier(bold),
+                gain: Style::default().fg(Color::Green).add_modifier(bold),
+                loss: Style::default().fg(Color::Red).add_modifier(bold),
+                common: Style::default().fg(Color::Black),
+                uncommon: Style::default().fg(Color::Green),
+                rare: Style::default().fg(Color::Blue).add_modifier(bold),
+                epic: Style::default().fg(Color::Magenta).add_modifier(bold),
+            },
+            ThemeName::HighContrast => Theme {
+                menu_normal: Style::default().fg(Color::White),
+                menu_unread: Style::default()
+                    .fg(Color::White)
+                    .add_modifier(bold | Modifier::UNDERLINED),
+                menu_important: Style::default().fg(Color::White).add_modifier(bold),
+                highlight: Style::default()
+                    .fg(Color::White)
+                    .add_modifier(bold | Modifier::REVERSED),
+                input: Style::default().fg(Color::White).add_modifier(bold),
+                info: Style::default().fg(Color::White),
+                warn: Style::default().fg(Color::White).add_modifier(bold),
+                error: Style::default()
+                    .fg(Color::White)
+                    .add_modifier(bold | Modifier::REVERSED),
+                gain: Style::default()
+                    .fg(Color::White)
+                    .add_modifier(bold | Modifier::UNDERLINED),
+                loss: Style::default()
+                    .fg(Color::White)
+                    .add_modifier(bold | Modifier::REVERSED),
+                common: Style::default().fg(Color::White),
+                uncommon: Style::default().fg(Color::White).add_modifier(bold),
+                rare: Style::default()
+                    .fg(Color::White)
+                    .add_modifier(bold | Modifier::UNDERLINED),
+                epic: Style::default()
+                    .fg(Color::White)
+                    .add_modifier(bold | Modifier::REVERSED),
+            },
+        }
+    }
+}
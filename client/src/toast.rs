@@ -0,0 +1,99 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use crate::command::CommandResult;
+use crate::error;
+use crate::notifications::{Notification, NotificationLog};
+
+/// How long a toast stays up, in game-clock time.
+pub const TOAST_DURATION: Duration = Duration::from_secs(4);
+/// Toasts start to dim once they have this little time left.
+pub const TOAST_FADE: Duration = Duration::from_secs(1);
+/// Older toasts are dropped early once this many are showing.
+pub const MAX_TOASTS: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastLevel {
+    Info,
+    Warn,
+    Error,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Toast {
+    pub text: String,
+    pub level: ToastLevel,
+    /// Game-clock time (`GameClock::elapsed`) at which the toast goes away.
+    pub expires_at: Duration,
+}
+
+/// Short-lived notes about what just happened, stacked newest last. Each one
+/// expires on its own, but is also kept in the notification log.
+#[derive(Debug, Default)]
+pub struct Toasts {
+    queue: VecDeque<Toast>,
+    log: NotificationLog,
+}
+
+impl Toasts {
+    pub fn push(&mut self, text: impl Into<String>, level: ToastLevel, now: Duration) {
+        let text = text.into();
+        self.log.push(Notification {
+            at: now,
+            level,
+            text: text.clone(),
+        });
+        self.queue.push_back(Toast {
+            text,
+            level,
+            expires_at: now + TOAST_DURATION,
+        });
+        if self.queue.len() > MAX_TOASTS {
+            self.queue.pop_front();
+        }
+    }
+
+    /// Toasts the outcome of a page action. Empty results and page switches
+    /// have nothing to say.
+    pub fn push_result(&mut self, result: &error::Result<CommandResult>, now: Duration) {
+        match result {
+            Ok(CommandResult::Message(text)) => self.push(text.clone(), ToastLevel::Info, now),
+            Ok(CommandResult::Setback(text)) => self.push(text.clone(), ToastLevel::Error, now),
+            Err(err) => self.push(err.to_string(), ToastLevel::Error, now),
+            Ok(
+                CommandResult::Empty
+                | CommandResult::Goto(_)
+                | CommandResult::Confirm(_)
+                | CommandResult::Tutorial
+                | CommandResult::Undo
+                | CommandResult::Export(_)
+                | CommandResult::Pause,
+            ) => {}
+        }
+    }
+
+    /// Drops expired toasts. Returns true if any went, so the screen can be
+    /// redrawn without them.
+    pub fn expire(&mut self, now: Duration) -> bool {
+        let before = self.queue.len();
+        self.queue.retain(|toast| toast.expires_at > now);
+        self.queue.len() != before
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Toast> {
+        self.queue.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Every toast so far this session, including expired ones.
+    pub fn log(&self) -> &NotificationLog {
+        &self.log
+    }
+
+    pub fn mark_read(&mut self) {
+        self.log.mark_read();
+    }
+}
@@ -0,0 +1,125 @@
+//! The slide between pages. It only ever touches what's drawn: the switch
+//! itself has already happened by the time the first frame slides, so keys
+//! pressed mid-slide act on the new page, and a second switch simply starts
+//! a new slide from whatever was on screen.
+
+use std::time::{Duration, Instant};
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+
+/// How long a slide takes from start to finish.
+pub const DURATION: Duration = Duration::from_millis(120);
+/// Longest the loop may wait between frames while a slide is running, so
+/// it's drawn as more than one or two frames at slow tick rates.
+pub const FRAME_INTERVAL: Duration = Duration::from_millis(16);
+
+struct Slide {
+    /// The content area as it was last drawn before the switch.
+    from: Buffer,
+    /// The new page comes in from the right (`true`) or the left.
+    forward: bool,
+    started: Instant,
+}
+
+/// Tracks which page the content area last showed and slides between them
+/// when that changes.
+#[derive(Default)]
+pub struct PageTransitions {
+    /// Menu index of the page last drawn.
+    shown: Option<usize>,
+    /// Copy of the content area from the last frame.
+    last: Option<Buffer>,
+    slide: Option<Slide>,
+}
+
+impl PageTransitions {
+    /// Whether a slide still has frames to draw.
+    pub fn is_running(&self, now: Instant) -> bool {
+        self.slide
+            .as_ref()
+            .is_some_and(|slide| now.duration_since(slide.started) < DURATION)
+    }
+
+    /// Called once the page at menu index `page` has been drawn into `area`
+    /// of `buf`. Starts a slide if the page changed and `enabled` is set,
+    /// then draws the running slide's current frame over the new page.
+    pub fn frame(
+        &mut self,
+        buf: &mut Buffer,
+        area: Rect,
+        page: usize,
+        enabled: bool,
+        now: Instant,
+    ) {
+        if let Some(shown) = self.shown
+            && shown != page
+        {
+            self.slide = match self.last.take() {
+                Some(from) if enabled && from.area == area => Some(Slide {
+                    from,
+                    forward: page > shown,
+                    started: now,
+                }),
+                _ => None,
+            };
+        }
+        self.shown = Some(page);
+
+        if let Some(slide) = &self.slide {
+            let elapsed = now.duration_since(slide.started);
+            // A resize mid-slide leaves the old picture the wrong size.
+            if elapsed >= DURATION || slide.from.area != area {
+                self.slide = None;
+            } else {
+                let offset = slid_columns(area.width, elapsed);
+                compose(buf, &slide.from, area, offset, slide.forward);
+            }
+        }
+        self.last = Some(snapshot(buf, area));
+    }
+}
+
+/// Columns the pages have moved after `elapsed`, easing out so the slide
+/// starts quickly and settles gently.
+fn slid_columns(width: u16, elapsed: Duration) -> u16 {
+    let t = (elapsed.as_secs_f64() / DURATION.as_secs_f64()).clamp(0.0, 1.0);
+    let eased = 1.0 - (1.0 - t) * (1.0 - t);
+    (f64::from(width) * eased).round() as u16
+}
+
+/// A copy of the cells of `area`.
+fn snapshot(buf: &Buffer, area: Rect) -> Buffer {
+    let mut copy = Buffer::empty(area);
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            copy[(x, y)] = buf[(x, y)].clone();
+        }
+    }
+    copy
+}
+
+/// Rebuilds `area` of `buf`, which holds the new page, as the old page
+/// `from` pushed `offset` columns out with the new one following it in.
+fn compose(buf: &mut Buffer, from: &Buffer, area: Rect, offset: u16, forward: bool) {
+    let incoming = snapshot(buf, area);
+    let width = area.width;
+    let offset = offset.min(width);
+    for y in area.top()..area.bottom() {
+        for col in 0..width {
+            let source = if forward {
+                // Old page leaves to the left; new page enters from the right.
+                if col < width - offset {
+                    &from[(area.x + col + offset, y)]
+                } else {
+                    &incoming[(area.x + col - (width - offset), y)]
+                }
+            } else if col < offset {
+                &incoming[(area.x + col + width - offset, y)]
+            } else {
+                &from[(area.x + col - offset, y)]
+            };
+            buf[(area.x + col, y)] = source.clone();
+        }
+    }
+}
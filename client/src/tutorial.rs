@@ -0,0 +1,91 @@
+//! The walkthrough that runs the first time a new game is started.
+
+use crate::state::GameState;
+
+/// Money paid for getting through the tutorial the first time.
+pub const TUTORIAL_BONUS: u64 = 100;
+
+/// The part of the screen a step is about, outlined while it's shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    /// Nothing in particular; the hint sits in the middle of the screen.
+    Screen,
+    Menu,
+    Info,
+    Content,
+    Input,
+}
+
+pub struct Step {
+    pub title: &'static str,
+    pub text: &'static str,
+    pub target: Target,
+}
+
+pub const STEPS: &[Step] = &[
+    Step {
+        title: "Welcome to Rusty",
+        text: "You've just arrived in town with a little cash and a lot of ambition. \
+               Here's a quick look around.",
+        target: Target::Screen,
+    },
+    Step {
+        title: "Menu",
+        text: "Every place in town is a page in the menu. Move with j / k or the arrow keys, \
+               or type a page's number to jump straight to it.",
+        target: Target::Menu,
+    },
+    Step {
+        title: "Info",
+        text: "Your energy, the time of day and your progress to the next level live up here. \
+               Most things cost energy, and it comes back by itself.",
+        target: Target::Info,
+    },
+    Step {
+        title: "Pages",
+        text: "The open page shows what you can do there. Left / Right change the selection \
+               and each page lists its own keys.",
+        target: Target::Content,
+    },
+    Step {
+        title: "Input",
+        text: "Press : or i to type a command, such as 'goto gym' or 'help'. Tab completes it \
+               and Esc goes back to the menu. Press ? any time for every key.",
+        target: Target::Input,
+    },
+];
+
+/// Which step of the tutorial is on screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Tutorial {
+    step: usize,
+}
+
+impl Tutorial {
+    pub fn step(&self) -> &'static Step {
+        &STEPS[self.step.min(STEPS.len() - 1)]
+    }
+
+    /// "n/total", for the hint's title.
+    pub fn progress(&self) -> String {
+        format!("{}/{}", self.step + 1, STEPS.len())
+    }
+
+    /// Moves to the next step. Returns `false` once there are none left.
+    pub fn advance(&mut self) -> bool {
+        self.step += 1;
+        self.step < STEPS.len()
+    }
+}
+
+/// Marks the tutorial as done. Finishing it (`completed`) pays
+/// `TUTORIAL_BONUS`, but only the first time, so replays and skips don't.
+pub fn finish(state: &mut GameState, completed: bool) -> Option<u64> {
+    let first_time = !state.tutorial_done;
+    state.tutorial_done = true;
+    if !(first_time && completed) {
+        return None;
+    }
+    state.player.money += TUTORIAL_BONUS;
+    Some(TUTORIAL_BONUS)
+}
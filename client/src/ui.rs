@@ -0,0 +1,877 @@
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Position, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{
+        Block, Borders, Clear, Gauge, LineGauge, List, ListItem, ListState, Paragraph, Scrollbar,
+        ScrollbarOrientation, ScrollbarState, Wrap,
+    },
+};
+
+use crate::command::CommandResult;
+use crate::difficulty::{self, Difficulty};
+use crate::error;
+use crate::money::format_money;
+use crate::pages::Page;
+use crate::palette::Palette;
+use crate::player::PlayerStats;
+use crate::slots::SlotPicker;
+use crate::state::GameState;
+use crate::theme::Theme;
+use crate::toast::{TOAST_FADE, ToastLevel, Toasts};
+use crate::tutorial::{Target, Tutorial};
+use crate::{clock, deadline, hospital, jail, level};
+
+/// Below this size the layout can't fit, so only a warning is drawn.
+pub const MIN_WIDTH: u16 = 40;
+pub const MIN_HEIGHT: u16 = 16;
+/// Width of the menu column on terminals with room to spare.
+const MENU_WIDTH: u16 = 20;
+/// Terminals narrower than this or shorter than `COMPACT_HEIGHT` get the
+/// compact layout: the Info box keeps only its level gauge.
+pub const COMPACT_WIDTH: u16 = 90;
+pub const COMPACT_HEIGHT: u16 = 28;
+/// Terminals narrower than this swap the menu column for a one-line
+/// breadcrumb above the content.
+pub const BREADCRUMB_WIDTH: u16 = 70;
+/// Content narrower than this shows a page's two boxes as one.
+pub const STACK_WIDTH: u16 = 70;
+
+pub fn is_too_small(area: Rect) -> bool {
+    area.width < MIN_WIDTH || area.height < MIN_HEIGHT
+}
+
+pub fn render_too_small(frame: &mut Frame, area: Rect) {
+    let message = Paragraph::new(format!(
+        "Terminal too small\n{}x{} (need {}x{})",
+        area.width, area.height, MIN_WIDTH, MIN_HEIGHT
+    ))
+    .alignment(Alignment::Center)
+    .wrap(Wrap { trim: true });
+    frame.render_widget(message, area);
+}
+
+/// Where each part of the screen goes for a given terminal size.
+#[derive(Debug, Clone, Copy)]
+pub struct AppLayout {
+    pub menu: Rect,
+    pub info: Rect,
+    pub status: Rect,
+    pub content: Rect,
+    pub output: Rect,
+    pub input: Rect,
+}
+
+/// Whether `area` calls for the compact layout.
+pub fn is_compact(area: Rect) -> bool {
+    area.width < COMPACT_WIDTH || area.height < COMPACT_HEIGHT
+}
+
+pub fn layout(area: Rect) -> AppLayout {
+    let [menu, main] = if area.width < BREADCRUMB_WIDTH {
+        Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(area)
+    } else {
+        Layout::horizontal([
+            // Give up menu width before squeezing the content on narrow terminals.
+            Constraint::Length(MENU_WIDTH.min(area.width / 3)),
+            Constraint::Min(0),
+        ])
+        .areas(area)
+    };
+    // The compact Info box is just its border and the level gauge.
+    let info_height = if is_compact(area) { 3 } else { 5 };
+
+    // Vertical: Info (5) | Status (1) | Main (flex) | Output (2) | Input (3)
+    let right_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(info_height), // Info box
+            Constraint::Length(1),           // Status bar
+            Constraint::Min(0),              // Content area
+            Constraint::Length(2),           // Command output
+            Constraint::Length(3),           // Input box
+        ])
+        .split(main);
+
+    AppLayout {
+        menu,
+        info: right_chunks[0],
+        status: right_chunks[1],
+        content: right_chunks[2],
+        output: right_chunks[3],
+        input: right_chunks[4],
+    }
+}
+
+/// The menu item under the terminal cell (`column`, `row`), accounting for
+/// the menu's border and how far the list has scrolled.
+pub fn menu_index_at(
+    menu: Rect,
+    state: &ListState,
+    len: usize,
+    column: u16,
+    row: u16,
+) -> Option<usize> {
+    let inner = menu.inner(Margin::new(1, 1));
+    if !inner.contains(Position::new(column, row)) {
+        return None;
+    }
+    let index = state.offset() + usize::from(row - inner.y);
+    (index < len).then_some(index)
+}
+
+/// The menu row for `page`: its name, plus the unread count for Messages.
+pub fn menu_label(page: &str, state: &GameState) -> String {
+    match page {
+        "Messages" if state.inbox.unread() > 0 => format!("{} ({})", page, state.inbox.unread()),
+        _ => page.to_string(),
+    }
+}
+
+/// Menu row style for `page`, worked out from the live game state each frame:
+/// Jail and Hospital stand out while the player is in them, and the Newspaper
+/// and Messages while they have anything unread.
+pub fn menu_color_for(page: &str, state: &GameState, now: Instant) -> Style {
+    let theme = state.theme.theme();
+    match page {
+        "Jail" if jail::is_jailed(state, now) => theme.menu_important,
+        "Hospital" if hospital::is_hospitalized(state, now) => theme.menu_important,
+        "Newspaper" if state.news.has_unread() => theme.menu_unread,
+        "Messages" if state.inbox.unread() > 0 => theme.menu_unread,
+        _ => theme.menu_normal,
+    }
+}
+
+/// Byte range of the first case-insensitive match of `query` in `label`.
+fn match_range(label: &str, query: &str) -> Option<(usize, usize)> {
+    if query.is_empty() {
+        return None;
+    }
+    label
+        .to_lowercase()
+        .find(&query.to_lowercase())
+        .map(|start| (start, start + query.len()))
+}
+
+/// Indexes of the `items` that contain `query`, ignoring case.
+pub fn filter_menu(items: &[&str], query: &str) -> Vec<usize> {
+    let query = query.to_lowercase();
+    items
+        .iter()
+        .enumerate()
+        .filter(|(_, label)| label.to_lowercase().contains(&query))
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// The menu as a bordered list, or as a one-line breadcrumb naming the
+/// selected page when `area` is too short for a list.
+pub fn render_menu(
+    frame: &mut Frame,
+    area: Rect,
+    title: &str,
+    items: &[(&str, Style)],
+    state: &mut ListState,
+    theme: &Theme,
+    query: &str,
+) {
+    if area.height < 3 {
+        let mut spans = vec![Span::raw(format!("{} › ", title))];
+        match state.selected().and_then(|i| Some((i, items.get(i)?))) {
+            Some((i, (label, style))) => {
+                spans.push(Span::styled(*label, theme.highlight.patch(*style)));
+                spans.push(Span::raw(format!("  ({}/{})", i + 1, items.len())));
+            }
+            None => spans.push(Span::raw("no match")),
+        }
+        frame.render_widget(Paragraph::new(Line::from(spans)), area);
+        return;
+    }
+    let matched = theme.highlight.add_modifier(Modifier::UNDERLINED);
+    let menu: Vec<ListItem> = items
+        .iter()
+        .map(|(label, style)| match match_range(label, query) {
+            Some((start, end)) => ListItem::new(Line::from(vec![
+                Span::raw(&label[..start]),
+                Span::styled(&label[start..end], matched),
+                Span::raw(&label[end..]),
+            ]))
+            .style(*style),
+            None => ListItem::new((*label).to_string()).style(*style),
+        })
+        .collect();
+
+    let list = List::new(menu)
+        .block(Block::default().title(title).borders(Borders::ALL))
+        .highlight_style(theme.highlight)
+        .highlight_symbol("> ");
+    frame.render_stateful_widget(list, area, state);
+}
+
+/// Lights up the border of the box already drawn in `area` in the theme's
+/// highlight, marking it as the panel keys go to. The symbols and title are
+/// left as they were; only their style changes.
+pub fn render_focus_ring(frame: &mut Frame, area: Rect, theme: &Theme) {
+    if area.width < 2 || area.height < 2 {
+        return;
+    }
+    let edges = [
+        Rect::new(area.x, area.y, area.width, 1),
+        Rect::new(area.x, area.bottom() - 1, area.width, 1),
+        Rect::new(area.x, area.y, 1, area.height),
+        Rect::new(area.right() - 1, area.y, 1, area.height),
+    ];
+    for edge in edges {
+        frame.buffer_mut().set_style(edge, theme.highlight);
+    }
+}
+
+/// The last pages visited, `trail`, along the bottom edge of the bordered
+/// menu in `area`, dropping the oldest until they fit.
+pub fn render_trail(frame: &mut Frame, area: Rect, trail: &[&str], theme: &Theme) {
+    if area.height < 3 || trail.is_empty() {
+        return;
+    }
+    let room = area.width.saturating_sub(2);
+    let Some(text) = (0..trail.len())
+        .map(|start| trail[start..].join(" › "))
+        .find(|text| text.chars().count() <= usize::from(room))
+    else {
+        return;
+    };
+    let edge = Rect::new(area.x + 1, area.bottom() - 1, room, 1);
+    frame.render_widget(Paragraph::new(text).style(theme.menu_normal), edge);
+}
+
+/// The Info box; its title names the active input `mode` and counts any
+/// `unread` notifications. The bottom row is a gauge of the player's
+/// progress through their level.
+pub fn render_info(
+    frame: &mut Frame,
+    area: Rect,
+    mode: &str,
+    unread: usize,
+    text: &str,
+    player: &PlayerStats,
+    theme: &Theme,
+) {
+    let title = match unread {
+        0 => format!("Info [{}]", mode),
+        _ => format!("Info [{}] {} new (n)", mode, unread),
+    };
+    let block = Block::default().title(title).borders(Borders::ALL);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let [text_area, gauge_area] =
+        Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(inner);
+    frame.render_widget(Paragraph::new(text).wrap(Wrap { trim: true }), text_area);
+
+    let (earned, needed) = level::progress(player);
+    let gauge = LineGauge::default()
+        .ratio((earned as f64 / needed.max(1) as f64).min(1.0))
+        .label(format!("Level {}  XP {}/{}", player.level, earned, needed))
+        .filled_style(theme.highlight);
+    frame.render_widget(gauge, gauge_area);
+}
+
+/// Red at a quarter or less of `max`, yellow at half or less, green above.
+pub fn vital_style(current: u64, max: u64, theme: &Theme) -> Style {
+    let max = max.max(1);
+    if current.saturating_mul(4) <= max {
+        theme.error
+    } else if current.saturating_mul(2) <= max {
+        theme.warn
+    } else {
+        theme.info
+    }
+}
+
+/// A bar showing `current` out of `max` with `label` over it, colored by
+/// `vital_style` so it turns from green to red as it runs down. A `max` of
+/// zero draws an empty bar.
+pub fn vitals_gauge(label: &str, current: u64, max: u64, theme: &Theme) -> Gauge<'static> {
+    let ratio = if max == 0 {
+        0.0
+    } else {
+        (current as f64 / max as f64).min(1.0)
+    };
+    Gauge::default()
+        .ratio(ratio)
+        .label(label.to_string())
+        .gauge_style(vital_style(current, max, theme))
+        .use_unicode(true)
+}
+
+/// The one-line summary of the player's vitals shown on every page: energy
+/// and health bars, then the rest as text. Short labels keep it on one line
+/// in an 80-column terminal.
+/// The status line. `money_flash` colors the money field while it flashes,
+/// and `next_regen` is the game time left until energy next comes back.
+pub fn render_status(
+    frame: &mut Frame,
+    area: Rect,
+    state: &GameState,
+    now: Instant,
+    next_regen: Option<Duration>,
+    money_flash: Option<Style>,
+    theme: &Theme,
+) {
+    let player = &state.player;
+    let [energy_area, _, health_area, _, text_area] = Layout::horizontal([
+        Constraint::Length(VITALS_GAUGE_WIDTH),
+        Constraint::Length(1),
+        Constraint::Length(VITALS_GAUGE_WIDTH),
+        Constraint::Length(2),
+        Constraint::Min(0),
+    ])
+    .areas(area);
+    let (energy, max_energy) = (u64::from(player.energy), u64::from(player.max_energy));
+    frame.render_widget(
+        vitals_gauge(
+            &format!("EN {}/{}", energy, max_energy),
+            energy,
+            max_energy,
+            theme,
+        ),
+        energy_area,
+    );
+    let (health, max_health) = (u64::from(player.health), u64::from(player.max_health));
+    frame.render_widget(
+        vitals_gauge(
+            &format!("HP {}/{}", health, max_health),
+            health,
+            max_health,
+            theme,
+        ),
+        health_area,
+    );
+
+    let separator = || Span::raw("  ");
+    let money_style = match money_flash {
+        Some(style) => style,
+        None if player.money == 0 => theme.error,
+        None => Style::default(),
+    };
+    let mut spans = vec![
+        Span::styled(format_money(player.money), money_style),
+        separator(),
+        Span::raw(format!("Lv {}", player.level)),
+        separator(),
+        Span::raw(clock::format_time(state.clock.minutes())),
+    ];
+    let recovering = player.energy < player.max_energy || player.health < player.max_health;
+    if let Some(left) = next_regen.filter(|_| recovering) {
+        spans.push(separator());
+        spans.push(Span::styled(
+            format!("+1 EN {}", deadline::format_remaining(left)),
+            theme.info,
+        ));
+    }
+    if let Some((recovered, total)) = hospital::stay_progress(state, now) {
+        spans.push(separator());
+        spans.push(Span::styled(
+            format!(
+                "Out in {}",
+                deadline::format_remaining(Duration::from_secs(total - recovered))
+            ),
+            theme.warn,
+        ));
+    }
+    frame.render_widget(Paragraph::new(Line::from(spans)), text_area);
+}
+
+/// Columns given to each bar in the status line; room for `EN 100/100` with
+/// a little bar showing either side.
+const VITALS_GAUGE_WIDTH: u16 = 14;
+
+/// Draws `page`, with its action legend along the bottom if it has one.
+pub fn render_page(page: &dyn Page, frame: &mut Frame, area: Rect, theme: &Theme) {
+    let actions = page.actions();
+    if actions.is_empty() {
+        page.render(frame, area);
+        return;
+    }
+    let [content, legend] =
+        Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(area);
+    page.render(frame, content);
+    let text = legend_text(actions, usize::from(legend.width));
+    frame.render_widget(Paragraph::new(text).style(theme.info), legend);
+}
+
+/// `key action` pairs on one line, cut short with an ellipsis if they don't
+/// fit in `width` columns.
+pub fn legend_text(actions: &[(&str, &str)], width: usize) -> String {
+    let full = actions
+        .iter()
+        .map(|(key, action)| format!("{} {}", key, action))
+        .collect::<Vec<_>>()
+        .join("  ");
+    if full.chars().count() <= width {
+        return full;
+    }
+    let mut cut: String = full.chars().take(width.saturating_sub(1)).collect();
+    cut.push('…');
+    cut
+}
+
+/// The result of the last command, shown between the content and the input.
+pub fn render_output(
+    frame: &mut Frame,
+    area: Rect,
+    result: &error::Result<CommandResult>,
+    theme: &Theme,
+) {
+    let (text, style) = match result {
+        Ok(CommandResult::Message(text)) => (text.clone(), Style::default()),
+        Ok(CommandResult::Setback(text)) => (text.clone(), theme.error),
+        Err(err) => (err.to_string(), theme.error),
+        Ok(
+            CommandResult::Empty
+            | CommandResult::Goto(_)
+            | CommandResult::Confirm(_)
+            | CommandResult::Tutorial
+            | CommandResult::Undo
+            | CommandResult::Export(_)
+            | CommandResult::Pause,
+        ) => return,
+    };
+    let output = Paragraph::new(text).style(style).wrap(Wrap { trim: true });
+    frame.render_widget(output, area);
+}
+
+/// The Input box. Its border lights up while it has focus, which is when
+/// typed characters go to it rather than to navigation.
+/// The Input box. `cursor` is the cursor's column while the box has focus;
+/// the text scrolls sideways to keep it in view.
+pub fn render_input(
+    frame: &mut Frame,
+    area: Rect,
+    input: &str,
+    cursor: Option<usize>,
+    theme: &Theme,
+) {
+    let (title, border) = if cursor.is_some() {
+        ("Input (Esc to navigate)", theme.highlight)
+    } else {
+        ("Input (: or i to type)", Style::default())
+    };
+    let width = usize::from(area.width.saturating_sub(2)).max(1);
+    let column = cursor.unwrap_or(0);
+    let offset = column.saturating_sub(width - 1);
+    let input_box = Paragraph::new(input)
+        .style(theme.input)
+        .scroll((0, u16::try_from(offset).unwrap_or(u16::MAX)))
+        .block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_style(border),
+        );
+    frame.render_widget(input_box, area);
+    if cursor.is_some() {
+        let x = area.x + 1 + u16::try_from(column - offset).unwrap_or(0);
+        frame.set_cursor_position(Position::new(x, area.y + 1));
+    }
+}
+
+/// Splits `area` into the two side-by-side boxes most pages use, or one
+/// above the other when it's too narrow for both.
+pub fn split_boxes(area: Rect) -> (Rect, Rect) {
+    let direction = if area.width < STACK_WIDTH {
+        Direction::Vertical
+    } else {
+        Direction::Horizontal
+    };
+    let chunks = Layout::default()
+        .direction(direction)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+    (chunks[0], chunks[1])
+}
+
+/// `left` followed by `right` under a blank line, for narrow content areas
+/// that show both boxes' text in one.
+fn stacked<'a>(left: impl Into<Text<'a>>, right: impl Into<Text<'a>>) -> Text<'a> {
+    let mut text = left.into();
+    text.lines.push(Line::default());
+    text.lines.extend(right.into().lines);
+    text
+}
+
+pub fn content_box<'a>(text: impl Into<Text<'a>>, title: &'a str) -> Paragraph<'a> {
+    Paragraph::new(text.into())
+        .wrap(Wrap { trim: false })
+        .block(Block::default().title(title).borders(Borders::ALL))
+}
+
+/// Draws `text` in a bordered box scrolled down by `offset` lines, with a
+/// scrollbar once it no longer fits. Returns the offset actually used, which
+/// is clamped so the content can't scroll past its last line.
+pub fn render_scrollable<'a>(
+    frame: &mut Frame,
+    area: Rect,
+    text: impl Into<Text<'a>>,
+    title: &'a str,
+    offset: u16,
+) -> u16 {
+    let text = text.into();
+    let visible = area.height.saturating_sub(2);
+    let lines = Paragraph::new(text.clone())
+        .wrap(Wrap { trim: false })
+        .line_count(area.width.saturating_sub(2));
+    let lines = u16::try_from(lines).unwrap_or(u16::MAX);
+    let max_offset = lines.saturating_sub(visible);
+    let offset = offset.min(max_offset);
+
+    frame.render_widget(content_box(text, title).scroll((offset, 0)), area);
+
+    if max_offset > 0 {
+        let mut state = ScrollbarState::new(usize::from(max_offset)).position(usize::from(offset));
+        frame.render_stateful_widget(
+            Scrollbar::new(ScrollbarOrientation::VerticalRight),
+            area.inner(Margin::new(0, 1)),
+            &mut state,
+        );
+    }
+    offset
+}
+
+/// Renders the standard "Left Box" / "Right Box" pair, both scrolled by the
+/// page's shared offset. Narrow areas get a single box holding both.
+pub fn render_boxes<'a>(
+    frame: &mut Frame,
+    area: Rect,
+    left: impl Into<Text<'a>>,
+    right: impl Into<Text<'a>>,
+    scroll: &Cell<u16>,
+) {
+    if area.width < STACK_WIDTH {
+        let offset = render_scrollable(frame, area, stacked(left, right), "Content", scroll.get());
+        scroll.set(offset);
+        return;
+    }
+    let (left_area, right_area) = split_boxes(area);
+    let offset = scroll.get();
+    let left = render_scrollable(frame, left_area, left, "Left Box", offset);
+    let right = render_scrollable(frame, right_area, right, "Right Box", offset);
+    scroll.set(left.max(right));
+}
+
+/// `render_boxes` with `gauge` in a bordered strip titled `title` across the
+/// top of the left box.
+pub fn render_boxes_with_gauge(
+    frame: &mut Frame,
+    area: Rect,
+    gauge: Gauge,
+    title: &str,
+    left: String,
+    right: String,
+    scroll: &Cell<u16>,
+) {
+    if area.width < STACK_WIDTH {
+        let [gauge_area, area] =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(area);
+        frame.render_widget(
+            gauge.block(Block::default().title(title).borders(Borders::ALL)),
+            gauge_area,
+        );
+        render_boxes(frame, area, left, right, scroll);
+        return;
+    }
+    let (left_area, right_area) = split_boxes(area);
+    let [gauge_area, left_area] =
+        Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(left_area);
+    frame.render_widget(
+        gauge.block(Block::default().title(title).borders(Borders::ALL)),
+        gauge_area,
+    );
+    let offset = scroll.get();
+    let left = render_scrollable(frame, left_area, left, "Left Box", offset);
+    let right = render_scrollable(frame, right_area, right, "Right Box", offset);
+    scroll.set(left.max(right));
+}
+
+/// A rect `percent_x` by `percent_y` of `area`, centered within it.
+pub fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
+    let vertical = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(area);
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(vertical[1])[1]
+}
+
+/// The keybinding popup, drawn last so it floats over whatever page is open.
+pub fn render_help(frame: &mut Frame, text: &str, theme: &Theme) {
+    let area = centered_rect(70, 80, frame.area());
+    let help = Paragraph::new(text).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .title("Help (Esc or ? to close)")
+            .borders(Borders::ALL)
+            .border_style(theme.highlight),
+    );
+    frame.render_widget(Clear, area);
+    frame.render_widget(help, area);
+}
+
+/// The notification log popup, scrolled by `offset`. Returns the offset
+/// actually used.
+pub fn render_notifications(frame: &mut Frame, text: String, offset: u16) -> u16 {
+    let area = centered_rect(70, 60, frame.area());
+    frame.render_widget(Clear, area);
+    render_scrollable(
+        frame,
+        area,
+        text,
+        "Notifications (Esc or n to close)",
+        offset,
+    )
+}
+
+/// The current tutorial hint. The part of the screen it describes gets a
+/// highlighted outline, and the hint floats over the page content so it
+/// never covers that part, whatever the terminal's size.
+pub fn render_tutorial(frame: &mut Frame, layout: &AppLayout, tutorial: &Tutorial, theme: &Theme) {
+    let step = tutorial.step();
+    let target = match step.target {
+        Target::Screen => None,
+        Target::Menu => Some(layout.menu),
+        Target::Info => Some(layout.info),
+        Target::Content => Some(layout.content),
+        Target::Input => Some(layout.input),
+    };
+    let area = match target {
+        Some(target) => {
+            frame.render_widget(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(theme.highlight),
+                target,
+            );
+            centered_rect(80, 70, layout.content)
+        }
+        None => centered_rect(60, 40, frame.area()),
+    };
+    let hint = Paragraph::new(step.text).wrap(Wrap { trim: true }).block(
+        Block::default()
+            .title(format!("{} ({})", step.title, tutorial.progress()))
+            .title_bottom("Any key: next   Esc: skip")
+            .borders(Borders::ALL)
+            .border_style(theme.highlight),
+    );
+    frame.render_widget(Clear, area);
+    frame.render_widget(hint, area);
+}
+
+/// The Ctrl-P palette: the query on top and the matching commands under it,
+/// the one Enter picks highlighted.
+pub fn render_palette(frame: &mut Frame, palette: &Palette, theme: &Theme) {
+    let area = centered_rect(70, 60, frame.area());
+    let block = Block::default()
+        .title("Command palette")
+        .title_bottom("Up / Down: choose   Enter: run   Esc: close")
+        .borders(Borders::ALL)
+        .border_style(theme.highlight);
+    let inner = block.inner(area);
+    frame.render_widget(Clear, area);
+    frame.render_widget(block, area);
+
+    let [query_area, list_area] =
+        Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(inner);
+    frame.render_widget(Paragraph::new(format!("> {}", palette.query)), query_area);
+    let matches = palette.matches();
+    let width = matches
+        .iter()
+        .map(|entry| entry.label.len())
+        .max()
+        .unwrap_or(0);
+    let items: Vec<ListItem> = matches
+        .iter()
+        .map(|entry| {
+            ListItem::new(Line::from(vec![
+                Span::raw(format!("{:<width$}  ", entry.label)),
+                Span::styled(entry.description.as_str(), theme.info),
+            ]))
+        })
+        .collect();
+    let list = List::new(items)
+        .highlight_style(theme.highlight)
+        .highlight_symbol("> ");
+    let mut state = ListState::default().with_selected(Some(palette.selected));
+    frame.render_stateful_widget(list, list_area, &mut state);
+}
+
+/// The new-game popup asking how hard the game should be.
+pub fn render_difficulty_picker(frame: &mut Frame, selected: Difficulty, theme: &Theme) {
+    let area = centered_rect(70, 30, frame.area());
+    let picker = Paragraph::new(difficulty::picker_text(selected))
+        .wrap(Wrap { trim: false })
+        .block(
+            Block::default()
+                .title("New game: choose a difficulty")
+                .title_bottom("Up / Down: choose   Enter: start")
+                .borders(Borders::ALL)
+                .border_style(theme.highlight),
+        );
+    frame.render_widget(Clear, area);
+    frame.render_widget(picker, area);
+}
+
+/// The banner shown over everything while the game is paused.
+pub fn render_paused(frame: &mut Frame, theme: &Theme) {
+    let area = centered_rect(40, 20, frame.area());
+    let banner = Paragraph::new("PAUSED\n\nThe clock is stopped.\np or Esc: resume")
+        .alignment(Alignment::Center)
+        .style(theme.highlight)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(theme.highlight),
+        );
+    frame.render_widget(Clear, area);
+    frame.render_widget(banner, area);
+}
+
+/// The y/n popup for a destructive action.
+pub fn render_confirm(frame: &mut Frame, prompt: &str, theme: &Theme) {
+    let area = centered_rect(50, 30, frame.area());
+    let text = format!("{}\n\ny: yes   n / Esc: no", prompt);
+    let dialog = Paragraph::new(text)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+        .block(
+            Block::default()
+                .title("Are you sure?")
+                .borders(Borders::ALL)
+                .border_style(theme.error),
+        );
+    frame.render_widget(Clear, area);
+    frame.render_widget(dialog, area);
+}
+
+/// The startup list of save slots, with a y/n line in place of the key
+/// hints while a delete waits for an answer.
+pub fn render_slot_picker(frame: &mut Frame, picker: &SlotPicker, theme: &Theme) {
+    let area = centered_rect(60, 60, frame.area());
+    let items: Vec<ListItem> = picker
+        .labels(std::time::SystemTime::now())
+        .into_iter()
+        .map(ListItem::new)
+        .collect();
+    let hint = match picker.deleting {
+        Some(slot) => format!("Delete slot {}? y: yes   n / Esc: no", slot),
+        None => "Enter: play   n: new   d: delete   q: quit".to_string(),
+    };
+    let mut block = Block::default()
+        .title("Choose a save")
+        .title_bottom(hint)
+        .borders(Borders::ALL)
+        .border_style(if picker.deleting.is_some() {
+            theme.error
+        } else {
+            theme.highlight
+        });
+    if let Some(message) = &picker.message {
+        block = block.title_bottom(Line::from(message.as_str()).right_aligned());
+    }
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(theme.highlight)
+        .highlight_symbol("> ");
+    let mut state = ListState::default().with_selected(Some(picker.selected));
+    frame.render_widget(Clear, area);
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+/// Stacks the live toasts along the bottom of `area`, newest lowest. Toasts
+/// dim in their last moments before expiring.
+pub fn render_toasts(frame: &mut Frame, area: Rect, toasts: &Toasts, now: Duration, theme: &Theme) {
+    if toasts.is_empty() {
+        return;
+    }
+    let inner = area.inner(Margin::new(1, 1));
+    let lines: Vec<Line> = toasts
+        .iter()
+        .map(|toast| {
+            let mut style = match toast.level {
+                ToastLevel::Info => theme.info,
+                ToastLevel::Warn => theme.warn,
+                ToastLevel::Error => theme.error,
+            };
+            if toast.expires_at.saturating_sub(now) <= TOAST_FADE {
+                style = style.add_modifier(Modifier::DIM);
+            }
+            Line::styled(toast.text.as_str(), style)
+        })
+        .collect();
+
+    let height = u16::try_from(lines.len())
+        .unwrap_or(u16::MAX)
+        .min(inner.height);
+    let toast_area = Rect {
+        y: inner.bottom() - height,
+        height,
+        ..inner
+    };
+    frame.render_widget(Clear, toast_area);
+    frame.render_widget(Paragraph::new(lines), toast_area);
+}
+
+/// The Tab completion candidates, in a box along the bottom of `area`, with
+/// the one the Input box currently holds highlighted.
+pub fn render_completions(
+    frame: &mut Frame,
+    area: Rect,
+    choices: &[&str],
+    current: Option<usize>,
+    theme: &Theme,
+) {
+    let spans: Vec<Span> = choices
+        .iter()
+        .enumerate()
+        .flat_map(|(i, choice)| {
+            let style = if current == Some(i) {
+                theme.highlight
+            } else {
+                Style::default()
+            };
+            [Span::styled(*choice, style), Span::raw("  ")]
+        })
+        .collect();
+    let inner = area.inner(Margin::new(1, 1));
+    let text = Paragraph::new(Line::from(spans)).wrap(Wrap { trim: true });
+    let height = u16::try_from(text.line_count(inner.width.saturating_sub(2)))
+        .unwrap_or(u16::MAX)
+        .saturating_add(2)
+        .min(inner.height);
+    let popup = Rect {
+        y: inner.bottom() - height,
+        height,
+        ..inner
+    };
+    frame.render_widget(Clear, popup);
+    frame.render_widget(
+        text.block(
+            Block::default()
+                .title("Completions (Tab to cycle)")
+                .borders(Borders::ALL)
+                .border_style(theme.highlight),
+        ),
+        popup,
+    );
+}
@@ -0,0 +1,105 @@
+use crate::state::GameState;
+
+/// Snapshots taken just before reversible actions (buys, sells, discards),
+/// newest last. Anything that can't be taken back, such as a crime or a
+/// course finishing, clears it so an undo never jumps back over it.
+#[derive(Debug, Clone, Default)]
+pub struct UndoStack {
+    states: Vec<GameState>,
+}
+
+impl UndoStack {
+    /// Remembers `before`, the state ahead of a reversible action, dropping
+    /// the oldest snapshots beyond `depth`.
+    pub fn push(&mut self, before: GameState, depth: usize) {
+        self.states.push(before);
+        let excess = self.states.len().saturating_sub(depth);
+        self.states.drain(..excess);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.states.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.states.clear();
+    }
+
+    /// Puts back the most recent snapshot. Things that carry on regardless of
+    /// the undone action stay as they are now: the clock, the energy and
+    /// health regenerated since, the jail, hospital, course and cooldown
+    /// timers (which a pause since may have pushed back), and the player's
+    /// preferences. Returns false if there was nothing to undo.
+    pub fn undo(&mut self, state: &mut GameState) -> bool {
+        let Some(before) = self.states.pop() else {
+            return false;
+        };
+        let mut player = before.player.clone();
+        player.energy = state.player.energy;
+        player.health = state.player.health;
+        *state = GameState {
+            player,
+            clock: state.clock,
+            jail_until: state.jail_until,
+            jail_term_secs: state.jail_term_secs,
+            hospital_until: state.hospital_until,
+            hospital_stay_secs: state.hospital_stay_secs,
+            enrolled_until: state.enrolled_until,
+            cooldowns: state.cooldowns.clone(),
+            theme: state.theme,
+            settings: state.settings.clone(),
+            tutorial_done: state.tutorial_done,
+            ..before
+        };
+        true
+    }
+}
+
+/// Whether anything that's saved differs between `before` and `after`,
+/// besides the clock and preferences. Menu and page selections aren't
+/// saved, so moving around doesn't count.
+pub fn progress_changed(before: &GameState, after: &GameState) -> bool {
+    // Deadlines are saved as time left, which changes by the moment, so
+    // they're compared as they are instead.
+    let deadlines =
+        |state: &GameState| (state.jail_until, state.hospital_until, state.enrolled_until);
+    let saved = |state: &GameState| {
+        serde_json::to_value(GameState {
+            clock: Default::default(),
+            theme: Default::default(),
+            settings: Default::default(),
+            jail_until: None,
+            hospital_until: None,
+            enrolled_until: None,
+            ..state.clone()
+        })
+        .ok()
+    };
+    deadlines(before) != deadlines(after) || saved(before) != saved(after)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn undo_keeps_postponed_deadlines() {
+        let now = Instant::now();
+        let mut state = GameState {
+            jail_until: Some(now + Duration::from_secs(60)),
+            hospital_until: Some(now + Duration::from_secs(30)),
+            ..GameState::default()
+        };
+        let mut undo = UndoStack::default();
+        undo.push(state.clone(), 5);
+        state.player.money += 100;
+        state.postpone_deadlines(Duration::from_secs(20));
+
+        assert!(undo.undo(&mut state));
+        assert_eq!(state.player.money, GameState::default().player.money);
+        assert_eq!(state.jail_until, Some(now + Duration::from_secs(80)));
+        assert_eq!(state.hospital_until, Some(now + Duration::from_secs(50)));
+        assert!(!undo.undo(&mut state));
+    }
+}
@@ -0,0 +1,55 @@
+//! Back and forward through the pages visited, like a browser. Pages are
+//! kept by name rather than menu index so reordering or hiding menu entries
+//! doesn't send Back somewhere else.
+
+/// Most pages remembered; the oldest are dropped past this.
+pub const VISITS_LEN: usize = 30;
+/// Pages shown in the breadcrumb under the menu, the open one included.
+pub const BREADCRUMB_LEN: usize = 3;
+
+#[derive(Debug, Default)]
+pub struct PageVisits {
+    pages: Vec<&'static str>,
+    /// Index into `pages` of the open page. Entries after it are the
+    /// forward history.
+    cursor: usize,
+}
+
+impl PageVisits {
+    /// Records that `page` is open. Staying on a page records nothing, and
+    /// opening a new page after going back drops the forward history.
+    pub fn visit(&mut self, page: &'static str) {
+        if self.pages.get(self.cursor) == Some(&page) {
+            return;
+        }
+        self.pages.truncate(self.cursor + 1);
+        self.pages.push(page);
+        if self.pages.len() > VISITS_LEN {
+            self.pages.remove(0);
+        }
+        self.cursor = self.pages.len() - 1;
+    }
+
+    /// The page before the open one, if there is one; stepping onto it is
+    /// left to the caller, whose next `visit` then matches.
+    pub fn back(&mut self) -> Option<&'static str> {
+        self.cursor = self.cursor.checked_sub(1)?;
+        Some(self.pages[self.cursor])
+    }
+
+    pub fn forward(&mut self) -> Option<&'static str> {
+        let page = *self.pages.get(self.cursor + 1)?;
+        self.cursor += 1;
+        Some(page)
+    }
+
+    /// The last few pages leading up to the open one, oldest first. Empty
+    /// while there's nowhere to go back to.
+    pub fn breadcrumb(&self) -> &[&'static str] {
+        if self.cursor == 0 {
+            return &[];
+        }
+        let end = self.cursor + 1;
+        &self.pages[end.saturating_sub(BREADCRUMB_LEN)..end]
+    }
+}